@@ -1,7 +1,10 @@
 use crate::session::{AppResult, AppSession};
 use crate::tracing::TracingOptions;
 use miette::IntoDiagnostic;
+use starbase_styles::color::ColorMode;
+use std::env;
 use std::future::Future;
+use std::path::{Path, PathBuf};
 use std::process::ExitCode;
 use tokio::spawn;
 use tokio::task::JoinHandle;
@@ -22,9 +25,29 @@ pub enum AppPhase {
 pub struct App {
     pub phase: AppPhase,
     exit_code: Option<u8>,
+    working_dir: Option<PathBuf>,
 }
 
 impl App {
+    /// Change the process working directory to the provided path before the
+    /// startup phase, and restore the original working directory after the
+    /// shutdown phase (or when a phase fails).
+    pub fn with_working_dir(mut self, path: impl AsRef<Path>) -> Self {
+        self.working_dir = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Set the process-wide color mode, which controls whether colors are applied
+    /// by `starbase_styles` painting functions and `starbase_console` components.
+    /// Typically called with the value of a `--color` or `--no-color` argument.
+    /// Consoles that were created before this call also apply the new mode,
+    /// as it's read when a theme is requested or an element is rendered.
+    pub fn set_color_mode(&self, mode: ColorMode) {
+        trace!(?mode, "Setting color mode");
+
+        starbase_styles::color::set_color_mode(mode);
+    }
+
     /// Setup `miette` diagnostics by registering error and panic hooks.
     pub fn setup_diagnostics(&self) {
         crate::diagnostics::setup_miette();
@@ -65,6 +88,11 @@ impl App {
         F: FnOnce(S) -> Fut + Send + 'static,
         Fut: Future<Output = AppResult> + Send + 'static,
     {
+        let working_dir = match &self.working_dir {
+            Some(dir) => Some(WorkingDirGuard::change(dir)?),
+            None => None,
+        };
+
         // Startup
         if let Err(error) = self.run_startup(session).await {
             self.run_shutdown(session, Some(&error)).await?;
@@ -89,6 +117,10 @@ impl App {
         // Shutdown
         self.run_shutdown(session, None).await?;
 
+        if let Some(guard) = working_dir {
+            guard.restore()?;
+        }
+
         Ok(self.exit_code.unwrap_or_default())
     }
 
@@ -179,3 +211,72 @@ impl App {
         }
     }
 }
+
+// Restores the original working directory when dropped, so that
+// it's also restored when a phase fails and returns early.
+struct WorkingDirGuard {
+    original: Option<PathBuf>,
+}
+
+impl WorkingDirGuard {
+    fn change(dir: &Path) -> miette::Result<Self> {
+        let original = env::current_dir().map_err(|error| WorkingDirError::Current {
+            error: Box::new(error),
+        })?;
+
+        trace!(dir = ?dir, original = ?original, "Changing working directory");
+
+        set_working_dir(dir)?;
+
+        Ok(Self {
+            original: Some(original),
+        })
+    }
+
+    fn restore(mut self) -> miette::Result<()> {
+        match self.original.take() {
+            Some(original) => {
+                trace!(dir = ?original, "Restoring working directory");
+
+                set_working_dir(&original)
+            }
+            None => Ok(()),
+        }
+    }
+}
+
+impl Drop for WorkingDirGuard {
+    fn drop(&mut self) {
+        if let Some(original) = self.original.take() {
+            let _ = env::set_current_dir(original);
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error, miette::Diagnostic)]
+enum WorkingDirError {
+    #[diagnostic(code(app::working_dir))]
+    #[error("Failed to determine current working directory.")]
+    Current {
+        #[source]
+        error: Box<std::io::Error>,
+    },
+
+    #[diagnostic(code(app::working_dir))]
+    #[error("Failed to change working directory to {}.", .dir.display())]
+    Change {
+        dir: PathBuf,
+        #[source]
+        error: Box<std::io::Error>,
+    },
+}
+
+fn set_working_dir(dir: &Path) -> miette::Result<()> {
+    env::set_current_dir(dir).map_err(|error| {
+        WorkingDirError::Change {
+            dir: dir.to_path_buf(),
+            error: Box::new(error),
+        }
+        .into()
+    })
+}
@@ -16,3 +16,75 @@ pub fn setup_miette() {
     }))
     .unwrap();
 }
+
+/// The default diagnostic code used when wrapping non-diagnostic errors.
+pub const DEFAULT_ERROR_CODE: &str = "app::error";
+
+type BoxedError = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+/// A diagnostic that wraps any standard error (`std::io::Error`, `thiserror`
+/// enums, `anyhow::Error`, etc), assigns it a diagnostic code, and preserves
+/// its source chain, so that it renders through `miette` like any other
+/// diagnostic.
+#[derive(Debug)]
+pub struct AppError {
+    code: String,
+    error: BoxedError,
+}
+
+impl AppError {
+    /// Wrap the provided error with the default code.
+    pub fn new(error: impl Into<BoxedError>) -> Self {
+        Self::with_code(error, DEFAULT_ERROR_CODE)
+    }
+
+    /// Wrap the provided error with a custom code.
+    pub fn with_code(error: impl Into<BoxedError>, code: impl AsRef<str>) -> Self {
+        Self {
+            code: code.as_ref().to_owned(),
+            error: error.into(),
+        }
+    }
+
+    /// Return the wrapped error.
+    pub fn inner(&self) -> &(dyn std::error::Error + Send + Sync + 'static) {
+        self.error.as_ref()
+    }
+}
+
+impl std::fmt::Display for AppError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.error, f)
+    }
+}
+
+impl std::error::Error for AppError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.error.source()
+    }
+}
+
+impl Diagnostic for AppError {
+    fn code<'a>(&'a self) -> Option<Box<dyn std::fmt::Display + 'a>> {
+        Some(Box::new(&self.code))
+    }
+}
+
+/// Convert the error of a result into an [`AppError`] diagnostic.
+pub trait IntoAppError<T> {
+    /// Wrap the error with the default code.
+    fn into_app_error(self) -> miette::Result<T>;
+
+    /// Wrap the error with a custom code.
+    fn into_app_error_with_code(self, code: &str) -> miette::Result<T>;
+}
+
+impl<T, E: Into<BoxedError>> IntoAppError<T> for Result<T, E> {
+    fn into_app_error(self) -> miette::Result<T> {
+        self.map_err(|error| AppError::new(error).into())
+    }
+
+    fn into_app_error_with_code(self, code: &str) -> miette::Result<T> {
+        self.map_err(|error| AppError::with_code(error, code).into())
+    }
+}
@@ -1,3 +1,7 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
 pub type AppResult = miette::Result<Option<u8>>;
 
 #[async_trait::async_trait]
@@ -25,3 +29,112 @@ pub trait AppSession: Clone + Send + Sync {
         Ok(None)
     }
 }
+
+type PhaseCallback<T> =
+    Arc<dyn Fn(T) -> Pin<Box<dyn Future<Output = AppResult> + Send>> + Send + Sync>;
+
+/// A session that runs closures in each phase, instead of requiring a custom
+/// [`AppSession`] implementation. Each closure receives a clone of the provided
+/// state, so state that needs to be mutated across phases should use interior
+/// mutability (for example, an `Arc<RwLock<T>>`).
+#[derive(Clone)]
+pub struct CallbackSession<T> {
+    state: T,
+    startup: Option<PhaseCallback<T>>,
+    analyze: Option<PhaseCallback<T>>,
+    execute: Option<PhaseCallback<T>>,
+    shutdown: Option<PhaseCallback<T>>,
+}
+
+impl<T: Clone + Send + Sync + 'static> CallbackSession<T> {
+    /// Create a new session with the provided state, and no callbacks.
+    pub fn new(state: T) -> Self {
+        Self {
+            state,
+            startup: None,
+            analyze: None,
+            execute: None,
+            shutdown: None,
+        }
+    }
+
+    /// Return the state of the session.
+    pub fn state(&self) -> &T {
+        &self.state
+    }
+
+    /// Run the callback in the startup phase. See [`AppSession::startup`].
+    pub fn on_startup<F, Fut>(mut self, callback: F) -> Self
+    where
+        F: Fn(T) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = AppResult> + Send + 'static,
+    {
+        self.startup = Some(Self::wrap(callback));
+        self
+    }
+
+    /// Run the callback in the analyze phase. See [`AppSession::analyze`].
+    pub fn on_analyze<F, Fut>(mut self, callback: F) -> Self
+    where
+        F: Fn(T) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = AppResult> + Send + 'static,
+    {
+        self.analyze = Some(Self::wrap(callback));
+        self
+    }
+
+    /// Run the callback in the background of the execute phase.
+    /// See [`AppSession::execute`].
+    pub fn on_execute<F, Fut>(mut self, callback: F) -> Self
+    where
+        F: Fn(T) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = AppResult> + Send + 'static,
+    {
+        self.execute = Some(Self::wrap(callback));
+        self
+    }
+
+    /// Run the callback in the shutdown phase. See [`AppSession::shutdown`].
+    pub fn on_shutdown<F, Fut>(mut self, callback: F) -> Self
+    where
+        F: Fn(T) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = AppResult> + Send + 'static,
+    {
+        self.shutdown = Some(Self::wrap(callback));
+        self
+    }
+
+    fn wrap<F, Fut>(callback: F) -> PhaseCallback<T>
+    where
+        F: Fn(T) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = AppResult> + Send + 'static,
+    {
+        Arc::new(move |state| Box::pin(callback(state)))
+    }
+
+    async fn run_callback(&self, callback: Option<&PhaseCallback<T>>) -> AppResult {
+        match callback {
+            Some(callback) => callback(self.state.clone()).await,
+            None => Ok(None),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl<T: Clone + Send + Sync + 'static> AppSession for CallbackSession<T> {
+    async fn startup(&mut self) -> AppResult {
+        self.run_callback(self.startup.as_ref()).await
+    }
+
+    async fn analyze(&mut self) -> AppResult {
+        self.run_callback(self.analyze.as_ref()).await
+    }
+
+    async fn execute(&mut self) -> AppResult {
+        self.run_callback(self.execute.as_ref()).await
+    }
+
+    async fn shutdown(&mut self) -> AppResult {
+        self.run_callback(self.shutdown.as_ref()).await
+    }
+}
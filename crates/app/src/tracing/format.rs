@@ -1,6 +1,6 @@
 use chrono::{Local, Timelike};
 use starbase_styles::color;
-use starbase_styles::color::apply_style_tags;
+use starbase_styles::color::write_style_tags;
 use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
 use tracing::{field::Visit, metadata::LevelFilter, Level, Metadata, Subscriber};
 use tracing_subscriber::{
@@ -16,10 +16,18 @@ struct FieldVisitor<'writer> {
     writer: fmt::format::Writer<'writer>,
 }
 
+impl FieldVisitor<'_> {
+    fn write_message(&mut self, value: &str) {
+        self.writer.write_str("  ").unwrap();
+        write_style_tags(&mut self.writer, value).unwrap();
+        self.writer.write_str(" ").unwrap();
+    }
+}
+
 impl Visit for FieldVisitor<'_> {
     fn record_str(&mut self, field: &tracing::field::Field, value: &str) {
         if field.name() == "message" {
-            self.record_debug(field, &format_args!("{}", value))
+            self.write_message(value)
         } else {
             self.record_debug(field, &value)
         }
@@ -27,12 +35,7 @@ impl Visit for FieldVisitor<'_> {
 
     fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
         if field.name() == "message" {
-            write!(
-                self.writer,
-                "  {} ",
-                apply_style_tags(format!("{:?}", value))
-            )
-            .unwrap()
+            self.write_message(&format!("{:?}", value));
         } else {
             write!(
                 self.writer,
@@ -61,6 +64,7 @@ impl<'writer> FormatFields<'writer> for FieldFormatter {
 }
 
 pub struct EventFormatter {
+    pub full_timestamp: bool,
     pub show_spans: bool,
 }
 
@@ -74,10 +78,12 @@ impl FormatTime for EventFormatter {
         let current_timestamp = Local::now();
         let current_hour = current_timestamp.hour() as u8;
 
-        if current_hour == LAST_HOUR.load(Ordering::Acquire) {
-            date_format = "%H:%M:%S%.3f";
-        } else {
-            LAST_HOUR.store(current_hour, Ordering::Release);
+        if !self.full_timestamp {
+            if current_hour == LAST_HOUR.load(Ordering::Acquire) {
+                date_format = "%H:%M:%S%.3f";
+            } else {
+                LAST_HOUR.store(current_hour, Ordering::Release);
+            }
         }
 
         write!(
@@ -164,3 +170,44 @@ where
         writeln!(writer)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn format_time(formatter: &EventFormatter) -> String {
+        let mut buffer = String::new();
+        let mut writer = fmt::format::Writer::new(&mut buffer);
+
+        formatter.format_time(&mut writer).unwrap();
+
+        buffer
+    }
+
+    #[test]
+    fn truncates_date_within_same_hour() {
+        let formatter = EventFormatter {
+            full_timestamp: false,
+            show_spans: false,
+        };
+
+        let date = Local::now().format("%Y-%m-%d").to_string();
+
+        LAST_HOUR.store(Local::now().hour() as u8, Ordering::Release);
+
+        assert!(!format_time(&formatter).contains(&date));
+    }
+
+    #[test]
+    fn always_includes_date_when_full() {
+        let formatter = EventFormatter {
+            full_timestamp: true,
+            show_spans: false,
+        };
+
+        let date = Local::now().format("%Y-%m-%d").to_string();
+
+        assert!(format_time(&formatter).contains(&date));
+        assert!(format_time(&formatter).contains(&date));
+    }
+}
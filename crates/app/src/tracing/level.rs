@@ -3,7 +3,7 @@ use std::fmt;
 use std::str::FromStr;
 
 // This is similar to tracing `Level` but provides an "Off" variant.
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub enum LogLevel {
     Off,
     Error,
@@ -14,6 +14,29 @@ pub enum LogLevel {
     Trace,
 }
 
+impl LogLevel {
+    /// Map a verbosity count (typically the number of `-v` flags)
+    /// to a log level. 0 = info, 1 = debug, and 2+ = trace.
+    pub fn from_verbosity(count: u8) -> Self {
+        match count {
+            0 => Self::Info,
+            1 => Self::Debug,
+            _ => Self::Trace,
+        }
+    }
+
+    /// Map a quietness count (typically the number of `-q` flags)
+    /// to a log level. 0 = info, 1 = warn, 2 = error, and 3+ = off.
+    pub fn from_quiet(count: u8) -> Self {
+        match count {
+            0 => Self::Info,
+            1 => Self::Warn,
+            2 => Self::Error,
+            _ => Self::Off,
+        }
+    }
+}
+
 impl fmt::Display for LogLevel {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
         write!(
@@ -1,7 +1,10 @@
 mod format;
 mod level;
+mod summary;
 
 use crate::tracing::format::*;
+use crate::tracing::summary::SpanSummaryLayer;
+use starbase_console::{ConsoleStream, ConsoleStreamType};
 use std::fs::File;
 use std::io;
 use std::path::PathBuf;
@@ -15,15 +18,20 @@ use tracing_subscriber::fmt::{self, SubscriberBuilder};
 use tracing_subscriber::{prelude::*, EnvFilter};
 
 pub use crate::tracing::level::LogLevel;
+pub use crate::tracing::summary::{SpanStat, SpanSummary};
 pub use tracing::{
     debug, debug_span, enabled, error, error_span, event, event_enabled, info, info_span,
     instrument, span, span_enabled, trace, trace_span, warn, warn_span,
 };
 
 pub struct TracingOptions {
+    /// Always include the date in log timestamps, instead of
+    /// only the time once the hour has been logged.
+    pub always_show_full_timestamp: bool,
     /// Minimum level of messages to display.
     pub default_level: LogLevel,
     /// Dump a trace file that can be viewed in Chrome.
+    /// Will write to `trace_file` if provided.
     pub dump_trace: bool,
     /// List of modules/prefixes to only log.
     pub filter_modules: Vec<String>,
@@ -37,13 +45,20 @@ pub struct TracingOptions {
     pub log_file: Option<PathBuf>,
     /// Show span hierarchy in log output.
     pub show_spans: bool,
+    /// Render a summary of the slowest spans to the `stderr` console stream
+    /// when the guard is dropped.
+    pub summarize_spans: bool,
     /// Name of the testing environment variable.
     pub test_env: String,
+    /// Absolute path to a file to write the Chrome trace to when `dump_trace`
+    /// is enabled. Defaults to `./dump-<micros>.json`.
+    pub trace_file: Option<PathBuf>,
 }
 
 impl Default for TracingOptions {
     fn default() -> Self {
         TracingOptions {
+            always_show_full_timestamp: false,
             default_level: LogLevel::Info,
             dump_trace: false,
             filter_modules: vec![],
@@ -52,7 +67,9 @@ impl Default for TracingOptions {
             log_env: "STARBASE_LOG".into(),
             log_file: None,
             show_spans: false,
+            summarize_spans: false,
             test_env: "STARBASE_TEST".into(),
+            trace_file: None,
         }
     }
 }
@@ -60,6 +77,26 @@ impl Default for TracingOptions {
 pub struct TracingGuard {
     chrome_guard: Option<FlushGuard>,
     log_file: Option<Arc<File>>,
+    span_summary: Option<SpanSummary>,
+}
+
+impl TracingGuard {
+    /// Return the span summary, if `summarize_spans` was enabled.
+    pub fn span_summary(&self) -> Option<&SpanSummary> {
+        self.span_summary.as_ref()
+    }
+}
+
+impl Drop for TracingGuard {
+    fn drop(&mut self) {
+        if let Some(summary) = &self.span_summary {
+            let stream = ConsoleStream::empty(ConsoleStreamType::Stderr);
+
+            if summary.render_to(&stream, 20).is_ok() {
+                let _ = stream.flush();
+            }
+        }
+    }
 }
 
 #[tracing::instrument(skip_all)]
@@ -95,6 +132,7 @@ pub fn setup_tracing(options: TracingOptions) -> TracingGuard {
     // Build our subscriber
     let subscriber = SubscriberBuilder::default()
         .event_format(EventFormatter {
+            full_timestamp: options.always_show_full_timestamp,
             show_spans: options.show_spans,
         })
         .fmt_fields(FieldFormatter)
@@ -106,6 +144,7 @@ pub fn setup_tracing(options: TracingOptions) -> TracingGuard {
     let mut guard = TracingGuard {
         chrome_guard: None,
         log_file: None,
+        span_summary: None,
     };
 
     let _ = set_global_default(
@@ -126,13 +165,24 @@ pub fn setup_tracing(options: TracingOptions) -> TracingGuard {
             })
             // Dump a trace profile
             .with(if options.dump_trace {
+                let trace_file = match options.trace_file {
+                    Some(trace_file) => {
+                        if let Some(dir) = trace_file.parent() {
+                            fs::create_dir_all(dir).expect("Failed to create trace directory.");
+                        }
+
+                        trace_file
+                    }
+                    None => PathBuf::from(format!(
+                        "./dump-{}.json",
+                        SystemTime::UNIX_EPOCH.elapsed().unwrap().as_micros()
+                    )),
+                };
+
                 let (chrome_layer, chrome_guard) = ChromeLayerBuilder::new()
                     .include_args(true)
                     .include_locations(true)
-                    .file(format!(
-                        "./dump-{}.json",
-                        SystemTime::UNIX_EPOCH.elapsed().unwrap().as_micros()
-                    ))
+                    .file(trace_file)
                     .build();
 
                 guard.chrome_guard = Some(chrome_guard);
@@ -140,6 +190,16 @@ pub fn setup_tracing(options: TracingOptions) -> TracingGuard {
                 Some(chrome_layer)
             } else {
                 None
+            })
+            // Aggregate span timings
+            .with(if options.summarize_spans {
+                let summary = SpanSummary::default();
+
+                guard.span_summary = Some(summary.clone());
+
+                Some(SpanSummaryLayer { summary })
+            } else {
+                None
             }),
     );
 
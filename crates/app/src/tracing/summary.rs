@@ -0,0 +1,185 @@
+use starbase_console::ConsoleStream;
+use starbase_styles::color;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tracing::span::{Attributes, Id};
+use tracing::Subscriber;
+use tracing_subscriber::layer::Context;
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::Layer;
+
+/// Aggregated timings for all spans with the same name.
+#[derive(Clone, Debug, Default)]
+pub struct SpanStat {
+    /// Name of the span, prefixed with its target.
+    pub name: String,
+    /// Number of times the span was closed.
+    pub count: u64,
+    /// Total time spent within the span.
+    pub total: Duration,
+}
+
+impl SpanStat {
+    /// Return the average time spent within the span per call.
+    pub fn average(&self) -> Duration {
+        if self.count == 0 {
+            Duration::ZERO
+        } else {
+            self.total.div_f64(self.count as f64)
+        }
+    }
+}
+
+/// A collection of span timings, shared between the tracing layer
+/// and the guard that renders it.
+#[derive(Clone, Default)]
+pub struct SpanSummary {
+    stats: Arc<Mutex<HashMap<String, SpanStat>>>,
+}
+
+impl SpanSummary {
+    /// Return all span timings, sorted by total duration (slowest first).
+    pub fn get_stats(&self) -> Vec<SpanStat> {
+        let mut stats = self
+            .stats
+            .lock()
+            .unwrap()
+            .values()
+            .cloned()
+            .collect::<Vec<_>>();
+
+        stats.sort_by(|a, b| b.total.cmp(&a.total).then_with(|| a.name.cmp(&b.name)));
+        stats
+    }
+
+    /// Render a table of the slowest spans, up to the provided limit.
+    pub fn render(&self, limit: usize) -> String {
+        let stats = self.get_stats();
+
+        if stats.is_empty() {
+            return String::new();
+        }
+
+        let rows = stats
+            .iter()
+            .take(limit)
+            .map(|stat| {
+                (
+                    stat.name.as_str(),
+                    stat.count.to_string(),
+                    format_duration(stat.total),
+                    format_duration(stat.average()),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let name_width = rows
+            .iter()
+            .map(|row| color::display_width(row.0))
+            .max()
+            .unwrap_or(0)
+            .max(4);
+        let count_width = rows.iter().map(|row| row.1.len()).max().unwrap_or(0).max(5);
+        let total_width = rows.iter().map(|row| row.2.len()).max().unwrap_or(0).max(5);
+        let avg_width = rows.iter().map(|row| row.3.len()).max().unwrap_or(0).max(3);
+
+        let mut output = vec![color::muted(format!(
+            "{:<name_width$}  {:>count_width$}  {:>total_width$}  {:>avg_width$}",
+            "Span", "Calls", "Total", "Avg"
+        ))];
+
+        for (name, count, total, avg) in rows {
+            output.push(format!(
+                "{}  {:>count_width$}  {:>total_width$}  {}",
+                color::property(format!(
+                    "{name}{}",
+                    " ".repeat(name_width - color::display_width(name))
+                )),
+                count,
+                total,
+                color::muted_light(format!("{avg:>avg_width$}")),
+            ));
+        }
+
+        output.join("\n")
+    }
+
+    /// Render a table of the slowest spans, up to the provided limit,
+    /// and write it to the provided console stream.
+    pub fn render_to(&self, stream: &ConsoleStream, limit: usize) -> miette::Result<()> {
+        let output = self.render(limit);
+
+        if output.is_empty() {
+            return Ok(());
+        }
+
+        stream.write_line(output)
+    }
+
+    fn record(&self, name: String, duration: Duration) {
+        let mut stats = self.stats.lock().unwrap();
+        let stat = stats.entry(name).or_insert_with_key(|name| SpanStat {
+            name: name.to_owned(),
+            ..SpanStat::default()
+        });
+
+        stat.count += 1;
+        stat.total += duration;
+    }
+}
+
+fn format_duration(duration: Duration) -> String {
+    format!("{:.3}ms", duration.as_secs_f64() * 1000.0)
+}
+
+#[derive(Default)]
+struct SpanTiming {
+    busy: Duration,
+    entered: Option<Instant>,
+}
+
+/// A tracing layer that aggregates the time spent within each span.
+pub(crate) struct SpanSummaryLayer {
+    pub summary: SpanSummary,
+}
+
+impl<S> Layer<S> for SpanSummaryLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, _attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        if let Some(span) = ctx.span(id) {
+            span.extensions_mut().insert(SpanTiming::default());
+        }
+    }
+
+    fn on_enter(&self, id: &Id, ctx: Context<'_, S>) {
+        if let Some(span) = ctx.span(id) {
+            if let Some(timing) = span.extensions_mut().get_mut::<SpanTiming>() {
+                timing.entered = Some(Instant::now());
+            }
+        }
+    }
+
+    fn on_exit(&self, id: &Id, ctx: Context<'_, S>) {
+        if let Some(span) = ctx.span(id) {
+            if let Some(timing) = span.extensions_mut().get_mut::<SpanTiming>() {
+                if let Some(entered) = timing.entered.take() {
+                    timing.busy += entered.elapsed();
+                }
+            }
+        }
+    }
+
+    fn on_close(&self, id: Id, ctx: Context<'_, S>) {
+        if let Some(span) = ctx.span(&id) {
+            if let Some(timing) = span.extensions_mut().remove::<SpanTiming>() {
+                let meta = span.metadata();
+
+                self.summary
+                    .record(format!("{}::{}", meta.target(), meta.name()), timing.busy);
+            }
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use miette::bail;
+use starbase::{App, CallbackSession};
+use std::sync::{Arc, Mutex};
+
+type Order = Arc<Mutex<Vec<&'static str>>>;
+
+fn create_session(order: Order) -> CallbackSession<Order> {
+    CallbackSession::new(order)
+        .on_startup(|order: Order| async move {
+            order.lock().unwrap().push("startup");
+            Ok(None)
+        })
+        .on_analyze(|order: Order| async move {
+            order.lock().unwrap().push("analyze");
+            Ok(None)
+        })
+        .on_execute(|order: Order| async move {
+            order.lock().unwrap().push("execute");
+            Ok(None)
+        })
+        .on_shutdown(|order: Order| async move {
+            order.lock().unwrap().push("shutdown");
+            Ok(None)
+        })
+}
+
+mod callback_session {
+    use super::*;
+
+    #[tokio::test]
+    async fn runs_callbacks_in_order() {
+        let order = Order::default();
+
+        let code = App::default()
+            .run(create_session(order.clone()), |session| async move {
+                // Wait for the background execute to avoid a race
+                while !session.state().lock().unwrap().contains(&"execute") {
+                    tokio::task::yield_now().await;
+                }
+
+                session.state().lock().unwrap().push("main");
+                Ok(None)
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(code, 0);
+        assert_eq!(
+            *order.lock().unwrap(),
+            vec!["startup", "analyze", "execute", "main", "shutdown"]
+        );
+    }
+
+    #[tokio::test]
+    async fn skips_missing_callbacks() {
+        let order = Order::default();
+        let session = CallbackSession::new(order.clone()).on_shutdown(|order: Order| async move {
+            order.lock().unwrap().push("shutdown");
+            Ok(Some(2))
+        });
+
+        let code = App::default()
+            .run(session, |_| async { Ok(None) })
+            .await
+            .unwrap();
+
+        assert_eq!(code, 2);
+        assert_eq!(*order.lock().unwrap(), vec!["shutdown"]);
+    }
+
+    #[tokio::test]
+    async fn runs_shutdown_on_failure() {
+        let order = Order::default();
+        let session = create_session(order.clone()).on_analyze(|_| async { bail!("failed") });
+
+        let error = App::default()
+            .run(session, |_| async { Ok(None) })
+            .await
+            .unwrap_err();
+
+        assert_eq!(error.to_string(), "failed");
+        assert_eq!(*order.lock().unwrap(), vec!["startup", "shutdown"]);
+    }
+}
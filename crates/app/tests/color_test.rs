@@ -0,0 +1,38 @@
+use starbase::style::color::{get_color_mode, no_color, ColorMode};
+use starbase::App;
+use starbase_console::ui::{create_result_notice, ConsoleTheme, Variant};
+use starbase_console::{Console, EmptyReporter};
+
+// The color mode is process-wide, so all modes are checked within a single test.
+#[test]
+fn sets_color_mode_for_styles_and_console() {
+    let app = App::default();
+    let console = Console::<EmptyReporter>::new_testing();
+
+    app.set_color_mode(ColorMode::Never);
+
+    assert_eq!(get_color_mode(), ColorMode::Never);
+    assert!(no_color());
+    assert!(!ConsoleTheme::default().supports_color);
+    assert!(!console.theme().supports_color);
+
+    let never_output = render(&console);
+
+    app.set_color_mode(ColorMode::Always);
+
+    assert_eq!(get_color_mode(), ColorMode::Always);
+    assert!(!no_color());
+    assert!(ConsoleTheme::default().supports_color);
+    assert!(console.theme().supports_color);
+    assert_ne!(render(&console), never_output);
+
+    app.set_color_mode(ColorMode::Auto);
+
+    assert_eq!(get_color_mode(), ColorMode::Auto);
+}
+
+fn render(console: &Console<EmptyReporter>) -> String {
+    console
+        .render_to_string(create_result_notice(Variant::Success, "hello"))
+        .unwrap()
+}
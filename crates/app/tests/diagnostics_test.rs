@@ -0,0 +1,77 @@
+use async_trait::async_trait;
+use miette::{GraphicalReportHandler, GraphicalTheme, Report};
+use starbase::diagnostics::{AppError, IntoAppError, DEFAULT_ERROR_CODE};
+use starbase::{App, AppResult, AppSession};
+use std::io;
+
+#[derive(Clone, Debug, Default)]
+struct IoErrorSession;
+
+#[async_trait]
+impl AppSession for IoErrorSession {
+    async fn startup(&mut self) -> AppResult {
+        std::fs::read_to_string("missing-file-that-does-not-exist.txt").into_app_error()?;
+
+        Ok(None)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error("Outer failure.")]
+struct OuterError {
+    #[source]
+    error: io::Error,
+}
+
+fn render(report: &Report) -> String {
+    let mut out = String::new();
+
+    GraphicalReportHandler::new_themed(GraphicalTheme::unicode_nocolor())
+        .render_report(&mut out, report.as_ref())
+        .unwrap();
+
+    out
+}
+
+#[tokio::test]
+async fn wraps_io_errors_from_sessions() {
+    let error = App::default()
+        .run(IoErrorSession, |_| async { Ok(None) })
+        .await
+        .unwrap_err();
+
+    assert_eq!(
+        error.code().map(|code| code.to_string()),
+        Some(DEFAULT_ERROR_CODE.to_owned())
+    );
+    assert!(error.downcast_ref::<AppError>().is_some());
+
+    let output = render(&error);
+
+    assert!(output.contains("app::error"));
+    assert!(output.contains("No such file or directory") || output.contains("cannot find"));
+}
+
+#[test]
+fn supports_custom_codes() {
+    let result: Result<(), io::Error> = Err(io::Error::other("boom"));
+    let error = result.into_app_error_with_code("custom::code").unwrap_err();
+
+    assert_eq!(
+        error.code().map(|code| code.to_string()),
+        Some("custom::code".to_owned())
+    );
+    assert!(render(&error).contains("boom"));
+}
+
+#[test]
+fn preserves_source_chain() {
+    let error = Report::new(AppError::new(OuterError {
+        error: io::Error::other("inner failure"),
+    }));
+
+    let output = render(&error);
+
+    assert!(output.contains("Outer failure."));
+    assert!(output.contains("inner failure"));
+}
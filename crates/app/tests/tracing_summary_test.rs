@@ -0,0 +1,47 @@
+use starbase::tracing::{instrument, setup_tracing, TracingOptions};
+use starbase_console::{ConsoleStream, ConsoleStreamType};
+
+#[instrument]
+fn instrumented_work() {
+    std::thread::sleep(std::time::Duration::from_millis(5));
+}
+
+#[instrument(name = "幅広い")]
+fn wide_work() {}
+
+#[test]
+fn summarizes_spans() {
+    let guard = setup_tracing(TracingOptions {
+        summarize_spans: true,
+        ..TracingOptions::default()
+    });
+
+    instrumented_work();
+    instrumented_work();
+    wide_work();
+
+    let summary = guard.span_summary().unwrap();
+    let stat = summary
+        .get_stats()
+        .into_iter()
+        .find(|stat| stat.name.ends_with("::instrumented_work"))
+        .unwrap();
+
+    assert_eq!(stat.count, 2);
+    assert!(stat.average().as_millis() >= 5);
+    assert!(summary.render(10).contains("instrumented_work"));
+
+    // Wide characters are aligned by their display width
+    let rendered = summary.render(10);
+    let widths = rendered
+        .lines()
+        .map(starbase_styles::color::display_width)
+        .collect::<Vec<_>>();
+
+    assert!(widths.iter().all(|width| *width == widths[0]));
+
+    let stream = ConsoleStream::new_captured(ConsoleStreamType::Stderr);
+    summary.render_to(&stream, 10).unwrap();
+
+    assert!(stream.captured_output().contains("instrumented_work"));
+}
@@ -0,0 +1,50 @@
+use starbase::tracing::LogLevel;
+
+mod log_level {
+    use super::*;
+
+    #[test]
+    fn from_verbosity() {
+        assert_eq!(LogLevel::from_verbosity(0), LogLevel::Info);
+        assert_eq!(LogLevel::from_verbosity(1), LogLevel::Debug);
+        assert_eq!(LogLevel::from_verbosity(2), LogLevel::Trace);
+        assert_eq!(LogLevel::from_verbosity(5), LogLevel::Trace);
+    }
+
+    #[test]
+    fn from_quiet() {
+        assert_eq!(LogLevel::from_quiet(0), LogLevel::Info);
+        assert_eq!(LogLevel::from_quiet(1), LogLevel::Warn);
+        assert_eq!(LogLevel::from_quiet(2), LogLevel::Error);
+        assert_eq!(LogLevel::from_quiet(3), LogLevel::Off);
+        assert_eq!(LogLevel::from_quiet(10), LogLevel::Off);
+    }
+
+    #[test]
+    fn parses_strings() {
+        assert_eq!("DEBUG".parse::<LogLevel>().unwrap(), LogLevel::Debug);
+        assert_eq!(LogLevel::try_from("off").unwrap(), LogLevel::Off);
+        assert!(LogLevel::try_from("unknown".to_owned()).is_err());
+    }
+}
+
+mod setup {
+    use starbase::tracing::{setup_tracing, TracingOptions};
+    use starbase_sandbox::create_empty_sandbox;
+
+    #[test]
+    fn writes_trace_to_custom_path() {
+        let sandbox = create_empty_sandbox();
+        let trace_file = sandbox.path().join("traces/nested/profile.json");
+
+        let guard = setup_tracing(TracingOptions {
+            dump_trace: true,
+            trace_file: Some(trace_file.clone()),
+            ..TracingOptions::default()
+        });
+
+        drop(guard);
+
+        assert!(trace_file.exists());
+    }
+}
@@ -0,0 +1,53 @@
+use miette::bail;
+use starbase::{App, CallbackSession};
+use starbase_sandbox::create_empty_sandbox;
+use std::env;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+// Changing the working directory affects the entire process,
+// so these are run in a single test to avoid races.
+#[tokio::test]
+async fn changes_and_restores_working_dir() {
+    let sandbox = create_empty_sandbox();
+    let dir = sandbox.path().canonicalize().unwrap();
+    let original = env::current_dir().unwrap();
+    let during: Arc<Mutex<Option<PathBuf>>> = Arc::default();
+
+    // Success
+    let session = CallbackSession::new(during.clone()).on_execute(|during| async move {
+        *during.lock().unwrap() = Some(env::current_dir().unwrap());
+        Ok(None)
+    });
+
+    App::default()
+        .with_working_dir(&dir)
+        .run(session, |_| async { Ok(None) })
+        .await
+        .unwrap();
+
+    assert_eq!(during.lock().unwrap().take().unwrap(), dir);
+    assert_eq!(env::current_dir().unwrap(), original);
+
+    // Failure
+    let session = CallbackSession::new(()).on_analyze(|_| async { bail!("failed") });
+
+    App::default()
+        .with_working_dir(&dir)
+        .run(session, |_| async { Ok(None) })
+        .await
+        .unwrap_err();
+
+    assert_eq!(env::current_dir().unwrap(), original);
+
+    // Missing directory
+    let error = App::default()
+        .with_working_dir(dir.join("missing"))
+        .run(CallbackSession::new(()), |_| async { Ok(None) })
+        .await
+        .unwrap_err();
+
+    assert_eq!(error.code().unwrap().to_string(), "app::working_dir");
+    assert!(error.chain().nth(1).is_some());
+    assert_eq!(env::current_dir().unwrap(), original);
+}
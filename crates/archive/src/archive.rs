@@ -3,6 +3,8 @@ use crate::tree_differ::TreeDiffer;
 use crate::{get_full_file_extension, join_file_name};
 use rustc_hash::{FxHashMap, FxHashSet};
 use starbase_utils::glob;
+#[cfg(feature = "checksum")]
+use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 use tracing::{instrument, trace};
 
@@ -29,8 +31,30 @@ pub trait ArchiveUnpacker {
     /// Unpack the archive to the destination directory. If a prefix is provided,
     /// remove it from the start of all file paths within the archive.
     fn unpack(&mut self, prefix: &str, differ: &mut TreeDiffer) -> ArchiveResult<PathBuf>;
+
+    /// Read the contents of the entry with the provided name, without
+    /// unpacking the entire archive. Returns `None` if the entry does not exist.
+    /// Errors by default, as not all unpackers support reading entries.
+    fn read_entry(&mut self, name: &str) -> ArchiveResult<Option<Vec<u8>>> {
+        Err(ArchiveError::ReadEntryNotSupported {
+            name: name.to_owned(),
+        }
+        .into())
+    }
+}
+
+impl<T: ArchiveUnpacker + ?Sized> ArchiveUnpacker for Box<T> {
+    fn unpack(&mut self, prefix: &str, differ: &mut TreeDiffer) -> ArchiveResult<PathBuf> {
+        (**self).unpack(prefix, differ)
+    }
+
+    fn read_entry(&mut self, name: &str) -> ArchiveResult<Option<Vec<u8>>> {
+        (**self).read_entry(name)
+    }
 }
 
+type OnFileFn<'callback> = &'callback mut dyn FnMut(&str, &Path) -> ArchiveResult<()>;
+
 /// An `Archiver` is an abstraction for packing and unpacking archives,
 /// that utilizes the same set of sources for both operations. For packing,
 /// the sources are the files that will be included in the archive. For unpacking,
@@ -40,6 +64,10 @@ pub struct Archiver<'owner> {
     /// The archive file itself (`.zip`, etc).
     archive_file: &'owner Path,
 
+    /// Name of a checksum manifest to include in the archive.
+    #[cfg(feature = "checksum")]
+    checksum_file: Option<&'owner str>,
+
     /// Prefix to append to all files.
     prefix: &'owner str,
 
@@ -59,6 +87,8 @@ impl<'owner> Archiver<'owner> {
     pub fn new(source_root: &'owner Path, archive_file: &'owner Path) -> Self {
         Archiver {
             archive_file,
+            #[cfg(feature = "checksum")]
+            checksum_file: None,
             prefix: "",
             source_files: FxHashMap::default(),
             source_globs: FxHashSet::default(),
@@ -101,6 +131,14 @@ impl<'owner> Archiver<'owner> {
         self
     }
 
+    /// Set the name of a `SHA256SUMS`-style manifest file that will be
+    /// included in the archive when packing with [`Archiver#pack_with_checksums`].
+    #[cfg(feature = "checksum")]
+    pub fn set_checksum_file(&mut self, name: &'owner str) -> &mut Self {
+        self.checksum_file = Some(name);
+        self
+    }
+
     /// Set the prefix to prepend to files wth when packing,
     /// and to remove when unpacking.
     pub fn set_prefix(&mut self, prefix: &'owner str) -> &mut Self {
@@ -114,6 +152,77 @@ impl<'owner> Archiver<'owner> {
     /// from this method.
     #[instrument(skip_all)]
     pub fn pack<F, P>(&self, packer: F) -> ArchiveResult<PathBuf>
+    where
+        F: FnOnce(&Path) -> ArchiveResult<P>,
+        P: ArchivePacker,
+    {
+        let mut archive = self.add_sources(packer, None)?;
+
+        archive.pack()?;
+
+        Ok(self.archive_file.to_path_buf())
+    }
+
+    /// Pack and create the archive with the added source, using the
+    /// provided packer factory, while computing a SHA-256 checksum for
+    /// every packed file. Returns the archive file path, and a mapping
+    /// of file names within the archive to their checksum.
+    ///
+    /// If a checksum file has been set with [`Archiver#set_checksum_file`],
+    /// the checksums will also be written into the archive as a manifest.
+    #[cfg(feature = "checksum")]
+    #[instrument(skip_all)]
+    pub fn pack_with_checksums<F, P>(
+        &self,
+        packer: F,
+    ) -> ArchiveResult<(PathBuf, BTreeMap<String, String>)>
+    where
+        F: FnOnce(&Path) -> ArchiveResult<P>,
+        P: ArchivePacker,
+    {
+        use starbase_utils::fs;
+
+        let mut checksums = BTreeMap::default();
+
+        let mut archive = self.add_sources(
+            packer,
+            Some(&mut |name, file| {
+                checksums.insert(name.to_owned(), fs::hash_file(file)?);
+
+                Ok(())
+            }),
+        )?;
+
+        if let Some(checksum_file) = self.checksum_file {
+            let mut manifest = String::new();
+
+            for (name, hash) in &checksums {
+                manifest.push_str(&format!("{hash}  {name}\n"));
+            }
+
+            let temp_file = self.archive_file.with_file_name(format!(
+                ".{}.{checksum_file}",
+                fs::file_name(self.archive_file)
+            ));
+
+            trace!(file = checksum_file, "Packing checksum manifest");
+
+            fs::write_file(&temp_file, manifest)?;
+
+            let result =
+                archive.add_file(&join_file_name([self.prefix, checksum_file]), &temp_file);
+
+            fs::remove_file(&temp_file)?;
+
+            result?;
+        }
+
+        archive.pack()?;
+
+        Ok((self.archive_file.to_path_buf(), checksums))
+    }
+
+    fn add_sources<F, P>(&self, packer: F, mut on_file: Option<OnFileFn>) -> ArchiveResult<P>
     where
         F: FnOnce(&Path) -> ArchiveResult<P>,
         P: ArchivePacker,
@@ -137,8 +246,27 @@ impl<'owner> Archiver<'owner> {
 
             if source.is_file() {
                 archive.add_file(&name, source)?;
+
+                if let Some(on_file) = &mut on_file {
+                    on_file(&name, source)?;
+                }
             } else {
                 archive.add_dir(&name, source)?;
+
+                if let Some(on_file) = &mut on_file {
+                    for entry in starbase_utils::fs::read_dir_all(source)? {
+                        let path = entry.path();
+
+                        // Archive entry names always use forward slashes
+                        let file_name = path
+                            .strip_prefix(source)
+                            .unwrap()
+                            .to_string_lossy()
+                            .replace('\\', "/");
+
+                        on_file(&join_file_name([name.as_str(), &file_name]), &path)?;
+                    }
+                }
             }
         }
 
@@ -151,23 +279,50 @@ impl<'owner> Archiver<'owner> {
                     .unwrap()
                     .to_str()
                     .unwrap();
+                let name = join_file_name([self.prefix, file_name]);
+
+                archive.add_file(&name, &file)?;
 
-                archive.add_file(&join_file_name([self.prefix, file_name]), &file)?;
+                if let Some(on_file) = &mut on_file {
+                    on_file(&name, &file)?;
+                }
             }
         }
 
-        archive.pack()?;
-
-        Ok(self.archive_file.to_path_buf())
+        Ok(archive)
     }
 
     /// Determine the packer to use based on the archive file extension,
     /// then pack the archive using [`Archiver#pack`].
+    // When no formats are enabled, every branch returns early
+    #[allow(unreachable_code, unused_variables)]
     pub fn pack_from_ext(&self) -> ArchiveResult<(String, PathBuf)> {
         let ext = get_full_file_extension(self.archive_file);
         let out = self.archive_file.to_path_buf();
 
         match ext.as_deref() {
+            Some("bz2") => {
+                #[cfg(feature = "bz2")]
+                self.pack(crate::bz2::Bz2Packer::new)?;
+
+                #[cfg(not(feature = "bz2"))]
+                return Err(ArchiveError::FeatureNotEnabled {
+                    feature: "bz2".into(),
+                    path: self.archive_file.to_path_buf(),
+                }
+                .into());
+            }
+            Some("xz") => {
+                #[cfg(feature = "xz")]
+                self.pack(crate::xz::XzPacker::new)?;
+
+                #[cfg(not(feature = "xz"))]
+                return Err(ArchiveError::FeatureNotEnabled {
+                    feature: "xz".into(),
+                    path: self.archive_file.to_path_buf(),
+                }
+                .into());
+            }
             Some("gz") => {
                 #[cfg(feature = "gz")]
                 self.pack(crate::gz::GzPacker::new)?;
@@ -303,116 +458,174 @@ impl<'owner> Archiver<'owner> {
     /// Returns an absolute path to the directory or file that was created,
     /// and the extension that was extracted from the input archive file.
     pub fn unpack_from_ext(&self) -> ArchiveResult<(String, PathBuf)> {
-        let ext = get_full_file_extension(self.archive_file);
-        let out;
+        let out = self.unpack(create_unpacker_from_ext)?;
 
-        match ext.as_deref() {
-            Some("gz") => {
-                #[cfg(feature = "gz")]
-                {
-                    out = self.unpack(crate::gz::GzUnpacker::new)?;
-                }
+        // An unknown extension would have failed to unpack
+        Ok((
+            get_full_file_extension(self.archive_file).unwrap_or_default(),
+            out,
+        ))
+    }
 
-                #[cfg(not(feature = "gz"))]
-                return Err(ArchiveError::FeatureNotEnabled {
-                    feature: "gz".into(),
-                    path: self.archive_file.to_path_buf(),
-                }
-                .into());
-            }
-            Some("tar") => {
-                #[cfg(feature = "tar")]
-                {
-                    out = self.unpack(crate::tar::TarUnpacker::new)?;
-                }
+    /// Read the contents of a single entry from the archive, using the
+    /// provided unpacker factory, without unpacking the entire archive.
+    /// The entry name is relative from the prefix, if one has been set.
+    /// Returns `None` if the entry does not exist in the archive.
+    #[instrument(skip(self, unpacker))]
+    pub fn read_entry_with<F, P>(&self, name: &str, unpacker: F) -> ArchiveResult<Option<Vec<u8>>>
+    where
+        F: FnOnce(&Path, &Path) -> ArchiveResult<P>,
+        P: ArchiveUnpacker,
+    {
+        trace!(
+            input_file = ?self.archive_file,
+            "Reading entry from archive",
+        );
 
-                #[cfg(not(feature = "tar"))]
-                return Err(ArchiveError::FeatureNotEnabled {
-                    feature: "tar".into(),
-                    path: self.archive_file.to_path_buf(),
-                }
-                .into());
-            }
-            Some("tar.bz2" | "tz2" | "tbz" | "tbz2") => {
-                #[cfg(feature = "tar-bz2")]
-                {
-                    out = self.unpack(crate::tar::TarUnpacker::new_bz2)?;
-                }
+        let mut archive = unpacker(self.source_root, self.archive_file)?;
 
-                #[cfg(not(feature = "tar-bz2"))]
-                return Err(ArchiveError::FeatureNotEnabled {
-                    feature: "tar-bz2".into(),
-                    path: self.archive_file.to_path_buf(),
-                }
-                .into());
-            }
-            Some("tar.gz" | "tgz") => {
-                #[cfg(feature = "tar-gz")]
-                {
-                    out = self.unpack(crate::tar::TarUnpacker::new_gz)?;
-                }
+        archive.read_entry(&join_file_name([self.prefix, name]))
+    }
 
-                #[cfg(not(feature = "tar-gz"))]
-                return Err(ArchiveError::FeatureNotEnabled {
-                    feature: "tar-gz".into(),
-                    path: self.archive_file.to_path_buf(),
-                }
-                .into());
-            }
-            Some("tar.xz" | "txz") => {
-                #[cfg(feature = "tar-xz")]
-                {
-                    out = self.unpack(crate::tar::TarUnpacker::new_xz)?;
-                }
+    /// Determine the unpacker to use based on the archive file extension,
+    /// then read a single entry using [`Archiver#read_entry_with`].
+    pub fn read_entry(&self, name: &str) -> ArchiveResult<Option<Vec<u8>>> {
+        self.read_entry_with(name, create_unpacker_from_ext)
+    }
+}
 
-                #[cfg(not(feature = "tar-xz"))]
-                return Err(ArchiveError::FeatureNotEnabled {
-                    feature: "tar-xz".into(),
-                    path: self.archive_file.to_path_buf(),
-                }
-                .into());
+// Determine the unpacker to create based on the input file extension.
+#[allow(unused_variables)]
+fn create_unpacker_from_ext(
+    output_dir: &Path,
+    input_file: &Path,
+) -> ArchiveResult<Box<dyn ArchiveUnpacker>> {
+    match get_full_file_extension(input_file).as_deref() {
+        Some("bz2") => {
+            #[cfg(feature = "bz2")]
+            return Ok(Box::new(crate::bz2::Bz2Unpacker::new(
+                output_dir, input_file,
+            )?));
+
+            #[cfg(not(feature = "bz2"))]
+            return Err(ArchiveError::FeatureNotEnabled {
+                feature: "bz2".into(),
+                path: input_file.to_path_buf(),
             }
-            Some("zst" | "zstd") => {
-                #[cfg(feature = "tar-zstd")]
-                {
-                    out = self.unpack(crate::tar::TarUnpacker::new_zstd)?;
-                }
-
-                #[cfg(not(feature = "tar-zstd"))]
-                return Err(ArchiveError::FeatureNotEnabled {
-                    feature: "tar-zstd".into(),
-                    path: self.archive_file.to_path_buf(),
-                }
-                .into());
+            .into());
+        }
+        Some("xz") => {
+            #[cfg(feature = "xz")]
+            return Ok(Box::new(crate::xz::XzUnpacker::new(
+                output_dir, input_file,
+            )?));
+
+            #[cfg(not(feature = "xz"))]
+            return Err(ArchiveError::FeatureNotEnabled {
+                feature: "xz".into(),
+                path: input_file.to_path_buf(),
             }
-            Some("zip") => {
-                #[cfg(feature = "zip")]
-                {
-                    out = self.unpack(crate::zip::ZipUnpacker::new)?;
-                }
-
-                #[cfg(not(feature = "zip"))]
-                return Err(ArchiveError::FeatureNotEnabled {
-                    feature: "zip".into(),
-                    path: self.archive_file.to_path_buf(),
-                }
-                .into());
+            .into());
+        }
+        Some("gz") => {
+            #[cfg(feature = "gz")]
+            return Ok(Box::new(crate::gz::GzUnpacker::new(
+                output_dir, input_file,
+            )?));
+
+            #[cfg(not(feature = "gz"))]
+            return Err(ArchiveError::FeatureNotEnabled {
+                feature: "gz".into(),
+                path: input_file.to_path_buf(),
             }
-            Some(ext) => {
-                return Err(ArchiveError::UnsupportedFormat {
-                    format: ext.into(),
-                    path: self.archive_file.to_path_buf(),
-                }
-                .into());
+            .into());
+        }
+        Some("tar") => {
+            #[cfg(feature = "tar")]
+            return Ok(Box::new(crate::tar::TarUnpacker::new(
+                output_dir, input_file,
+            )?));
+
+            #[cfg(not(feature = "tar"))]
+            return Err(ArchiveError::FeatureNotEnabled {
+                feature: "tar".into(),
+                path: input_file.to_path_buf(),
             }
-            None => {
-                return Err(ArchiveError::UnknownFormat {
-                    path: self.archive_file.to_path_buf(),
-                }
-                .into());
+            .into());
+        }
+        Some("tar.bz2" | "tz2" | "tbz" | "tbz2") => {
+            #[cfg(feature = "tar-bz2")]
+            return Ok(Box::new(crate::tar::TarUnpacker::new_bz2(
+                output_dir, input_file,
+            )?));
+
+            #[cfg(not(feature = "tar-bz2"))]
+            return Err(ArchiveError::FeatureNotEnabled {
+                feature: "tar-bz2".into(),
+                path: input_file.to_path_buf(),
             }
-        };
-
-        Ok((ext.unwrap(), out))
+            .into());
+        }
+        Some("tar.gz" | "tgz") => {
+            #[cfg(feature = "tar-gz")]
+            return Ok(Box::new(crate::tar::TarUnpacker::new_gz(
+                output_dir, input_file,
+            )?));
+
+            #[cfg(not(feature = "tar-gz"))]
+            return Err(ArchiveError::FeatureNotEnabled {
+                feature: "tar-gz".into(),
+                path: input_file.to_path_buf(),
+            }
+            .into());
+        }
+        Some("tar.xz" | "txz") => {
+            #[cfg(feature = "tar-xz")]
+            return Ok(Box::new(crate::tar::TarUnpacker::new_xz(
+                output_dir, input_file,
+            )?));
+
+            #[cfg(not(feature = "tar-xz"))]
+            return Err(ArchiveError::FeatureNotEnabled {
+                feature: "tar-xz".into(),
+                path: input_file.to_path_buf(),
+            }
+            .into());
+        }
+        Some("zst" | "zstd") => {
+            #[cfg(feature = "tar-zstd")]
+            return Ok(Box::new(crate::tar::TarUnpacker::new_zstd(
+                output_dir, input_file,
+            )?));
+
+            #[cfg(not(feature = "tar-zstd"))]
+            return Err(ArchiveError::FeatureNotEnabled {
+                feature: "tar-zstd".into(),
+                path: input_file.to_path_buf(),
+            }
+            .into());
+        }
+        Some("zip") => {
+            #[cfg(feature = "zip")]
+            return Ok(Box::new(crate::zip::ZipUnpacker::new(
+                output_dir, input_file,
+            )?));
+
+            #[cfg(not(feature = "zip"))]
+            return Err(ArchiveError::FeatureNotEnabled {
+                feature: "zip".into(),
+                path: input_file.to_path_buf(),
+            }
+            .into());
+        }
+        Some(ext) => Err(ArchiveError::UnsupportedFormat {
+            format: ext.into(),
+            path: input_file.to_path_buf(),
+        }
+        .into()),
+        None => Err(ArchiveError::UnknownFormat {
+            path: input_file.to_path_buf(),
+        }
+        .into()),
     }
 }
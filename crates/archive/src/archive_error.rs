@@ -27,4 +27,11 @@ pub enum ArchiveError {
         .path.style(Style::Path),
     )]
     UnknownFormat { path: PathBuf },
+
+    #[cfg_attr(feature = "miette", diagnostic(code(archive::read_entry_unsupported)))]
+    #[error(
+        "Unable to read entry {}, the archive format does not support reading individual entries.",
+        .name.style(Style::File),
+    )]
+    ReadEntryNotSupported { name: String },
 }
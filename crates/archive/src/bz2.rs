@@ -0,0 +1,131 @@
+use crate::archive::{ArchivePacker, ArchiveResult, ArchiveUnpacker};
+use crate::tree_differ::TreeDiffer;
+use bzip2::read::BzDecoder;
+use bzip2::write::BzEncoder;
+use bzip2::Compression;
+use starbase_utils::fs;
+use std::fs::File;
+use std::io::prelude::*;
+use std::path::{Path, PathBuf};
+use tracing::{instrument, trace};
+
+pub use crate::bz2_error::Bz2Error;
+
+/// Applies bzip2 to a single file.
+pub struct Bz2Packer {
+    archive: Option<BzEncoder<File>>,
+    file_count: usize,
+}
+
+impl Bz2Packer {
+    /// Create a new packer with a custom compression level.
+    pub fn create(output_file: &Path, compression: Compression) -> ArchiveResult<Self> {
+        Ok(Bz2Packer {
+            archive: Some(BzEncoder::new(fs::create_file(output_file)?, compression)),
+            file_count: 0,
+        })
+    }
+
+    /// Create a new `.bz2` packer.
+    pub fn new(output_file: &Path) -> ArchiveResult<Self> {
+        Self::create(output_file, Compression::new(6)) // Default in lib
+    }
+}
+
+impl ArchivePacker for Bz2Packer {
+    fn add_file(&mut self, _name: &str, file: &Path) -> ArchiveResult<()> {
+        if self.file_count > 0 {
+            return Err(Bz2Error::OneFile.into());
+        }
+
+        self.archive
+            .as_mut()
+            .unwrap()
+            .write_all(&fs::read_file_bytes(file)?)
+            .map_err(|error| Bz2Error::AddFailure {
+                source: file.to_path_buf(),
+                error: Box::new(error),
+            })?;
+
+        self.file_count += 1;
+
+        Ok(())
+    }
+
+    fn add_dir(&mut self, _name: &str, _dir: &Path) -> ArchiveResult<()> {
+        Err(Bz2Error::NoDirs.into())
+    }
+
+    #[instrument(name = "pack_bz2", skip_all)]
+    fn pack(&mut self) -> ArchiveResult<()> {
+        trace!("Bzipping file");
+
+        self.archive
+            .take()
+            .unwrap()
+            .finish()
+            .map_err(|error| Bz2Error::PackFailure {
+                error: Box::new(error),
+            })?;
+
+        Ok(())
+    }
+}
+
+/// Opens a bzipped file.
+pub struct Bz2Unpacker {
+    archive: BzDecoder<File>,
+    file_name: String,
+    output_dir: PathBuf,
+}
+
+impl Bz2Unpacker {
+    /// Create a new `.bz2` unpacker.
+    pub fn new(output_dir: &Path, input_file: &Path) -> ArchiveResult<Self> {
+        fs::create_dir_all(output_dir)?;
+
+        Ok(Bz2Unpacker {
+            archive: BzDecoder::new(fs::open_file(input_file)?),
+            file_name: fs::file_name(input_file).replace(".bz2", ""),
+            output_dir: output_dir.to_path_buf(),
+        })
+    }
+}
+
+impl ArchiveUnpacker for Bz2Unpacker {
+    #[instrument(name = "unpack_bz2", skip_all)]
+    fn unpack(&mut self, _prefix: &str, _differ: &mut TreeDiffer) -> ArchiveResult<PathBuf> {
+        trace!(output_dir = ?self.output_dir, "Unbzipping file");
+
+        let mut bytes = vec![];
+
+        self.archive
+            .read_to_end(&mut bytes)
+            .map_err(|error| Bz2Error::UnpackFailure {
+                error: Box::new(error),
+            })?;
+
+        let out_file = self.output_dir.join(&self.file_name);
+
+        fs::write_file(&out_file, bytes)?;
+
+        Ok(out_file)
+    }
+
+    // Only contains a single file, named after the archive
+    fn read_entry(&mut self, name: &str) -> ArchiveResult<Option<Vec<u8>>> {
+        if name != self.file_name {
+            return Ok(None);
+        }
+
+        let mut bytes = vec![];
+
+        self.archive
+            .read_to_end(&mut bytes)
+            .map_err(|error| Bz2Error::UnpackFailure {
+                error: Box::new(error),
+            })?;
+
+        Ok(Some(bytes))
+    }
+}
@@ -0,0 +1,66 @@
+use starbase_styles::{Style, Stylize};
+use std::path::PathBuf;
+use thiserror::Error;
+
+#[cfg(not(feature = "miette"))]
+#[derive(Error, Debug)]
+pub enum Bz2Error {
+    #[error("Failed to add source {} to archive.\n{error}", .source.style(Style::Path))]
+    AddFailure {
+        source: PathBuf,
+        #[source]
+        error: Box<std::io::Error>,
+    },
+
+    #[error("Directories cannot be bzipped. Use {} instead.", "tar".style(Style::Symbol))]
+    NoDirs,
+
+    #[error("Only 1 file can be bzipped, received more than 1.")]
+    OneFile,
+
+    #[error("Failed to pack archive.\n{error}")]
+    PackFailure {
+        #[source]
+        error: Box<std::io::Error>,
+    },
+
+    #[error("Failed to unpack archive.\n{error}")]
+    UnpackFailure {
+        #[source]
+        error: Box<std::io::Error>,
+    },
+}
+
+#[cfg(feature = "miette")]
+#[derive(Error, Debug, miette::Diagnostic)]
+pub enum Bz2Error {
+    #[diagnostic(code(bz2::pack::add))]
+    #[error("Failed to add source {} to archive.", .source.style(Style::Path))]
+    AddFailure {
+        source: PathBuf,
+        #[source]
+        error: Box<std::io::Error>,
+    },
+
+    #[diagnostic(code(bz2::pack::no_dirs))]
+    #[error("Directories cannot be bzipped. Use {} instead.", "tar".style(Style::Symbol))]
+    NoDirs,
+
+    #[diagnostic(code(bz2::pack::one_file))]
+    #[error("Only 1 file can be bzipped, received more than 1.")]
+    OneFile,
+
+    #[diagnostic(code(bz2::pack::finish))]
+    #[error("Failed to pack archive.")]
+    PackFailure {
+        #[source]
+        error: Box<std::io::Error>,
+    },
+
+    #[diagnostic(code(bz2::unpack::finish))]
+    #[error("Failed to unpack archive.")]
+    UnpackFailure {
+        #[source]
+        error: Box<std::io::Error>,
+    },
+}
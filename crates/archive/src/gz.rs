@@ -111,4 +111,21 @@ impl ArchiveUnpacker for GzUnpacker {
 
         Ok(out_file)
     }
+
+    // Only contains a single file, named after the archive
+    fn read_entry(&mut self, name: &str) -> ArchiveResult<Option<Vec<u8>>> {
+        if name != self.file_name {
+            return Ok(None);
+        }
+
+        let mut bytes = vec![];
+
+        self.archive
+            .read_to_end(&mut bytes)
+            .map_err(|error| GzError::UnpackFailure {
+                error: Box::new(error),
+            })?;
+
+        Ok(Some(bytes))
+    }
 }
@@ -1,3 +1,9 @@
+/// Handles standard `.bz2` files.
+#[cfg(feature = "bz2")]
+pub mod bz2;
+#[cfg(feature = "bz2")]
+mod bz2_error;
+
 /// Handles standard `.gz` files.
 #[cfg(feature = "gz")]
 pub mod gz;
@@ -10,6 +16,12 @@ pub mod tar;
 #[cfg(feature = "tar")]
 mod tar_error;
 
+/// Handles standard `.xz` files.
+#[cfg(feature = "xz")]
+pub mod xz;
+#[cfg(feature = "xz")]
+mod xz_error;
+
 /// Handles `.zip` files.
 #[cfg(feature = "zip")]
 pub mod zip;
@@ -90,6 +102,8 @@ pub fn get_supported_archive_extensions() -> Vec<String> {
         "zst".into(),
         "zip".into(),
         "gz".into(),
+        "bz2".into(),
+        "xz".into(),
     ]
 }
 
@@ -104,3 +118,27 @@ pub fn is_supported_archive_extension(path: &Path) -> bool {
                 .any(|ext| name.ends_with(&ext))
         })
 }
+
+/// Return true if the file path has a supported archive extension,
+/// and the Cargo feature for that format has been enabled in the
+/// current build.
+pub fn is_archive_format_enabled(path: &Path) -> bool {
+    if !is_supported_archive_extension(path) {
+        return false;
+    }
+
+    // Depending on enabled features, this may look like a `matches!`
+    #[allow(clippy::match_like_matches_macro)]
+    match get_full_file_extension(path).as_deref() {
+        Some("bz2") => cfg!(feature = "bz2"),
+        Some("gz") => cfg!(feature = "gz"),
+        Some("tar") => cfg!(feature = "tar"),
+        Some("tar.bz2" | "tz2" | "tbz" | "tbz2") => cfg!(feature = "tar-bz2"),
+        Some("tar.gz" | "tgz") => cfg!(feature = "tar-gz"),
+        Some("tar.xz" | "txz") => cfg!(feature = "tar-xz"),
+        Some("xz") => cfg!(feature = "xz"),
+        Some("zst" | "zstd") => cfg!(feature = "tar-zstd"),
+        Some("zip") => cfg!(feature = "zip"),
+        _ => false,
+    }
+}
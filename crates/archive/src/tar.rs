@@ -8,7 +8,8 @@ use tracing::{instrument, trace};
 
 pub use crate::tar_error::TarError;
 
-/// Creates tar archives.
+/// Creates tar archives. Entry paths longer than the 100 byte limit of
+/// the ustar format are written with GNU long name extensions.
 pub struct TarPacker {
     archive: TarBuilder<Box<dyn Write>>,
 }
@@ -260,4 +261,44 @@ impl ArchiveUnpacker for TarUnpacker {
 
         Ok(self.output_dir.clone())
     }
+
+    #[instrument(name = "read_tar_entry", skip(self))]
+    fn read_entry(&mut self, name: &str) -> ArchiveResult<Option<Vec<u8>>> {
+        let name = Path::new(name.trim_start_matches("./"));
+
+        // Entries can't be seeked to (compressed streams don't support it),
+        // so iterate until we find a match, without unpacking the others
+        for entry in self
+            .archive
+            .entries()
+            .map_err(|error| TarError::UnpackFailure {
+                error: Box::new(error),
+            })?
+        {
+            let mut entry = entry.map_err(|error| TarError::UnpackFailure {
+                error: Box::new(error),
+            })?;
+
+            let is_match = entry
+                .path()
+                .is_ok_and(|path| path.strip_prefix("./").unwrap_or(&path) == name);
+
+            if !is_match || entry.header().entry_type().is_dir() {
+                continue;
+            }
+
+            let mut bytes = vec![];
+
+            entry
+                .read_to_end(&mut bytes)
+                .map_err(|error| TarError::ExtractFailure {
+                    source: name.to_path_buf(),
+                    error: Box::new(error),
+                })?;
+
+            return Ok(Some(bytes));
+        }
+
+        Ok(None)
+    }
 }
@@ -0,0 +1,130 @@
+use crate::archive::{ArchivePacker, ArchiveResult, ArchiveUnpacker};
+use crate::tree_differ::TreeDiffer;
+use starbase_utils::fs;
+use std::fs::File;
+use std::io::prelude::*;
+use std::path::{Path, PathBuf};
+use tracing::{instrument, trace};
+use xz2::read::XzDecoder;
+use xz2::write::XzEncoder;
+
+pub use crate::xz_error::XzError;
+
+/// Applies xz to a single file.
+pub struct XzPacker {
+    archive: Option<XzEncoder<File>>,
+    file_count: usize,
+}
+
+impl XzPacker {
+    /// Create a new packer with a custom compression level.
+    pub fn create(output_file: &Path, level: u32) -> ArchiveResult<Self> {
+        Ok(XzPacker {
+            archive: Some(XzEncoder::new(fs::create_file(output_file)?, level)),
+            file_count: 0,
+        })
+    }
+
+    /// Create a new `.xz` packer.
+    pub fn new(output_file: &Path) -> ArchiveResult<Self> {
+        Self::create(output_file, 4)
+    }
+}
+
+impl ArchivePacker for XzPacker {
+    fn add_file(&mut self, _name: &str, file: &Path) -> ArchiveResult<()> {
+        if self.file_count > 0 {
+            return Err(XzError::OneFile.into());
+        }
+
+        self.archive
+            .as_mut()
+            .unwrap()
+            .write_all(&fs::read_file_bytes(file)?)
+            .map_err(|error| XzError::AddFailure {
+                source: file.to_path_buf(),
+                error: Box::new(error),
+            })?;
+
+        self.file_count += 1;
+
+        Ok(())
+    }
+
+    fn add_dir(&mut self, _name: &str, _dir: &Path) -> ArchiveResult<()> {
+        Err(XzError::NoDirs.into())
+    }
+
+    #[instrument(name = "pack_xz", skip_all)]
+    fn pack(&mut self) -> ArchiveResult<()> {
+        trace!("Compressing file with xz");
+
+        self.archive
+            .take()
+            .unwrap()
+            .finish()
+            .map_err(|error| XzError::PackFailure {
+                error: Box::new(error),
+            })?;
+
+        Ok(())
+    }
+}
+
+/// Opens an xz compressed file.
+pub struct XzUnpacker {
+    archive: XzDecoder<File>,
+    file_name: String,
+    output_dir: PathBuf,
+}
+
+impl XzUnpacker {
+    /// Create a new `.xz` unpacker.
+    pub fn new(output_dir: &Path, input_file: &Path) -> ArchiveResult<Self> {
+        fs::create_dir_all(output_dir)?;
+
+        Ok(XzUnpacker {
+            archive: XzDecoder::new(fs::open_file(input_file)?),
+            file_name: fs::file_name(input_file).replace(".xz", ""),
+            output_dir: output_dir.to_path_buf(),
+        })
+    }
+}
+
+impl ArchiveUnpacker for XzUnpacker {
+    #[instrument(name = "unpack_xz", skip_all)]
+    fn unpack(&mut self, _prefix: &str, _differ: &mut TreeDiffer) -> ArchiveResult<PathBuf> {
+        trace!(output_dir = ?self.output_dir, "Decompressing file with xz");
+
+        let mut bytes = vec![];
+
+        self.archive
+            .read_to_end(&mut bytes)
+            .map_err(|error| XzError::UnpackFailure {
+                error: Box::new(error),
+            })?;
+
+        let out_file = self.output_dir.join(&self.file_name);
+
+        fs::write_file(&out_file, bytes)?;
+
+        Ok(out_file)
+    }
+
+    // Only contains a single file, named after the archive
+    fn read_entry(&mut self, name: &str) -> ArchiveResult<Option<Vec<u8>>> {
+        if name != self.file_name {
+            return Ok(None);
+        }
+
+        let mut bytes = vec![];
+
+        self.archive
+            .read_to_end(&mut bytes)
+            .map_err(|error| XzError::UnpackFailure {
+                error: Box::new(error),
+            })?;
+
+        Ok(Some(bytes))
+    }
+}
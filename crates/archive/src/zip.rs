@@ -203,4 +203,32 @@ impl ArchiveUnpacker for ZipUnpacker {
 
         Ok(self.output_dir.clone())
     }
+
+    #[instrument(name = "read_zip_entry", skip(self))]
+    fn read_entry(&mut self, name: &str) -> ArchiveResult<Option<Vec<u8>>> {
+        let mut file = match self.archive.by_name(name) {
+            Ok(file) => file,
+            Err(zip::result::ZipError::FileNotFound) => return Ok(None),
+            Err(error) => {
+                return Err(ZipError::UnpackFailure {
+                    error: Box::new(error),
+                }
+                .into())
+            }
+        };
+
+        if !file.is_file() {
+            return Ok(None);
+        }
+
+        let mut bytes = vec![];
+
+        file.read_to_end(&mut bytes)
+            .map_err(|error| ZipError::ExtractFailure {
+                source: PathBuf::from(name),
+                error: Box::new(error),
+            })?;
+
+        Ok(Some(bytes))
+    }
 }
@@ -1,5 +1,6 @@
-use starbase_archive::Archiver;
+use starbase_archive::{is_archive_format_enabled, Archiver};
 use starbase_sandbox::{create_empty_sandbox, create_sandbox};
+use std::path::Path;
 
 #[test]
 #[should_panic(expected = "unsupported")]
@@ -158,3 +159,130 @@ fn can_use_negated_globs() {
 
     assert!(out.path().join("folder/nested.json").exists());
 }
+
+mod read_entry {
+    use super::*;
+    use starbase_archive::{ArchiveResult, ArchiveUnpacker, TreeDiffer};
+    use std::path::PathBuf;
+
+    struct UnpackOnly;
+
+    impl ArchiveUnpacker for UnpackOnly {
+        fn unpack(&mut self, _prefix: &str, _differ: &mut TreeDiffer) -> ArchiveResult<PathBuf> {
+            unimplemented!()
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "read_entry_unsupported")]
+    fn errors_by_default() {
+        let sandbox = create_empty_sandbox();
+        let file = sandbox.path().join("out.custom");
+        let archiver = Archiver::new(sandbox.path(), &file);
+
+        archiver
+            .read_entry_with("file.txt", |_, _| Ok(UnpackOnly))
+            .unwrap();
+    }
+}
+
+mod format_enabled {
+    use super::*;
+
+    #[test]
+    fn enabled_for_all_test_features() {
+        for file in [
+            "file.bz2",
+            "file.gz",
+            "file.tar",
+            "file.tar.bz2",
+            "file.tbz2",
+            "file.tar.gz",
+            "file.tgz",
+            "file.tar.xz",
+            "file.txz",
+            "file.xz",
+            "file.zst",
+            "file.zip",
+        ] {
+            assert!(is_archive_format_enabled(Path::new(file)), "{file}");
+        }
+    }
+
+    #[test]
+    fn disabled_for_unknown_formats() {
+        assert!(!is_archive_format_enabled(Path::new("file.rar")));
+        assert!(!is_archive_format_enabled(Path::new("file.txt")));
+        assert!(!is_archive_format_enabled(Path::new("file")));
+    }
+}
+
+mod checksums {
+    use super::*;
+    use starbase_utils::fs;
+
+    #[test]
+    fn returns_checksums_for_packed_files() {
+        let sandbox = create_sandbox("archives");
+        let tarball = sandbox.path().join("out.tar.gz");
+
+        let mut archiver = Archiver::new(sandbox.path(), &tarball);
+        archiver.set_prefix("prefix");
+        archiver.add_source_file("file.txt", None);
+        archiver.add_source_file("folder", None);
+
+        let (_, checksums) = archiver
+            .pack_with_checksums(starbase_archive::tar::TarPacker::new_gz)
+            .unwrap();
+
+        assert_eq!(
+            checksums.keys().collect::<Vec<_>>(),
+            [
+                "prefix/file.txt",
+                "prefix/folder/nested.json",
+                "prefix/folder/nested.txt",
+                "prefix/folder/nested/docs.md",
+                "prefix/folder/nested/other.txt",
+            ]
+        );
+        assert_eq!(
+            checksums.get("prefix/file.txt").unwrap(),
+            &fs::hash_file(sandbox.path().join("file.txt")).unwrap()
+        );
+        assert_eq!(
+            checksums.get("prefix/folder/nested.txt").unwrap(),
+            &fs::hash_file(sandbox.path().join("folder/nested.txt")).unwrap()
+        );
+    }
+
+    #[test]
+    fn writes_manifest_into_archive() {
+        let sandbox = create_sandbox("archives");
+        let tarball = sandbox.path().join("out.zip");
+
+        let mut archiver = Archiver::new(sandbox.path(), &tarball);
+        archiver.add_source_file("file.txt", None);
+        archiver.add_source_file("data.json", None);
+        archiver.set_checksum_file("SHA256SUMS");
+
+        let (_, checksums) = archiver
+            .pack_with_checksums(starbase_archive::zip::ZipPacker::new)
+            .unwrap();
+
+        let out = create_empty_sandbox();
+
+        archiver.source_root = out.path();
+        archiver.unpack_from_ext().unwrap();
+
+        assert_eq!(
+            fs::read_file(out.path().join("SHA256SUMS")).unwrap(),
+            format!(
+                "{}  data.json\n{}  file.txt\n",
+                fs::hash_file(sandbox.path().join("data.json")).unwrap(),
+                fs::hash_file(sandbox.path().join("file.txt")).unwrap(),
+            )
+        );
+        assert_eq!(checksums.len(), 2);
+        assert!(!sandbox.path().join(".out.zip.SHA256SUMS").exists());
+    }
+}
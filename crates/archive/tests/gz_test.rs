@@ -43,6 +43,28 @@ mod gz {
         ));
     }
 
+    #[test]
+    fn read_entry() {
+        let sandbox = create_sandbox("archives");
+
+        // Pack
+        let input = sandbox.path();
+        let archive = sandbox.path().join("file.txt.gz");
+
+        let mut archiver = Archiver::new(input, &archive);
+        archiver.add_source_file("file.txt", None);
+        archiver.pack(GzPacker::new).unwrap();
+
+        // Read
+        let archiver = Archiver::new(input, &archive);
+
+        assert_eq!(
+            archiver.read_entry("file.txt").unwrap().unwrap(),
+            std::fs::read(input.join("file.txt")).unwrap()
+        );
+        assert_eq!(archiver.read_entry("other.txt").unwrap(), None);
+    }
+
     #[test]
     fn file_ignores_prefix() {
         let sandbox = create_sandbox("archives");
@@ -34,3 +34,63 @@ mod tar_bz2 {
 
     generate_tests!("out.tar.bz2", TarPacker::new_bz2, TarUnpacker::new_bz2);
 }
+
+mod tar_long_paths {
+    use super::*;
+    use starbase_sandbox::create_empty_sandbox;
+
+    const DEEP_DIR: &str = "deeply/nested/directory/structure/that/goes/on/and/on/for/a/very/long/time/until/it/exceeds/the/limit";
+
+    fn pack_and_unpack<P, U>(filename: &str, packer: P, unpacker: U)
+    where
+        P: FnOnce(&Path) -> starbase_archive::ArchiveResult<TarPacker>,
+        U: FnOnce(&Path, &Path) -> starbase_archive::ArchiveResult<TarUnpacker>,
+    {
+        let sandbox = create_empty_sandbox();
+        let long_file = format!("{DEEP_DIR}/with-a-long-file-name-that-is-also-quite-long.txt");
+        let long_name = format!("{}.txt", "x".repeat(120));
+
+        assert!(long_file.len() > 100);
+
+        sandbox.create_file(&long_file, "deep");
+        sandbox.create_file(&long_name, "wide");
+
+        // Pack
+        let input = sandbox.path();
+        let archive = sandbox.path().join(filename);
+
+        let mut archiver = Archiver::new(input, &archive);
+        archiver.add_source_file("deeply", None);
+        archiver.add_source_file(&long_name, None);
+        archiver.pack(packer).unwrap();
+
+        // Unpack
+        let output = sandbox.path().join("out");
+
+        let archiver = Archiver::new(&output, &archive);
+        archiver.unpack(unpacker).unwrap();
+
+        assert_eq!(
+            std::fs::read_to_string(output.join(&long_file)).unwrap(),
+            "deep"
+        );
+        assert_eq!(
+            std::fs::read_to_string(output.join(&long_name)).unwrap(),
+            "wide"
+        );
+        assert_eq!(
+            archiver.read_entry(&long_file).unwrap().unwrap(),
+            b"deep".to_vec()
+        );
+    }
+
+    #[test]
+    fn tar() {
+        pack_and_unpack("out.tar", TarPacker::new, TarUnpacker::new);
+    }
+
+    #[test]
+    fn tar_gz() {
+        pack_and_unpack("out.tar.gz", TarPacker::new_gz, TarUnpacker::new_gz);
+    }
+}
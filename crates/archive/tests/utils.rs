@@ -450,5 +450,67 @@ macro_rules! generate_tests {
                 &output.join("folder/nested/other.txt")
             ));
         }
+
+        #[test]
+        fn read_entry() {
+            let sandbox = create_sandbox("archives");
+
+            // Pack
+            let input = sandbox.path();
+            let archive = sandbox.path().join($filename);
+
+            let mut archiver = Archiver::new(input, &archive);
+            archiver.add_source_file("file.txt", None);
+            archiver.add_source_file("folder", None);
+            archiver.pack($packer).unwrap();
+
+            // Read
+            let output = sandbox.path().join("out");
+            let archiver = Archiver::new(&output, &archive);
+
+            assert_eq!(
+                archiver
+                    .read_entry_with("folder/nested/other.txt", $unpacker)
+                    .unwrap()
+                    .unwrap(),
+                std::fs::read(input.join("folder/nested/other.txt")).unwrap()
+            );
+            assert_eq!(
+                archiver.read_entry("file.txt").unwrap().unwrap(),
+                std::fs::read(input.join("file.txt")).unwrap()
+            );
+            assert_eq!(archiver.read_entry("missing.txt").unwrap(), None);
+            assert_eq!(archiver.read_entry("folder").unwrap(), None);
+
+            // Nothing else was unpacked
+            assert!(!output.join("file.txt").exists());
+        }
+
+        #[test]
+        fn read_entry_with_prefix() {
+            let sandbox = create_sandbox("archives");
+
+            // Pack
+            let input = sandbox.path();
+            let archive = sandbox.path().join($filename);
+
+            let mut archiver = Archiver::new(input, &archive);
+            archiver.add_source_file("file.txt", None);
+            archiver.set_prefix("some/prefix");
+            archiver.pack($packer).unwrap();
+
+            // Read
+            let output = sandbox.path().join("out");
+            let mut archiver = Archiver::new(&output, &archive);
+
+            assert_eq!(archiver.read_entry("file.txt").unwrap(), None);
+
+            archiver.set_prefix("some/prefix");
+
+            assert_eq!(
+                archiver.read_entry("file.txt").unwrap().unwrap(),
+                std::fs::read(input.join("file.txt")).unwrap()
+            );
+        }
     };
 }
@@ -9,11 +9,17 @@ use std::time::Duration;
 pub struct ConsoleBuffer {
     buffer: Arc<Mutex<Vec<u8>>>,
     stream: ConsoleStreamType,
+
+    pub(crate) captured: bool,
 }
 
 impl ConsoleBuffer {
     pub fn new(buffer: Arc<Mutex<Vec<u8>>>, stream: ConsoleStreamType) -> Self {
-        Self { buffer, stream }
+        Self {
+            buffer,
+            stream,
+            captured: false,
+        }
     }
 }
 
@@ -25,6 +31,10 @@ impl Write for ConsoleBuffer {
     }
 
     fn flush(&mut self) -> io::Result<()> {
+        if self.captured {
+            return Ok(());
+        }
+
         flush(&mut self.buffer.lock(), self.stream)
     }
 }
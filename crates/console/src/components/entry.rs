@@ -2,6 +2,7 @@ use super::layout::*;
 use super::styled_text::*;
 use crate::ui::ConsoleTheme;
 use iocraft::prelude::*;
+use starbase_styles::color::display_width;
 
 #[derive(Default, Props)]
 pub struct EntryProps<'a> {
@@ -12,6 +13,9 @@ pub struct EntryProps<'a> {
     pub separator: Option<String>,
     pub children: Vec<AnyElement<'a>>,
     pub no_children: bool,
+    /// Pad the name to this width, so that separators are aligned
+    /// across multiple entries.
+    pub name_width: Option<u32>,
 }
 
 #[component]
@@ -23,7 +27,9 @@ pub fn Entry<'a>(props: &mut EntryProps<'a>, hooks: Hooks) -> impl Into<AnyEleme
         Stack {
             View {
                 View(margin_right: 1) {
-                    Text(content: &props.name)
+                    View(min_width: props.name_width.map(Size::Length).unwrap_or(Size::Auto)) {
+                        Text(content: &props.name)
+                    }
                     Separator(value: props.separator.as_deref().unwrap_or(":"))
                 }
 
@@ -66,3 +72,79 @@ pub fn Entry<'a>(props: &mut EntryProps<'a>, hooks: Hooks) -> impl Into<AnyEleme
         }
     }
 }
+
+pub enum EntryValue<'a> {
+    Content(String),
+    Element(AnyElement<'a>),
+}
+
+impl From<String> for EntryValue<'_> {
+    fn from(value: String) -> Self {
+        Self::Content(value)
+    }
+}
+
+impl From<&str> for EntryValue<'_> {
+    fn from(value: &str) -> Self {
+        Self::Content(value.to_owned())
+    }
+}
+
+impl<'a> From<AnyElement<'a>> for EntryValue<'a> {
+    fn from(value: AnyElement<'a>) -> Self {
+        Self::Element(value)
+    }
+}
+
+/// Convert an ordered map (or any iterator of pairs) of names to values,
+/// into a list of entries for [`EntryList`].
+pub fn create_entries<'a, I, K, V>(map: I) -> Vec<(String, EntryValue<'a>)>
+where
+    I: IntoIterator<Item = (K, V)>,
+    K: Into<String>,
+    V: Into<EntryValue<'a>>,
+{
+    map.into_iter()
+        .map(|(name, value)| (name.into(), value.into()))
+        .collect()
+}
+
+#[derive(Default, Props)]
+pub struct EntryListProps<'a> {
+    pub entries: Vec<(String, EntryValue<'a>)>,
+    pub fallback: Option<String>,
+    pub separator: Option<String>,
+}
+
+#[component]
+pub fn EntryList<'a>(props: &mut EntryListProps<'a>) -> impl Into<AnyElement<'a>> {
+    let name_width = props
+        .entries
+        .iter()
+        .map(|(name, _)| display_width(name))
+        .max()
+        .unwrap_or_default() as u32;
+
+    element! {
+        Stack {
+            #(props.entries.drain(..).map(|(name, value)| {
+                let (content, value) = match value {
+                    EntryValue::Content(content) if content.is_empty() => (None, None),
+                    EntryValue::Content(content) => (Some(content), None),
+                    EntryValue::Element(element) => (None, Some(element)),
+                };
+
+                element! {
+                    Entry(
+                        name,
+                        name_width,
+                        content,
+                        value,
+                        fallback: props.fallback.clone(),
+                        separator: props.separator.clone(),
+                    )
+                }
+            }))
+        }
+    }
+}
@@ -8,6 +8,9 @@ use iocraft::prelude::*;
 pub struct InputProps<'a> {
     pub default_value: String,
     pub description: Option<String>,
+    /// Previously entered values (oldest first) that can be recalled with
+    /// the up and down keys. On submit, the new value is appended.
+    pub history: Option<&'a mut Vec<String>>,
     pub label: String,
     pub prefix_symbol: Option<String>,
     pub validate: Validator<'static, String>,
@@ -21,11 +24,53 @@ pub fn Input<'a>(props: &mut InputProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
     let mut value = hooks.use_state(|| props.default_value.clone());
     let mut should_exit = hooks.use_state(|| false);
     let mut error = hooks.use_state(|| None);
+    let history = hooks.use_state(|| props.history.as_deref().cloned().unwrap_or_default());
+    let mut history_index = hooks.use_state(|| None::<usize>);
+    let mut draft = hooks.use_state(String::new);
 
     let validate = props.validate.take();
 
     hooks.use_local_terminal_events({
         move |event| match event {
+            TerminalEvent::Key(KeyEvent {
+                code: KeyCode::Up,
+                kind,
+                ..
+            }) if kind != KeyEventKind::Release => {
+                let entries = history.read();
+
+                let next_index = match history_index.get() {
+                    Some(index) => index.saturating_sub(1),
+                    None if entries.is_empty() => return,
+                    None => {
+                        // Keep the current value so it can be restored
+                        draft.set(value.to_string());
+                        entries.len() - 1
+                    }
+                };
+
+                history_index.set(Some(next_index));
+                value.set(entries[next_index].clone());
+            }
+            TerminalEvent::Key(KeyEvent {
+                code: KeyCode::Down,
+                kind,
+                ..
+            }) if kind != KeyEventKind::Release => {
+                let Some(index) = history_index.get() else {
+                    return;
+                };
+
+                let entries = history.read();
+
+                if index + 1 < entries.len() {
+                    history_index.set(Some(index + 1));
+                    value.set(entries[index + 1].clone());
+                } else {
+                    history_index.set(None);
+                    value.set(draft.to_string());
+                }
+            }
             TerminalEvent::Key(KeyEvent {
                 code: KeyCode::Enter,
                 kind,
@@ -49,6 +94,15 @@ pub fn Input<'a>(props: &mut InputProps<'a>, mut hooks: Hooks) -> impl Into<AnyE
             **outer_value = value.to_string();
         }
 
+        if let Some(outer_history) = &mut props.history {
+            let entry = value.to_string();
+
+            // Avoid consecutive duplicates when re-submitting a recalled value
+            if !entry.is_empty() && outer_history.last() != Some(&entry) {
+                outer_history.push(entry);
+            }
+        }
+
         system.exit();
 
         return element! {
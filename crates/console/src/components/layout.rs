@@ -51,14 +51,31 @@ pub fn Stack<'a>(props: &mut StackProps<'a>) -> impl Into<AnyElement<'a>> {
 pub struct GroupProps<'a> {
     pub children: Vec<AnyElement<'a>>,
     pub gap: Gap,
+    /// Flow children onto the next line when they would overflow
+    /// the available width (typically the terminal width).
+    pub wrap: bool,
 }
 
 #[component]
-pub fn Group<'a>(props: &mut GroupProps<'a>) -> impl Into<AnyElement<'a>> {
+pub fn Group<'a>(props: &mut GroupProps<'a>, mut hooks: Hooks) -> impl Into<AnyElement<'a>> {
+    let (terminal_width, _) = hooks.use_terminal_size();
+
     element! {
         View(
             flex_direction: FlexDirection::Row,
-            gap: props.gap,
+            flex_wrap: if props.wrap {
+                FlexWrap::Wrap
+            } else {
+                FlexWrap::NoWrap
+            },
+            // Only gap between items, not between wrapped lines
+            column_gap: props.gap,
+            // Non-TTY's like CI environments have no width
+            max_width: if props.wrap && terminal_width > 0 {
+                Size::Length(terminal_width as u32)
+            } else {
+                Size::Auto
+            },
         ) {
             #(&mut props.children)
         }
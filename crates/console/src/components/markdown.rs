@@ -0,0 +1,286 @@
+use super::layout::*;
+use super::list::*;
+use crate::ui::ConsoleTheme;
+use iocraft::prelude::*;
+use starbase_styles::color::parse_tags;
+use starbase_styles::Style;
+
+#[derive(Clone, Debug, PartialEq)]
+enum InlineKind {
+    Text(Option<String>),
+    Bold,
+    Italic,
+    Code,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Block {
+    Heading(usize, String),
+    Paragraph(String),
+    List(Vec<String>),
+    Code(Vec<String>),
+}
+
+fn parse_blocks(content: &str) -> Vec<Block> {
+    let mut blocks = vec![];
+    let mut paragraph: Vec<&str> = vec![];
+    let mut list: Vec<String> = vec![];
+    let mut code: Option<Vec<String>> = None;
+
+    let flush = |blocks: &mut Vec<Block>, paragraph: &mut Vec<&str>, list: &mut Vec<String>| {
+        if !paragraph.is_empty() {
+            blocks.push(Block::Paragraph(paragraph.join(" ")));
+            paragraph.clear();
+        }
+
+        if !list.is_empty() {
+            blocks.push(Block::List(std::mem::take(list)));
+        }
+    };
+
+    for line in content.lines() {
+        let trimmed = line.trim();
+
+        // Fenced code blocks are rendered as-is
+        if trimmed.starts_with("```") {
+            match code.take() {
+                Some(lines) => {
+                    blocks.push(Block::Code(lines));
+                }
+                None => {
+                    flush(&mut blocks, &mut paragraph, &mut list);
+                    code = Some(vec![]);
+                }
+            };
+
+            continue;
+        }
+
+        if let Some(lines) = &mut code {
+            lines.push(line.to_owned());
+            continue;
+        }
+
+        if trimmed.is_empty() {
+            flush(&mut blocks, &mut paragraph, &mut list);
+            continue;
+        }
+
+        let level = trimmed.chars().take_while(|ch| *ch == '#').count();
+
+        if (1..=6).contains(&level) && trimmed[level..].starts_with(' ') {
+            flush(&mut blocks, &mut paragraph, &mut list);
+            blocks.push(Block::Heading(level, trimmed[level..].trim().to_owned()));
+            continue;
+        }
+
+        if let Some(item) = trimmed
+            .strip_prefix("- ")
+            .or_else(|| trimmed.strip_prefix("* "))
+        {
+            if !paragraph.is_empty() {
+                flush(&mut blocks, &mut paragraph, &mut list);
+            }
+
+            list.push(item.trim().to_owned());
+            continue;
+        }
+
+        if !list.is_empty() {
+            flush(&mut blocks, &mut paragraph, &mut list);
+        }
+
+        paragraph.push(trimmed);
+    }
+
+    if let Some(lines) = code {
+        blocks.push(Block::Code(lines));
+    }
+
+    flush(&mut blocks, &mut paragraph, &mut list);
+
+    blocks
+}
+
+fn parse_inline(content: &str) -> Vec<(String, InlineKind)> {
+    let mut segments = vec![];
+    let mut text = String::new();
+    let mut rest = content;
+
+    let push_text = |segments: &mut Vec<(String, InlineKind)>, text: &mut String| {
+        if !text.is_empty() {
+            for (part, tag) in parse_tags(text.as_str()) {
+                segments.push((part, InlineKind::Text(tag)));
+            }
+
+            text.clear();
+        }
+    };
+
+    while let Some(ch) = rest.chars().next() {
+        let (marker, kind) = if rest.starts_with("**") {
+            ("**", InlineKind::Bold)
+        } else if ch == '`' {
+            ("`", InlineKind::Code)
+        } else if ch == '*' {
+            ("*", InlineKind::Italic)
+        } else {
+            text.push(ch);
+            rest = &rest[ch.len_utf8()..];
+            continue;
+        };
+
+        let inner = &rest[marker.len()..];
+
+        // Unterminated markers are rendered literally
+        match inner.find(marker) {
+            Some(end) if end > 0 => {
+                push_text(&mut segments, &mut text);
+                segments.push((inner[..end].to_owned(), kind));
+                rest = &inner[end + marker.len()..];
+            }
+            _ => {
+                text.push_str(marker);
+                rest = inner;
+            }
+        };
+    }
+
+    push_text(&mut segments, &mut text);
+
+    segments
+}
+
+// Split text into words that keep their trailing whitespace,
+// so that each word can be wrapped onto the next line
+fn split_words(text: &str) -> Vec<&str> {
+    let mut words = vec![];
+    let mut start = 0;
+    let mut in_space = false;
+
+    for (index, ch) in text.char_indices() {
+        if ch.is_whitespace() {
+            in_space = true;
+        } else if in_space {
+            words.push(&text[start..index]);
+            start = index;
+            in_space = false;
+        }
+    }
+
+    if start < text.len() {
+        words.push(&text[start..]);
+    }
+
+    words
+}
+
+#[derive(Default, Props)]
+struct MarkdownInlineProps {
+    pub content: String,
+    pub weight: Weight,
+    pub color: Option<Color>,
+}
+
+#[component]
+fn MarkdownInline<'a>(props: &MarkdownInlineProps, mut hooks: Hooks) -> impl Into<AnyElement<'a>> {
+    let theme = hooks.use_context::<ConsoleTheme>();
+    let (terminal_width, _) = hooks.use_terminal_size();
+
+    element! {
+        View(
+            flex_wrap: FlexWrap::Wrap,
+            // Non-TTY's like CI environments have no width
+            max_width: if terminal_width > 0 {
+                Size::Length(terminal_width as u32)
+            } else {
+                Size::Auto
+            },
+        ) {
+            #(parse_inline(&props.content).into_iter().flat_map(|(text, kind)| {
+                let (color, weight) = match kind {
+                    InlineKind::Text(tag) => (
+                        tag.and_then(|tag| theme.tag_to_color(&tag)).or(props.color),
+                        props.weight,
+                    ),
+                    InlineKind::Bold => (props.color, Weight::Bold),
+                    // Italics are not reliably supported in terminals
+                    InlineKind::Italic => (theme.style_to_color(&Style::MutedLight), props.weight),
+                    InlineKind::Code => (theme.style_to_color(&Style::Shell), props.weight),
+                };
+
+                split_words(&text)
+                    .into_iter()
+                    .map(|word| {
+                        element! {
+                            Text(
+                                color: if theme.supports_color { color } else { None },
+                                content: word,
+                                weight,
+                            )
+                        }
+                    })
+                    .collect::<Vec<_>>()
+            }))
+        }
+    }
+}
+
+#[derive(Default, Props)]
+pub struct MarkdownProps {
+    pub content: String,
+}
+
+/// Render a small subset of Markdown: headings, paragraphs, bullet lists,
+/// fenced code blocks, and inline bold (`**`), italic (`*`), and code (`` ` ``).
+/// Style tags (e.g. `<file>`) are also supported within text.
+#[component]
+pub fn Markdown<'a>(props: &MarkdownProps, hooks: Hooks) -> impl Into<AnyElement<'a>> {
+    let theme = hooks.use_context::<ConsoleTheme>();
+
+    element! {
+        Stack(gap: 1) {
+            #(parse_blocks(&props.content).into_iter().map(|block| {
+                match block {
+                    Block::Heading(level, text) => element! {
+                        MarkdownInline(
+                            content: text,
+                            weight: Weight::Bold,
+                            color: if level == 1 { Some(theme.brand_color) } else { None },
+                        )
+                    }.into_any(),
+                    Block::Paragraph(text) => element! {
+                        MarkdownInline(content: text)
+                    }.into_any(),
+                    Block::List(items) => element! {
+                        List {
+                            #(items.into_iter().map(|item| {
+                                element! {
+                                    ListItem {
+                                        MarkdownInline(content: item)
+                                    }
+                                }
+                            }))
+                        }
+                    }.into_any(),
+                    Block::Code(lines) => element! {
+                        View(flex_direction: FlexDirection::Column, padding_left: 2) {
+                            #(lines.into_iter().map(|line| {
+                                element! {
+                                    Text(
+                                        color: if theme.supports_color {
+                                            theme.style_to_color(&Style::Shell)
+                                        } else {
+                                            None
+                                        },
+                                        content: line,
+                                    )
+                                }
+                            }))
+                        }
+                    }.into_any(),
+                }
+            }))
+        }
+    }
+}
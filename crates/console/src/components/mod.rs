@@ -5,7 +5,9 @@ mod input_field;
 mod layout;
 mod list;
 mod map;
+mod markdown;
 mod notice;
+mod number_input;
 mod progress;
 mod section;
 mod select;
@@ -18,7 +20,9 @@ pub use input::*;
 pub use layout::*;
 pub use list::*;
 pub use map::*;
+pub use markdown::*;
 pub use notice::*;
+pub use number_input::*;
 pub use progress::*;
 pub use section::*;
 pub use select::*;
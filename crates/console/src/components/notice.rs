@@ -1,24 +1,48 @@
+use super::styled_text::StyledText;
 use crate::ui::{ConsoleTheme, Variant};
 use iocraft::prelude::*;
 
 #[derive(Default, Props)]
 pub struct NoticeProps<'a> {
     pub children: Vec<AnyElement<'a>>,
+    /// Size the notice to its content, instead of the available width.
+    pub fit_content: bool,
+    /// Maximum width of the notice. Defaults to the terminal width.
+    pub max_width: Option<u32>,
+    pub no_icon: bool,
     pub no_title: bool,
     pub title: Option<String>,
     pub variant: Option<Variant>,
 }
 
+fn get_icon(theme: &ConsoleTheme, variant: Variant) -> Option<String> {
+    let (symbol, fallback) = match variant {
+        Variant::Caution => (&theme.notice_caution_symbol, "!"),
+        Variant::Failure => (&theme.notice_failure_symbol, "x"),
+        Variant::Info => (&theme.notice_info_symbol, "i"),
+        Variant::Success => (&theme.notice_success_symbol, "+"),
+        Variant::Neutral => return None,
+    };
+
+    Some(if theme.supports_unicode {
+        symbol.to_owned()
+    } else {
+        fallback.to_owned()
+    })
+}
+
 #[component]
-pub fn Notice<'a>(props: &mut NoticeProps<'a>, hooks: Hooks) -> impl Into<AnyElement<'a>> {
+pub fn Notice<'a>(props: &mut NoticeProps<'a>, mut hooks: Hooks) -> impl Into<AnyElement<'a>> {
     let theme = hooks.use_context::<ConsoleTheme>();
+    let (terminal_width, _) = hooks.use_terminal_size();
+    let variant = props.variant.unwrap_or_default();
 
     let title = if props.no_title {
         None
     } else if props.title.is_some() {
         props.title.clone()
     } else {
-        match props.variant.unwrap_or_default() {
+        match variant {
             Variant::Caution => Some("Caution".into()),
             Variant::Failure => Some("Failure".into()),
             Variant::Success => Some("Success".into()),
@@ -27,36 +51,72 @@ pub fn Notice<'a>(props: &mut NoticeProps<'a>, hooks: Hooks) -> impl Into<AnyEle
         }
     };
 
+    let title = title.map(
+        |title| match get_icon(&theme, variant).filter(|_| !props.no_icon) {
+            Some(icon) => format!("{icon} {}", title.to_uppercase()),
+            None => title.to_uppercase(),
+        },
+    );
+
     let color = props
         .variant
         .map(|v| theme.variant(v))
         .or_else(|| Some(theme.border_color));
 
+    let max_width = props.max_width.or(if terminal_width > 0 {
+        Some(terminal_width as u32)
+    } else {
+        None
+    });
+
     element! {
+        // Children of a row are sized to their content, instead of being stretched
         View(
-            flex_direction: FlexDirection::Column,
-            border_color: color,
-            border_edges: Edges::Left,
-            border_style: BorderStyle::Round,
+            flex_direction: if props.fit_content {
+                FlexDirection::Row
+            } else {
+                FlexDirection::Column
+            },
             margin_top: 1,
             margin_bottom: 1,
-            padding_left: 1,
         ) {
-            #(title.map(|title| {
-                element! {
-                    Text(
-                        content: title.to_uppercase(),
-                        color: if theme.supports_color {
-                            color
-                        } else {
-                            None
-                        },
-                        weight: Weight::Bold,
-                    )
-                }
-            }))
-
-            #(&mut props.children)
+            View(
+                flex_direction: FlexDirection::Column,
+                max_width: max_width.map(Size::Length).unwrap_or(Size::Auto),
+                border_color: color,
+                border_edges: Edges::Left,
+                border_style: BorderStyle::Round,
+                padding_left: 1,
+            ) {
+                #(title.map(|title| {
+                    element! {
+                        Text(
+                            content: title,
+                            color: if theme.supports_color {
+                                color
+                            } else {
+                                None
+                            },
+                            weight: Weight::Bold,
+                        )
+                    }
+                }))
+
+                #(&mut props.children)
+            }
+        }
+    }
+}
+
+/// Create a [`Notice`] for the provided variant that contains the message
+/// as [`StyledText`], for summarizing the result of a task.
+pub fn create_result_notice(
+    variant: Variant,
+    message: impl AsRef<str>,
+) -> Element<'static, Notice> {
+    element! {
+        Notice(variant) {
+            StyledText(content: message.as_ref())
         }
     }
 }
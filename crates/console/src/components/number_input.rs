@@ -0,0 +1,162 @@
+use super::input_field::*;
+use super::layout::Group;
+use crate::ui::ConsoleTheme;
+use iocraft::prelude::*;
+
+#[derive(Props)]
+pub struct NumberInputProps<'a> {
+    pub allow_decimal: bool,
+    pub allow_negative: bool,
+    pub default_value: Option<f64>,
+    pub description: Option<String>,
+    pub label: String,
+    pub max: Option<f64>,
+    pub min: Option<f64>,
+    pub prefix_symbol: Option<String>,
+    pub on_value: Option<&'a mut f64>,
+    pub on_integer: Option<&'a mut i64>,
+}
+
+impl Default for NumberInputProps<'_> {
+    fn default() -> Self {
+        Self {
+            allow_decimal: false,
+            allow_negative: true,
+            default_value: None,
+            description: None,
+            label: "".into(),
+            max: None,
+            min: None,
+            prefix_symbol: None,
+            on_value: None,
+            on_integer: None,
+        }
+    }
+}
+
+// Remove any characters that are not valid for a number,
+// like letters, or a 2nd "-" or ".".
+fn sanitize_number(value: &str, allow_negative: bool, allow_decimal: bool) -> String {
+    let mut result = String::with_capacity(value.len());
+    let mut has_decimal = false;
+
+    for ch in value.chars() {
+        if ch.is_ascii_digit()
+            || (ch == '-' && allow_negative && result.is_empty())
+            || (ch == '.' && allow_decimal && !has_decimal)
+        {
+            has_decimal = has_decimal || ch == '.';
+            result.push(ch);
+        }
+    }
+
+    result
+}
+
+fn parse_number(value: &str, min: Option<f64>, max: Option<f64>) -> Result<f64, String> {
+    let number = value
+        .parse::<f64>()
+        .map_err(|_| "Please enter a number".to_owned())?;
+
+    match (min, max) {
+        (Some(min), Some(max)) if number < min || number > max => {
+            Err(format!("Please enter a number between {min} and {max}"))
+        }
+        (Some(min), _) if number < min => Err(format!("Please enter a number of at least {min}")),
+        (_, Some(max)) if number > max => Err(format!("Please enter a number of at most {max}")),
+        _ => Ok(number),
+    }
+}
+
+#[component]
+pub fn NumberInput<'a>(
+    props: &mut NumberInputProps<'a>,
+    mut hooks: Hooks,
+) -> impl Into<AnyElement<'a>> {
+    let theme = hooks.use_context::<ConsoleTheme>();
+    let mut system = hooks.use_context_mut::<SystemContext>();
+    let mut value = hooks.use_state(|| {
+        props
+            .default_value
+            .map(|value| value.to_string())
+            .unwrap_or_default()
+    });
+    let mut number = hooks.use_state(|| 0.0);
+    let mut should_exit = hooks.use_state(|| false);
+    let mut error = hooks.use_state(|| None);
+
+    let allow_decimal = props.allow_decimal;
+    let allow_negative = props.allow_negative;
+    let min = props.min;
+    let max = props.max;
+
+    hooks.use_local_terminal_events({
+        move |event| match event {
+            TerminalEvent::Key(KeyEvent {
+                code: KeyCode::Enter,
+                kind,
+                ..
+            }) if kind != KeyEventKind::Release => {
+                match parse_number(&value.read(), min, max) {
+                    Ok(result) => {
+                        error.set(None);
+                        number.set(result);
+                        should_exit.set(true);
+                    }
+                    Err(msg) => {
+                        error.set(Some(msg));
+                    }
+                };
+            }
+            _ => {}
+        }
+    });
+
+    if should_exit.get() {
+        if let Some(outer_value) = &mut props.on_value {
+            **outer_value = number.get();
+        }
+
+        if let Some(outer_integer) = &mut props.on_integer {
+            **outer_integer = number.get() as i64;
+        }
+
+        system.exit();
+
+        return element! {
+            InputFieldValue(
+                label: &props.label,
+                value: value.read().as_str(),
+            )
+        }
+        .into_any();
+    }
+
+    element! {
+        InputField(
+            label: &props.label,
+            description: props.description.clone(),
+            error: Some(error),
+        ) {
+            Group(gap: 1) {
+                View {
+                    Text(
+                        content: props.prefix_symbol.as_ref().unwrap_or(&theme.input_prefix_symbol),
+                        color: theme.input_prefix_color,
+                    )
+                }
+                View(width: 50) {
+                    TextInput(
+                        has_focus: true,
+                        value: value.to_string(),
+                        on_change: move |new_value: String| {
+                            // Reject invalid keystrokes
+                            value.set(sanitize_number(&new_value, allow_negative, allow_decimal));
+                        },
+                    )
+                }
+            }
+        }
+    }
+    .into_any()
+}
@@ -7,6 +7,7 @@ use iocraft::prelude::*;
 use std::time::{Duration, Instant};
 use tokio::sync::broadcast::{self, Receiver, Sender};
 use tokio::time::sleep;
+use tracing::warn;
 
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum ProgressDisplay {
@@ -20,6 +21,8 @@ pub enum ProgressState {
     CustomString(String),
     Display(ProgressDisplay),
     Exit,
+    Finish,
+    Inc(u64),
     Max(u64),
     Message(String),
     Prefix(String),
@@ -57,6 +60,20 @@ impl ProgressReporter {
         self.set(ProgressState::Exit)
     }
 
+    /// Set the value to the max and exit, while keeping the
+    /// completed progress visible.
+    pub fn finish(&self) -> &Self {
+        self.set(ProgressState::Finish)
+    }
+
+    pub fn inc(&self, delta: u64) -> &Self {
+        self.set(ProgressState::Inc(delta))
+    }
+
+    pub fn inc_by_one(&self) -> &Self {
+        self.inc(1)
+    }
+
     pub fn wait(&self, value: Duration) -> &Self {
         self.set(ProgressState::Wait(value))
     }
@@ -141,8 +158,18 @@ pub fn Progress<'a>(props: &mut ProgressProps, mut hooks: Hooks) -> impl Into<An
     let theme = hooks.use_context::<ConsoleTheme>();
     let mut system = hooks.use_context_mut::<SystemContext>();
     let mut should_exit = hooks.use_state(|| false);
+    let mut finished = hooks.use_state(|| false);
     let mut prefix = hooks.use_state(String::new);
-    let mut message = hooks.use_state(|| props.default_message.clone());
+    let mut message = hooks.use_state(|| {
+        if let Err(tokens) = validate_template(&props.default_message) {
+            warn!(
+                tokens = ?tokens,
+                "Progress message template contains unknown tokens, which will not be replaced"
+            );
+        }
+
+        props.default_message.clone()
+    });
     let mut suffix = hooks.use_state(String::new);
     let mut max = hooks.use_state(|| props.default_max);
     let mut value = hooks.use_state(|| props.default_value);
@@ -200,6 +227,15 @@ pub fn Progress<'a>(props: &mut ProgressProps, mut hooks: Hooks) -> impl Into<An
                     should_exit.set(true);
                     break;
                 }
+                ProgressState::Finish => {
+                    value.set(max.get());
+                    finished.set(true);
+                    should_exit.set(true);
+                    break;
+                }
+                ProgressState::Inc(val) => {
+                    value.set(value.get().saturating_add(val));
+                }
                 ProgressState::Max(val) => {
                     max.set(val);
                 }
@@ -229,7 +265,32 @@ pub fn Progress<'a>(props: &mut ProgressProps, mut hooks: Hooks) -> impl Into<An
     if should_exit.get() {
         system.exit();
 
-        return element!(View).into_any();
+        // When finished, render the completed state as the final frame
+        if !finished.get() {
+            return element!(View).into_any();
+        }
+    }
+
+    // Plain output can't redraw in place, so render a simple
+    // "value/max message" line with no bar or loader frames
+    if theme.plain_output {
+        let content = get_message(MessageData {
+            estimator: estimator.read(),
+            max: max.get(),
+            message: message.read(),
+            started: started.get(),
+            value: value.get(),
+        });
+
+        return match display.get() {
+            ProgressDisplay::Bar => element! {
+                StyledText(content: format!("{prefix}{}/{} {content}{suffix}", value.get(), max.get()))
+            },
+            ProgressDisplay::Loader => element! {
+                StyledText(content: format!("{prefix}{content}{suffix}"))
+            },
+        }
+        .into_any();
     }
 
     match display.get() {
@@ -324,6 +385,64 @@ pub fn Progress<'a>(props: &mut ProgressProps, mut hooks: Hooks) -> impl Into<An
     }
 }
 
+/// Tokens that can be used within a progress message template, like `{value}`.
+pub const PROGRESS_MESSAGE_TOKENS: [&str; 17] = [
+    "value",
+    "total",
+    "max",
+    "percent",
+    "bytes",
+    "total_bytes",
+    "binary_bytes",
+    "binary_total_bytes",
+    "decimal_bytes",
+    "decimal_total_bytes",
+    "elapsed",
+    "eta",
+    "duration",
+    "per_sec",
+    "bytes_per_sec",
+    "binary_bytes_per_sec",
+    "decimal_bytes_per_sec",
+];
+
+/// Validate that a progress message template only contains known
+/// [tokens](PROGRESS_MESSAGE_TOKENS). If not, return a list of the
+/// unknown tokens (without braces), in the order they were found.
+/// Braces that don't wrap a token-like name (`[a-z0-9_]+`) are ignored.
+pub fn validate_template(template: &str) -> Result<(), Vec<String>> {
+    let mut unknown = vec![];
+    let mut rest = template;
+
+    while let Some(start) = rest.find('{') {
+        rest = &rest[start + 1..];
+
+        let Some(end) = rest.find('}') else {
+            break;
+        };
+
+        let name = &rest[..end];
+
+        if !name.is_empty()
+            && name
+                .chars()
+                .all(|ch| ch.is_ascii_lowercase() || ch.is_ascii_digit() || ch == '_')
+        {
+            if !PROGRESS_MESSAGE_TOKENS.contains(&name) && !unknown.iter().any(|t| t == name) {
+                unknown.push(name.to_owned());
+            }
+
+            rest = &rest[end + 1..];
+        }
+    }
+
+    if unknown.is_empty() {
+        Ok(())
+    } else {
+        Err(unknown)
+    }
+}
+
 fn calculate_percent(value: u64, max: u64) -> f64 {
     (max as f64 * (value as f64 / 100.0)).clamp(0.0, 100.0)
 }
@@ -1,8 +1,12 @@
+use super::styled_text::StyledText;
 use crate::ui::{ConsoleTheme, Variant};
 use iocraft::prelude::*;
 
 #[derive(Default, Props)]
 pub struct SectionProps<'a> {
+    pub collapsed: bool,
+    pub indent: usize,
+    pub summary: Option<String>,
     pub title: String,
     pub title_color: Option<Color>,
     pub children: Vec<AnyElement<'a>>,
@@ -16,6 +20,7 @@ pub fn Section<'a>(props: &mut SectionProps<'a>, hooks: Hooks) -> impl Into<AnyE
     element! {
         View(
             flex_direction: FlexDirection::Column,
+            margin_left: (props.indent * 2) as i32,
             width: Size::Percent(100.0),
         ) {
             View(
@@ -42,6 +47,25 @@ pub fn Section<'a>(props: &mut SectionProps<'a>, hooks: Hooks) -> impl Into<AnyE
             }
             #(if props.children.is_empty() {
                 None
+            } else if props.collapsed {
+                let summary = props.summary.clone().unwrap_or_else(|| {
+                    let count = props.children.len();
+
+                    format!(
+                        "<mutedlight>{count} {} hidden</mutedlight>",
+                        if count == 1 { "item" } else { "items" }
+                    )
+                });
+
+                Some(element! {
+                    View(
+                        padding_top: 1,
+                        padding_left: 2,
+                        padding_bottom: 1,
+                    ) {
+                        StyledText(content: summary)
+                    }
+                })
             } else {
                 Some(element! {
                     View(
@@ -44,8 +44,11 @@ pub struct SelectProps<'a> {
     pub description: Option<String>,
     pub label: String,
     pub legend: bool,
+    pub max_selected: Option<usize>,
+    pub min_selected: Option<usize>,
     pub multiple: bool,
     pub options: Vec<SelectOption>,
+    pub page_size: usize,
     pub prefix_symbol: Option<String>,
     pub selected_symbol: Option<String>,
     pub on_index: Option<&'a mut usize>,
@@ -60,8 +63,11 @@ impl Default for SelectProps<'_> {
             description: None,
             label: "".into(),
             legend: true,
+            max_selected: None,
+            min_selected: None,
             multiple: false,
             options: vec![],
+            page_size: 10,
             prefix_symbol: None,
             selected_symbol: None,
             on_index: None,
@@ -70,6 +76,29 @@ impl Default for SelectProps<'_> {
     }
 }
 
+// Find the closest enabled option to the index, first searching in the
+// provided direction, then the opposite direction. Does not wrap around.
+fn find_enabled_index(options: &[SelectOption], index: usize, forward: bool) -> Option<usize> {
+    let is_enabled = |i: &usize| options.get(*i).is_some_and(|opt| !opt.disabled);
+    let index = index.min(options.len().saturating_sub(1));
+
+    let mut after = index..options.len();
+    let mut before = (0..=index).rev();
+
+    if forward {
+        after.find(is_enabled).or_else(|| before.find(is_enabled))
+    } else {
+        before.find(is_enabled).or_else(|| after.find(is_enabled))
+    }
+}
+
+fn format_count_error(bound: &str, count: usize) -> String {
+    format!(
+        "Please select {bound} {count} {}",
+        if count == 1 { "option" } else { "options" }
+    )
+}
+
 #[component]
 pub fn Select<'a>(props: &mut SelectProps<'a>, mut hooks: Hooks) -> impl Into<AnyElement<'a>> {
     let theme = hooks.use_context::<ConsoleTheme>();
@@ -90,6 +119,9 @@ pub fn Select<'a>(props: &mut SelectProps<'a>, mut hooks: Hooks) -> impl Into<An
     let mut error = hooks.use_state(|| None);
 
     let multiple = props.multiple;
+    let max_selected = props.max_selected.filter(|_| multiple);
+    let min_selected = props.min_selected.filter(|_| multiple);
+    let page_size = props.page_size.max(1);
     let option_last_index = options.read().len() - 1;
 
     let get_next_index = move |current: usize, step: isize| -> usize {
@@ -115,6 +147,10 @@ pub fn Select<'a>(props: &mut SelectProps<'a>, mut hooks: Hooks) -> impl Into<An
 
                         if selected_index.read().contains(&index) {
                             selected_index.write().remove(&index);
+                        } else if let Some(max) =
+                            max_selected.filter(|max| selected_index.read().len() >= *max)
+                        {
+                            error.set(Some(format_count_error("at most", max)));
                         } else {
                             if !multiple {
                                 selected_index.write().clear();
@@ -122,9 +158,30 @@ pub fn Select<'a>(props: &mut SelectProps<'a>, mut hooks: Hooks) -> impl Into<An
                             selected_index.write().insert(index);
                         }
                     }
+                    KeyCode::Char('a') if multiple => {
+                        let indexes = options
+                            .read()
+                            .iter()
+                            .enumerate()
+                            .filter(|(_, opt)| !opt.disabled)
+                            .map(|(index, _)| index)
+                            .take(max_selected.unwrap_or(usize::MAX))
+                            .collect();
+
+                        selected_index.set(indexes);
+                    }
+                    KeyCode::Char('n') if multiple => {
+                        selected_index.write().clear();
+                    }
                     KeyCode::Enter => {
-                        if selected_index.read().is_empty() {
+                        let count = selected_index.read().len();
+
+                        if count == 0 {
                             error.set(Some("Please select an option".into()));
+                        } else if let Some(min) = min_selected.filter(|min| count < *min) {
+                            error.set(Some(format_count_error("at least", min)));
+                        } else if let Some(max) = max_selected.filter(|max| count > *max) {
+                            error.set(Some(format_count_error("at most", max)));
                         } else {
                             should_exit.set(true);
                         }
@@ -163,6 +220,36 @@ pub fn Select<'a>(props: &mut SelectProps<'a>, mut hooks: Hooks) -> impl Into<An
 
                         active_index.set(next_index);
                     }
+                    KeyCode::Home => {
+                        if let Some(next_index) = find_enabled_index(&options.read(), 0, true) {
+                            active_index.set(next_index);
+                        }
+                    }
+                    KeyCode::End => {
+                        if let Some(next_index) =
+                            find_enabled_index(&options.read(), option_last_index, false)
+                        {
+                            active_index.set(next_index);
+                        }
+                    }
+                    KeyCode::PageUp => {
+                        if let Some(next_index) = find_enabled_index(
+                            &options.read(),
+                            active_index.get().saturating_sub(page_size),
+                            false,
+                        ) {
+                            active_index.set(next_index);
+                        }
+                    }
+                    KeyCode::PageDown => {
+                        if let Some(next_index) = find_enabled_index(
+                            &options.read(),
+                            active_index.get() + page_size,
+                            true,
+                        ) {
+                            active_index.set(next_index);
+                        }
+                    }
                     _ => {}
                 }
             }
@@ -206,12 +293,18 @@ pub fn Select<'a>(props: &mut SelectProps<'a>, mut hooks: Hooks) -> impl Into<An
             description: props.description.clone(),
             error: Some(error),
             footer: props.legend.then(|| {
+                let mut legend = vec![("⎵".into(), "select".into())];
+
+                if multiple {
+                    legend.push(("a".into(), "all".into()));
+                    legend.push(("n".into(), "none".into()));
+                }
+
+                legend.push(("↕".into(), "cycle".into()));
+                legend.push(("↵".into(), "submit".into()));
+
                 element! {
-                    InputLegend(legend: vec![
-                        ("⎵".into(), "select".into()),
-                        ("↕".into(), "cycle".into()),
-                        ("↵".into(), "submit".into()),
-                    ])
+                    InputLegend(legend)
                 }.into_any()
             })
         ) {
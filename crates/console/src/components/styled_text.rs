@@ -5,7 +5,7 @@ use starbase_styles::color::parse_tags;
 pub use starbase_styles::Style;
 
 pub fn style_to_color(style: Style) -> Color {
-    Color::AnsiValue(style.color() as u8)
+    Color::AnsiValue(style.color_code())
 }
 
 #[derive(Default, Props)]
@@ -13,6 +13,79 @@ struct TableContext {
     pub col_data: Vec<TableHeader>,
 }
 
+// Space between columns, and the left/right borders of the table
+const COL_GAP: u32 = 2;
+const BORDER_WIDTH: u32 = 2;
+
+/// Resolve all columns to fit within the available width. `Length` columns
+/// keep their width, `Percent` columns are converted to a length of the
+/// available width, and `Auto` columns share the remaining width equally,
+/// so that their content wraps instead of overflowing. If the `Length` and
+/// `Percent` columns alone exceed the available width, they are shrunk
+/// proportionally, so that the table never overflows.
+fn fit_columns(headers: &[TableHeader], available_width: u32) -> Vec<TableHeader> {
+    let gaps = COL_GAP * (headers.len().saturating_sub(1) as u32);
+    let inner_width = available_width.saturating_sub(BORDER_WIDTH + gaps);
+    let mut used_width = 0;
+    let mut auto_count = 0;
+
+    let mut columns = headers
+        .iter()
+        .map(|header| {
+            let mut header = header.clone();
+
+            match header.width {
+                Size::Length(length) => {
+                    used_width += length;
+                }
+                Size::Percent(percent) => {
+                    let length = (inner_width as f32 * percent / 100.0).floor() as u32;
+
+                    header.width = Size::Length(length);
+                    used_width += length;
+                }
+                Size::Auto | Size::Unset => {
+                    auto_count += 1;
+                }
+            };
+
+            header
+        })
+        .collect::<Vec<_>>();
+
+    // Reserve at least 1 column of width for each auto column
+    let fixed_width = inner_width.saturating_sub(auto_count);
+
+    if used_width > fixed_width {
+        let total_width = used_width;
+
+        used_width = 0;
+
+        for header in columns.iter_mut() {
+            if let Size::Length(length) = header.width {
+                let length = (length as u64 * fixed_width as u64 / total_width as u64) as u32;
+
+                header.width = Size::Length(length);
+                used_width += length;
+            }
+        }
+    }
+
+    let auto_width = if auto_count > 0 {
+        (inner_width.saturating_sub(used_width) / auto_count).max(1)
+    } else {
+        0
+    };
+
+    for header in columns.iter_mut() {
+        if matches!(header.width, Size::Auto | Size::Unset) {
+            header.width = Size::Length(auto_width);
+        }
+    }
+
+    columns
+}
+
 #[derive(Clone, Default)]
 pub struct TableHeader {
     pub align: TextAlign,
@@ -47,13 +120,30 @@ impl From<&str> for TableHeader {
 pub struct TableProps<'a> {
     pub children: Vec<AnyElement<'a>>,
     pub headers: Vec<TableHeader>,
+    /// Fit the table within the terminal width, by wrapping the
+    /// content of `Auto` columns.
+    pub auto_fit: bool,
+    /// Fit the table within this width instead of the terminal width.
+    /// Implies `auto_fit`.
+    pub max_width: Option<u32>,
 }
 
 #[component]
-pub fn Table<'a>(props: &mut TableProps<'a>, hooks: Hooks) -> impl Into<AnyElement<'a>> {
+pub fn Table<'a>(props: &mut TableProps<'a>, mut hooks: Hooks) -> impl Into<AnyElement<'a>> {
     let theme = hooks.use_context::<ConsoleTheme>();
+    let (terminal_width, _) = hooks.use_terminal_size();
+
+    let fit_width = props.max_width.or(if props.auto_fit && terminal_width > 0 {
+        Some(terminal_width as u32)
+    } else {
+        None
+    });
+
     let context = TableContext {
-        col_data: props.headers.clone(),
+        col_data: match fit_width {
+            Some(width) => fit_columns(&props.headers, width),
+            None => props.headers.clone(),
+        },
     };
 
     element! {
@@ -63,6 +153,7 @@ pub fn Table<'a>(props: &mut TableProps<'a>, hooks: Hooks) -> impl Into<AnyEleme
                 border_style: BorderStyle::Round,
                 flex_direction: FlexDirection::Column,
                 width: Size::Auto,
+                max_width: fit_width.map(Size::Length).unwrap_or(Size::Auto),
             ) {
                 View(
                     border_edges: Edges::Bottom,
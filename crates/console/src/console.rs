@@ -1,7 +1,10 @@
 use crate::reporter::*;
 use crate::stream::*;
 #[cfg(feature = "ui")]
-use crate::theme::ConsoleTheme;
+use crate::theme::{ConsoleTheme, Variant};
+use crate::utils::clipboard::create_osc52_sequence;
+#[cfg(feature = "ui")]
+use starbase_styles::color::{get_color_mode, ColorMode};
 use std::fmt;
 use std::ops::Deref;
 use std::sync::atomic::{AtomicBool, Ordering};
@@ -19,6 +22,9 @@ pub struct Console<R: Reporter> {
     quiet: Arc<AtomicBool>,
     reporter: Option<Arc<R>>,
 
+    #[cfg(feature = "ui")]
+    plain: bool,
+
     #[cfg(feature = "ui")]
     theme: ConsoleTheme,
 }
@@ -43,6 +49,8 @@ impl<R: Reporter> Console<R> {
             quiet,
             reporter: None,
             #[cfg(feature = "ui")]
+            plain: false,
+            #[cfg(feature = "ui")]
             theme: Default::default(),
         }
     }
@@ -56,6 +64,8 @@ impl<R: Reporter> Console<R> {
             quiet: Arc::new(AtomicBool::new(false)),
             reporter: None,
             #[cfg(feature = "ui")]
+            plain: false,
+            #[cfg(feature = "ui")]
             theme: Default::default(),
         }
     }
@@ -77,10 +87,41 @@ impl<R: Reporter> Console<R> {
         Ok(())
     }
 
+    /// Return true if components should be rendered as plain text,
+    /// without colors, cursor movement, or animations.
+    #[cfg(feature = "ui")]
+    pub fn is_plain(&self) -> bool {
+        self.plain
+    }
+
+    /// Copy the provided text to the system clipboard, using the OSC 52
+    /// escape sequence. Returns false if stdout is not a terminal, as the
+    /// sequence is not supported, otherwise true.
+    pub fn copy_to_clipboard(&self, text: &str) -> miette::Result<bool> {
+        if !self.out.is_terminal() {
+            trace!("Unable to copy to clipboard, stdout is not a terminal");
+
+            return Ok(false);
+        }
+
+        self.out.write(create_osc52_sequence(text))?;
+        self.out.flush()?;
+
+        Ok(true)
+    }
+
     pub fn quiet(&self) {
         self.quiet.store(true, Ordering::Release);
     }
 
+    /// Return the stream of the provided type.
+    pub fn stream(&self, target: ConsoleStreamType) -> &ConsoleStream {
+        match target {
+            ConsoleStreamType::Stderr => &self.err,
+            ConsoleStreamType::Stdout => &self.out,
+        }
+    }
+
     pub fn stderr(&self) -> ConsoleStream {
         self.err.clone()
     }
@@ -97,9 +138,14 @@ impl<R: Reporter> Console<R> {
         )
     }
 
+    /// Return a copy of the theme. Color support is read from the current
+    /// process-wide color mode, so that changing the mode after the console
+    /// was created still applies.
     #[cfg(feature = "ui")]
     pub fn theme(&self) -> ConsoleTheme {
-        self.theme.clone()
+        let mut theme = self.theme.clone();
+        theme.supports_color = get_color_mode() != ColorMode::Never;
+        theme
     }
 
     pub fn set_reporter(&mut self, mut reporter: R) {
@@ -111,6 +157,13 @@ impl<R: Reporter> Console<R> {
         self.reporter = Some(Arc::new(reporter));
     }
 
+    /// Force components to be rendered as plain text. This is automatically
+    /// enabled when the output stream is not a terminal (e.g. CI logs).
+    #[cfg(feature = "ui")]
+    pub fn set_plain(&mut self, plain: bool) {
+        self.plain = plain;
+    }
+
     #[cfg(feature = "ui")]
     pub fn set_theme(&mut self, theme: crate::theme::ConsoleTheme) {
         if let Some(arc_reporter) = &mut self.reporter {
@@ -123,6 +176,36 @@ impl<R: Reporter> Console<R> {
     }
 }
 
+#[cfg(feature = "ui")]
+impl<R: Reporter> Console<R> {
+    /// Print a themed notice containing the provided message, for summarizing
+    /// the result of a task. Colors are only used when supported.
+    pub fn print_result(&self, variant: Variant, message: impl AsRef<str>) -> miette::Result<()> {
+        self.render(crate::ui::create_result_notice(variant, message))
+    }
+
+    /// Print a themed success notice containing the provided message.
+    pub fn print_success(&self, message: impl AsRef<str>) -> miette::Result<()> {
+        self.print_result(Variant::Success, message)
+    }
+
+    /// Print a themed failure notice containing the provided message to stderr.
+    pub fn print_error(&self, message: impl AsRef<str>) -> miette::Result<()> {
+        self.render_to(
+            crate::ui::create_result_notice(Variant::Failure, message),
+            ConsoleStreamType::Stderr,
+        )
+    }
+
+    /// Print a themed caution notice containing the provided message to stderr.
+    pub fn print_warning(&self, message: impl AsRef<str>) -> miette::Result<()> {
+        self.render_to(
+            crate::ui::create_result_notice(Variant::Caution, message),
+            ConsoleStreamType::Stderr,
+        )
+    }
+}
+
 impl<R: Reporter> Clone for Console<R> {
     fn clone(&self) -> Self {
         Self {
@@ -133,6 +216,8 @@ impl<R: Reporter> Clone for Console<R> {
             quiet: self.quiet.clone(),
             reporter: self.reporter.clone(),
             #[cfg(feature = "ui")]
+            plain: self.plain,
+            #[cfg(feature = "ui")]
             theme: self.theme.clone(),
         }
     }
@@ -148,7 +233,7 @@ impl<R: Reporter> fmt::Debug for Console<R> {
             .field("reporter", &self.reporter);
 
         #[cfg(feature = "ui")]
-        dbg.field("theme", &self.theme);
+        dbg.field("plain", &self.plain).field("theme", &self.theme);
 
         dbg.finish()
     }
@@ -8,7 +8,7 @@ use std::sync::{mpsc, Arc};
 use std::thread::{spawn, JoinHandle};
 use tracing::trace;
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ConsoleStreamType {
     Stderr,
     Stdout,
@@ -19,6 +19,7 @@ pub struct ConsoleStream {
     channel: Option<mpsc::Sender<bool>>,
     stream: ConsoleStreamType,
 
+    pub(crate) captured: bool,
     pub(crate) handle: Option<JoinHandle<()>>,
     pub(crate) quiet: Option<Arc<AtomicBool>>,
     pub(crate) test_mode: bool,
@@ -39,6 +40,7 @@ impl ConsoleStream {
 
         Self {
             buffer,
+            captured: false,
             channel: Some(tx),
             handle,
             stream,
@@ -57,9 +59,19 @@ impl ConsoleStream {
         console
     }
 
+    /// Create a stream that captures all written output in its buffer,
+    /// instead of flushing it to the underlying stream. The captured
+    /// output can be read with [`ConsoleStream::captured_output`].
+    pub fn new_captured(stream: ConsoleStreamType) -> Self {
+        let mut console = Self::internal_new(stream, false);
+        console.captured = true;
+        console
+    }
+
     pub fn empty(stream: ConsoleStreamType) -> Self {
         Self {
             buffer: Arc::new(Mutex::new(Vec::new())),
+            captured: false,
             channel: None,
             stream,
             handle: None,
@@ -82,7 +94,20 @@ impl ConsoleStream {
     }
 
     pub fn buffer(&self) -> ConsoleBuffer {
-        ConsoleBuffer::new(self.buffer.clone(), self.stream)
+        let mut buffer = ConsoleBuffer::new(self.buffer.clone(), self.stream);
+        buffer.captured = self.captured;
+        buffer
+    }
+
+    /// Return the output that has been written to a
+    /// [captured](ConsoleStream::new_captured) stream.
+    pub fn captured_output(&self) -> String {
+        String::from_utf8_lossy(&self.buffer.lock()).into_owned()
+    }
+
+    /// Return the type of the underlying stream.
+    pub fn stream_type(&self) -> ConsoleStreamType {
+        self.stream
     }
 
     pub fn close(&self) -> miette::Result<()> {
@@ -105,6 +130,10 @@ impl ConsoleStream {
     }
 
     pub fn flush(&self) -> miette::Result<()> {
+        if self.captured {
+            return Ok(());
+        }
+
         flush(&mut self.buffer.lock(), self.stream).into_diagnostic()?;
 
         Ok(())
@@ -114,8 +143,12 @@ impl ConsoleStream {
         &self,
         mut op: F,
     ) -> miette::Result<()> {
+        // When capturing just write to the buffer
+        if self.captured {
+            op(&mut self.buffer.lock()).into_diagnostic()?;
+        }
         // When testing just flush immediately
-        if self.test_mode {
+        else if self.test_mode {
             let mut buffer = Vec::new();
 
             op(&mut buffer).into_diagnostic()?;
@@ -188,6 +221,7 @@ impl Clone for ConsoleStream {
         Self {
             buffer: Arc::clone(&self.buffer),
             stream: self.stream,
+            captured: self.captured,
             quiet: self.quiet.clone(),
             test_mode: self.test_mode,
             // Ignore for clones
@@ -202,6 +236,7 @@ impl fmt::Debug for ConsoleStream {
         f.debug_struct("ConsoleStream")
             .field("buffer", &self.buffer)
             .field("stream", &self.stream)
+            .field("captured", &self.captured)
             .field("quiet", &self.quiet)
             .field("test_mode", &self.test_mode)
             .finish()
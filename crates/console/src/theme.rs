@@ -1,7 +1,9 @@
 use crate::ui::style_to_color;
 use iocraft::Color;
-use starbase_styles::{color::Color as NativeColor, Style};
+use starbase_styles::color::{get_color_mode, Color as NativeColor, ColorMode};
+use starbase_styles::Style;
 use std::collections::HashMap;
+use std::env;
 
 // https://www.ditig.com/publications/256-colors-cheat-sheet
 #[derive(Clone, Debug)]
@@ -32,6 +34,12 @@ pub struct ConsoleTheme {
     pub layout_list_bullet: String,
     pub layout_map_separator: String,
 
+    // Notices
+    pub notice_caution_symbol: String,
+    pub notice_failure_symbol: String,
+    pub notice_info_symbol: String,
+    pub notice_success_symbol: String,
+
     // Progress
     pub progress_bar_color: Color,
     pub progress_bar_filled_char: char,
@@ -62,7 +70,9 @@ pub struct ConsoleTheme {
     pub style_url_color: Color,
 
     // Misc
+    pub plain_output: bool,
     pub supports_color: bool,
+    pub supports_unicode: bool,
     pub custom_tags: HashMap<String, Color>,
 }
 
@@ -84,6 +94,10 @@ impl Default for ConsoleTheme {
             layout_fallback_symbol: "—".into(),
             layout_list_bullet: "-".into(),
             layout_map_separator: "=".into(),
+            notice_caution_symbol: "⚠".into(),
+            notice_failure_symbol: "✘".into(),
+            notice_info_symbol: "ℹ".into(),
+            notice_success_symbol: "✔".into(),
             progress_bar_color: Color::White,
             progress_bar_filled_char: '█',
             progress_bar_position_char: '▒',
@@ -107,7 +121,9 @@ impl Default for ConsoleTheme {
             style_shell_color: style_to_color(Style::Shell),
             style_symbol_color: style_to_color(Style::Symbol),
             style_url_color: style_to_color(Style::Url),
-            supports_color: true,
+            plain_output: false,
+            supports_color: get_color_mode() != ColorMode::Never,
+            supports_unicode: is_unicode_supported(),
             custom_tags: HashMap::new(),
         }
     }
@@ -179,6 +195,25 @@ impl ConsoleTheme {
     }
 }
 
+// Based on the `is-unicode-supported` npm package
+fn is_unicode_supported() -> bool {
+    let var = |name| env::var(name).unwrap_or_default();
+
+    if !cfg!(windows) {
+        return var("TERM") != "linux";
+    }
+
+    env::var("CI").is_ok()
+        || env::var("WT_SESSION").is_ok()
+        || env::var("TERMINUS_SUBLIME").is_ok()
+        || var("ConEmuTask") == "{cmd::Cmder}"
+        || var("TERM_PROGRAM") == "Terminus-Sublime"
+        || var("TERM_PROGRAM") == "vscode"
+        || var("TERM") == "xterm-256color"
+        || var("TERM") == "alacritty"
+        || var("TERMINAL_EMULATOR") == "JetBrains-JediTerm"
+}
+
 #[derive(Clone, Copy, Default)]
 pub enum Variant {
     Caution,
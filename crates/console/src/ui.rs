@@ -1,8 +1,15 @@
 use crate::console::Console;
 use crate::reporter::Reporter;
+use crate::stream::{ConsoleStream, ConsoleStreamType};
+use futures::stream::StreamExt;
 use iocraft::prelude::*;
 use miette::IntoDiagnostic;
+use starbase_styles::color::{get_color_mode, ColorMode};
 use std::env;
+use std::future::Future;
+use std::io::Write;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
 
 pub use crate::components::*;
 pub use crate::theme::*;
@@ -11,12 +18,38 @@ fn is_forced_tty() -> bool {
     env::var("STARBASE_FORCE_TTY").is_ok()
 }
 
+fn is_color_enabled(is_tty: bool) -> bool {
+    match get_color_mode() {
+        ColorMode::Always => true,
+        ColorMode::Never => false,
+        ColorMode::Auto => env::var("NO_COLOR").is_err() && is_tty,
+    }
+}
+
 impl<R: Reporter> Console<R> {
+    /// Render the element to stdout. Use this for data and results that
+    /// should be captured when piping.
     pub fn render<T: Component>(&self, element: Element<'_, T>) -> miette::Result<()> {
-        let is_tty = is_forced_tty() || self.out.is_terminal();
+        self.render_to(element, ConsoleStreamType::Stdout)
+    }
+
+    /// Render the element to the provided stream.
+    pub fn render_to<T: Component>(
+        &self,
+        element: Element<'_, T>,
+        target: ConsoleStreamType,
+    ) -> miette::Result<()> {
+        let stream = self.stream(target);
+        let is_tty = is_forced_tty() || stream.is_terminal();
+
+        let is_plain = self.is_plain() || !is_tty;
 
         let mut theme = self.theme();
-        theme.supports_color = env::var("NO_COLOR").is_err() && is_tty;
+        theme.plain_output = is_plain;
+        // Piped output is plain, but an explicit color mode still applies
+        theme.supports_color = !self.is_plain() && is_color_enabled(is_tty);
+
+        let with_ansi = !is_plain || theme.supports_color;
 
         let canvas = element! {
             ContextProvider(value: Context::owned(theme)) {
@@ -29,19 +62,51 @@ impl<R: Reporter> Console<R> {
             None
         });
 
-        let buffer = self.out.buffer();
+        let buffer = stream.buffer();
 
-        if is_tty {
+        if with_ansi {
             canvas.write_ansi(buffer).into_diagnostic()?;
         } else {
             canvas.write(buffer).into_diagnostic()?;
         }
 
-        self.out.flush()?;
+        stream.flush()?;
 
         Ok(())
     }
 
+    /// Render the element to a string instead of writing to a stream. The string
+    /// is plain text when [plain](Console::is_plain), otherwise it contains ANSI
+    /// escape codes (colors are only included when enabled). Only non-interactive
+    /// elements are supported, and the width is unconstrained.
+    pub fn render_to_string<T: Component>(
+        &self,
+        element: Element<'_, T>,
+    ) -> miette::Result<String> {
+        let is_plain = self.is_plain();
+
+        let mut theme = self.theme();
+        theme.plain_output = is_plain;
+        theme.supports_color = !is_plain && is_color_enabled(true);
+
+        let canvas = element! {
+            ContextProvider(value: Context::owned(theme)) {
+                #(element)
+            }
+        }
+        .render(None);
+
+        if is_plain {
+            return Ok(canvas.to_string());
+        }
+
+        let mut buffer = Vec::new();
+
+        canvas.write_ansi(&mut buffer).into_diagnostic()?;
+
+        String::from_utf8(buffer).into_diagnostic()
+    }
+
     pub async fn render_interactive<T: Component>(
         &self,
         element: Element<'_, T>,
@@ -53,14 +118,51 @@ impl<R: Reporter> Console<R> {
             return Ok(());
         }
 
-        self.render_loop(element).await
+        // Prompts require key events, which only the terminal loop delivers,
+        // so they are never routed through the plain loop
+        self.render_terminal_loop(element).await
     }
 
+    /// Render the element in a loop until it exits, using the terminal render loop.
     pub async fn render_loop<T: Component>(&self, element: Element<'_, T>) -> miette::Result<()> {
-        let is_tty = is_forced_tty() || self.out.is_terminal();
+        self.render_terminal_loop(element).await
+    }
 
+    /// Render the element in a loop until it exits. When plain, or when the provided
+    /// stream or stdout is not a terminal, each change is written to the stream as
+    /// a new plain line (see [`render_plain_loop`](Console::render_plain_loop)).
+    /// Otherwise the terminal render loop is used, which always draws to stdout.
+    ///
+    /// The plain loop is driven on the current task, so the returned future is
+    /// not `Send`. Use [`render_loop`](Console::render_loop) when it must be.
+    pub async fn render_loop_to<T: Component>(
+        &self,
+        element: Element<'_, T>,
+        target: ConsoleStreamType,
+    ) -> miette::Result<()> {
+        let stream = self.stream(target);
+
+        // Cursor movement and animations are unreadable in logs,
+        // so render each change as a new plain line instead
+        if self.is_plain_loop(stream) {
+            self.render_plain_loop(element, stream.buffer()).await?;
+            stream.flush()?;
+
+            return Ok(());
+        }
+
+        stream.flush()?;
+
+        self.render_terminal_loop(element).await
+    }
+
+    // The terminal render loop always draws to stdout.
+    async fn render_terminal_loop<T: Component>(
+        &self,
+        element: Element<'_, T>,
+    ) -> miette::Result<()> {
         let mut theme = self.theme();
-        theme.supports_color = env::var("NO_COLOR").is_err() && is_tty;
+        theme.supports_color = is_color_enabled(true);
 
         self.out.flush()?;
 
@@ -77,4 +179,118 @@ impl<R: Reporter> Console<R> {
 
         Ok(())
     }
+
+    fn is_plain_loop(&self, stream: &ConsoleStream) -> bool {
+        let is_tty = is_forced_tty() || (stream.is_terminal() && self.out.is_terminal());
+
+        self.is_plain() || !is_tty
+    }
+
+    /// Render an indeterminate [`Progress`] loader with the provided message
+    /// while the future is being awaited. Once the future completes, the loader
+    /// is stopped and the future's output is returned. When plain, the message
+    /// is written once instead.
+    pub async fn with_spinner<F: Future>(
+        &self,
+        message: impl AsRef<str>,
+        future: F,
+    ) -> miette::Result<F::Output> {
+        if self.is_plain_loop(&self.err) {
+            self.err.write_line(message.as_ref())?;
+            self.err.flush()?;
+
+            return Ok(future.await);
+        }
+
+        let reporter = ProgressReporter::default();
+        let exit_reporter = reporter.clone();
+        let rendered = AtomicBool::new(false);
+
+        let render = async {
+            let result = self
+                .render_loop(element! {
+                    Progress(
+                        default_message: message.as_ref().to_owned(),
+                        display: ProgressDisplay::Loader,
+                        reporter,
+                    )
+                })
+                .await;
+
+            rendered.store(true, Ordering::Release);
+            result
+        };
+
+        let run = async {
+            let output = future.await;
+
+            // The loader may not have subscribed to the reporter yet,
+            // so keep signaling until it has actually exited
+            while !rendered.load(Ordering::Acquire) {
+                exit_reporter.exit();
+                tokio::time::sleep(Duration::from_millis(25)).await;
+            }
+
+            output
+        };
+
+        let (result, output) = futures::join!(render, run);
+
+        result?;
+
+        Ok(output)
+    }
+
+    /// Render the element in a loop as plain text (no colors, cursor movement,
+    /// or animations) to the provided writer. A new line is written whenever
+    /// the output changes, throttled to at most once per second, while the
+    /// final output is always written.
+    ///
+    /// The returned future is not `Send`, as iocraft's mock terminal is used to
+    /// drive the loop. Use [`render_loop`](Console::render_loop) when the future
+    /// must be sent across threads.
+    pub async fn render_plain_loop<T: Component, W: Write>(
+        &self,
+        element: Element<'_, T>,
+        mut writer: W,
+    ) -> miette::Result<()> {
+        let mut theme = self.theme();
+        theme.plain_output = true;
+        theme.supports_color = false;
+
+        let mut element = element! {
+            ContextProvider(value: Context::owned(theme)) {
+                #(element)
+            }
+        };
+        let mut canvases = element.mock_terminal_render_loop(MockTerminalConfig::default());
+        let mut last_line = String::new();
+        let mut last_written: Option<Instant> = None;
+        let mut pending_line = None;
+
+        while let Some(canvas) = canvases.next().await {
+            let line = canvas.to_string().trim_end().to_owned();
+
+            if line.is_empty() || line == last_line {
+                continue;
+            }
+
+            if last_written.is_some_and(|at| at.elapsed() < Duration::from_secs(1)) {
+                pending_line = Some(line);
+                continue;
+            }
+
+            writeln!(writer, "{line}").into_diagnostic()?;
+
+            last_line = line;
+            last_written = Some(Instant::now());
+            pending_line = None;
+        }
+
+        if let Some(line) = pending_line {
+            writeln!(writer, "{line}").into_diagnostic()?;
+        }
+
+        Ok(())
+    }
 }
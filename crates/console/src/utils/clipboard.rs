@@ -0,0 +1,8 @@
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+
+/// Create an OSC 52 escape sequence that writes the provided text to the
+/// system clipboard, when supported by the terminal.
+pub fn create_osc52_sequence(text: &str) -> String {
+    format!("\x1b]52;c;{}\x07", STANDARD.encode(text))
+}
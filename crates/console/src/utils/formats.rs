@@ -1,9 +1,42 @@
 use std::time::Duration;
 
+/// Format a float to 1 decimal place, and strip the decimal if it's zero.
 pub fn format_float(value: f64) -> String {
     format!("{value:.1}").replace(".0", "")
 }
 
+/// Format an integer with thousands separators, for example, `1,234,567`.
+pub fn format_number(value: impl Into<i128>) -> String {
+    let value = value.into();
+    let digits = value.unsigned_abs().to_string();
+    let mut output = String::with_capacity(digits.len() + digits.len() / 3 + 1);
+
+    if value < 0 {
+        output.push('-');
+    }
+
+    for (index, ch) in digits.chars().enumerate() {
+        if index > 0 && (digits.len() - index) % 3 == 0 {
+            output.push(',');
+        }
+
+        output.push(ch);
+    }
+
+    output
+}
+
+/// Format the value as a percentage of the total, clamped between 0% and 100%.
+pub fn format_percent(value: u64, total: u64) -> String {
+    let percent = if total == 0 {
+        0.0
+    } else {
+        (value as f64 / total as f64 * 100.0).clamp(0.0, 100.0)
+    };
+
+    format!("{}%", format_float(percent))
+}
+
 pub const DECIMAL_BYTE_UNITS: &[&str] = &["B", "kB", "MB", "GB", "TB", "PB"];
 pub const BINARY_BYTE_UNITS: &[&str] = &["B", "KiB", "MiB", "GiB", "TiB", "PiB"];
 
@@ -14,7 +47,7 @@ fn format_bytes(mut size: f64, kb: f64, units: &[&str]) -> String {
 
     let mut prefix = 1;
 
-    while size >= kb && prefix < 6 {
+    while size >= kb && prefix < units.len() {
         size /= kb;
         prefix += 1;
     }
@@ -22,17 +55,19 @@ fn format_bytes(mut size: f64, kb: f64, units: &[&str]) -> String {
     format!("{} {}", format_float(size), units[prefix - 1])
 }
 
+/// Format bytes using binary (1024) units, for example, `1.5 MiB`.
 pub fn format_bytes_binary(size: u64) -> String {
     format_bytes(size as f64, 1024.0, BINARY_BYTE_UNITS)
 }
 
+/// Format bytes using decimal (1000) units, for example, `1.5 MB`.
 pub fn format_bytes_decimal(size: u64) -> String {
     format_bytes(size as f64, 1000.0, DECIMAL_BYTE_UNITS)
 }
 
-pub const NANOSECOND: Duration = Duration::from_nanos(1_000_000_000);
-pub const MICROSECOND: Duration = Duration::from_micros(1_000_000);
-pub const MILLISECOND: Duration = Duration::from_millis(1_000);
+pub const NANOSECOND: Duration = Duration::from_nanos(1);
+pub const MICROSECOND: Duration = Duration::from_micros(1);
+pub const MILLISECOND: Duration = Duration::from_millis(1);
 pub const SECOND: Duration = Duration::from_secs(1);
 pub const MINUTE: Duration = Duration::from_secs(60);
 pub const HOUR: Duration = Duration::from_secs(60 * 60);
@@ -52,22 +87,54 @@ pub const DURATION_UNITS: &[(Duration, &str, &str, &str)] = &[
     (YEAR, "year", "years", "y"),
 ];
 
+/// Format a duration into a human readable string, for example, `1h 5m 30s`.
+/// Durations are truncated to seconds, so sub-second durations are `0s`, and
+/// exact unit boundaries use the larger unit, for example, 60 seconds is `1m`.
 pub fn format_duration(duration: Duration, short_suffix: bool) -> String {
+    let output = format_duration_units(duration, SECOND, short_suffix);
+
+    if output.is_empty() {
+        return "0s".into();
+    }
+
+    output
+}
+
+/// Format a duration into a human readable string, like [`format_duration`],
+/// but sub-second durations are formatted with their largest unit only,
+/// for example, `250ms`.
+pub fn format_duration_precise(duration: Duration, short_suffix: bool) -> String {
+    let min_unit = if duration >= SECOND {
+        SECOND
+    } else {
+        NANOSECOND
+    };
+    let output = format_duration_units(duration, min_unit, short_suffix);
+
+    if output.is_empty() {
+        return if short_suffix {
+            "0s".into()
+        } else {
+            "0 seconds".into()
+        };
+    }
+
+    output
+}
+
+fn format_duration_units(duration: Duration, min_unit: Duration, short_suffix: bool) -> String {
     let mut nanos = duration.as_nanos();
     let mut output: Vec<String> = vec![];
 
     for (d, long, long_plural, short) in DURATION_UNITS.iter().rev() {
-        if nanos == 0 {
+        if nanos == 0 || *d < min_unit || (min_unit < SECOND && !output.is_empty()) {
             break;
         }
 
-        let mut count = 0;
         let amount = d.as_nanos();
+        let count = nanos / amount;
 
-        while nanos > amount {
-            nanos -= amount;
-            count += 1;
-        }
+        nanos -= count * amount;
 
         if count > 0 {
             output.push(if short_suffix {
@@ -80,9 +147,5 @@ pub fn format_duration(duration: Duration, short_suffix: bool) -> String {
         }
     }
 
-    if output.is_empty() {
-        return "0s".into();
-    }
-
     output.join(" ")
 }
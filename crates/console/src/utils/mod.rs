@@ -1,2 +1,4 @@
+pub mod clipboard;
 pub(crate) mod estimator;
 pub mod formats;
+pub mod text;
@@ -0,0 +1,50 @@
+use starbase_styles::color::{ansi_segments, display_width};
+use unicode_width::UnicodeWidthChar;
+
+/// Truncate the string to fit within the provided number of display columns,
+/// and append the ellipsis when truncated (the ellipsis counts towards the width).
+/// ANSI escape sequences are never cut, and are preserved after the truncation
+/// point, so that styles are still reset. Wide characters (e.g. CJK) that would
+/// exceed the width are dropped entirely. An ellipsis wider than the width is
+/// itself truncated.
+pub fn truncate_display(value: &str, width: usize, ellipsis: &str) -> String {
+    if display_width(value) <= width {
+        return value.to_owned();
+    }
+
+    // The ellipsis itself must fit within the width
+    if display_width(ellipsis) > width {
+        return truncate_display(ellipsis, width, "");
+    }
+
+    let max_width = width - display_width(ellipsis);
+    let mut output = String::with_capacity(value.len());
+    let mut current_width = 0;
+    let mut truncated = false;
+
+    for (segment, is_escape) in ansi_segments(value) {
+        if is_escape {
+            output.push_str(segment);
+            continue;
+        }
+
+        if truncated {
+            continue;
+        }
+
+        for ch in segment.chars() {
+            let ch_width = ch.width().unwrap_or_default();
+
+            if current_width + ch_width > max_width {
+                output.push_str(ellipsis);
+                truncated = true;
+                break;
+            }
+
+            output.push(ch);
+            current_width += ch_width;
+        }
+    }
+
+    output
+}
@@ -0,0 +1,19 @@
+use starbase_console::utils::clipboard::create_osc52_sequence;
+
+#[test]
+fn encodes_sequence() {
+    assert_eq!(create_osc52_sequence("hello"), "\x1b]52;c;aGVsbG8=\x07");
+}
+
+#[test]
+fn encodes_unicode() {
+    assert_eq!(
+        create_osc52_sequence("https://example.com/🔑"),
+        "\x1b]52;c;aHR0cHM6Ly9leGFtcGxlLmNvbS/wn5SR\x07"
+    );
+}
+
+#[test]
+fn encodes_empty() {
+    assert_eq!(create_osc52_sequence(""), "\x1b]52;c;\x07");
+}
@@ -0,0 +1,252 @@
+use iocraft::prelude::*;
+use starbase_console::ui::*;
+use starbase_console::{Console, ConsoleStream, ConsoleStreamType, EmptyReporter};
+use starbase_sandbox::assert_snapshot;
+
+fn create_console() -> Console<EmptyReporter> {
+    let mut console = Console::<EmptyReporter>::new_testing();
+    console.err = ConsoleStream::new_captured(ConsoleStreamType::Stderr);
+    console.out = ConsoleStream::new_captured(ConsoleStreamType::Stdout);
+    console.set_plain(true);
+    console
+}
+
+mod streams {
+    use super::*;
+
+    #[test]
+    fn renders_to_stdout_by_default() {
+        let console = create_console();
+
+        console
+            .render(element! {
+                StyledText(content: "data")
+            })
+            .unwrap();
+
+        assert_eq!(console.out.captured_output().trim(), "data");
+        assert_eq!(console.err.captured_output(), "");
+    }
+
+    #[test]
+    fn renders_to_chosen_stream() {
+        let console = create_console();
+
+        console
+            .render_to(
+                element! {
+                    StyledText(content: "message")
+                },
+                ConsoleStreamType::Stderr,
+            )
+            .unwrap();
+
+        assert_eq!(console.err.captured_output().trim(), "message");
+        assert_eq!(console.out.captured_output(), "");
+    }
+
+    #[test]
+    fn returns_stream_by_type() {
+        let console = create_console();
+
+        assert_eq!(
+            console.stream(ConsoleStreamType::Stderr).stream_type(),
+            ConsoleStreamType::Stderr
+        );
+        assert_eq!(
+            console.stream(ConsoleStreamType::Stdout).stream_type(),
+            ConsoleStreamType::Stdout
+        );
+    }
+
+    #[tokio::test]
+    async fn renders_loops_to_stderr_by_default() {
+        let console = create_console();
+
+        console
+            .with_spinner("Loading", async {
+                tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+            })
+            .await
+            .unwrap();
+
+        assert!(console.err.captured_output().contains("Loading"));
+        assert_eq!(console.out.captured_output(), "");
+    }
+
+    async fn render_progress_loop(console: &Console<EmptyReporter>) {
+        let reporter = ProgressReporter::default();
+        let reporter_clone = reporter.clone();
+
+        tokio::spawn(async move {
+            tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+            reporter_clone.set_max(10).set_value(10);
+            tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+            reporter_clone.exit();
+        });
+
+        console
+            .render_loop_to(
+                element! {
+                    Progress(reporter)
+                },
+                ConsoleStreamType::Stderr,
+            )
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn renders_plain_loops_on_current_thread() {
+        let console = create_console();
+
+        render_progress_loop(&console).await;
+
+        assert_eq!(console.err.captured_output().lines().last(), Some("10/10"));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn renders_plain_loops_on_multi_thread() {
+        let console = create_console();
+
+        render_progress_loop(&console).await;
+
+        assert_eq!(console.err.captured_output().lines().last(), Some("10/10"));
+    }
+
+    #[test]
+    fn render_loop_is_send() {
+        fn assert_send<T: Send>(_: T) {}
+
+        let console = create_console();
+
+        assert_send(console.render_loop(element! {
+            Progress(reporter: ProgressReporter::default())
+        }));
+    }
+
+    #[test]
+    fn renders_ansi_when_colors_are_forced() {
+        let mut console = create_console();
+        console.set_plain(false);
+
+        starbase_styles::color::set_color_mode(starbase_styles::color::ColorMode::Always);
+
+        console
+            .render(element! {
+                StyledText(content: "hello <id>world</id>")
+            })
+            .unwrap();
+
+        starbase_styles::color::set_color_mode(starbase_styles::color::ColorMode::Auto);
+
+        assert!(console.out.captured_output().contains('\x1b'));
+    }
+}
+
+mod print {
+    use super::*;
+
+    #[test]
+    fn prints_results_to_stdout() {
+        let console = create_console();
+
+        console.print_success("built").unwrap();
+
+        assert!(console.out.captured_output().contains("built"));
+        assert_eq!(console.err.captured_output(), "");
+    }
+
+    #[test]
+    fn prints_errors_to_stderr() {
+        let console = create_console();
+
+        console.print_error("failed").unwrap();
+
+        assert!(console.err.captured_output().contains("failed"));
+        assert_eq!(console.out.captured_output(), "");
+    }
+
+    #[test]
+    fn prints_warnings_to_stderr() {
+        let console = create_console();
+
+        console.print_warning("careful").unwrap();
+
+        assert!(console.err.captured_output().contains("careful"));
+        assert_eq!(console.out.captured_output(), "");
+    }
+}
+
+mod render_to_string {
+    use super::*;
+    use starbase_styles::color::{set_color_mode, ColorMode};
+
+    #[test]
+    fn renders_styled_text() {
+        let console = create_console();
+
+        let output = console
+            .render_to_string(element! {
+                StyledText(content: "hello <id>world</id>")
+            })
+            .unwrap();
+
+        assert_eq!(output.trim_end(), "hello world");
+        assert_eq!(console.out.captured_output(), "");
+    }
+
+    #[test]
+    fn renders_styled_text_with_ansi() {
+        let mut console = create_console();
+        console.set_plain(false);
+
+        set_color_mode(ColorMode::Always);
+
+        let output = console
+            .render_to_string(element! {
+                StyledText(content: "hello <id>world</id>")
+            })
+            .unwrap();
+
+        set_color_mode(ColorMode::Auto);
+
+        assert!(output.contains('\x1b'));
+        assert!(output.contains("world"));
+    }
+
+    #[test]
+    fn renders_table() {
+        let console = create_console();
+
+        let output = console
+            .render_to_string(element! {
+                Table(
+                    headers: vec![
+                        TableHeader::new("Name", Size::Length(10)),
+                        TableHeader::new("Value", Size::Auto),
+                    ],
+                ) {
+                    TableRow(row: 0) {
+                        TableCol(col: 0) {
+                            Text(content: "a")
+                        }
+                        TableCol(col: 1) {
+                            Text(content: "1")
+                        }
+                    }
+                    TableRow(row: 1) {
+                        TableCol(col: 0) {
+                            Text(content: "b")
+                        }
+                        TableCol(col: 1) {
+                            Text(content: "2")
+                        }
+                    }
+                }
+            })
+            .unwrap();
+
+        assert_snapshot!(output);
+    }
+}
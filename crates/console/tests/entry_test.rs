@@ -0,0 +1,71 @@
+use iocraft::prelude::*;
+use starbase_console::ui::*;
+use std::collections::BTreeMap;
+
+fn render(element: AnyElement<'static>) -> String {
+    element! {
+        ContextProvider(value: Context::owned(ConsoleTheme {
+            supports_color: false,
+            ..Default::default()
+        })) {
+            #(element)
+        }
+    }
+    .render(Some(80))
+    .to_string()
+}
+
+mod entry_list {
+    use super::*;
+
+    #[test]
+    fn aligns_separators() {
+        let map = BTreeMap::from_iter([("name", "starbase"), ("version", "1.0.0"), ("id", "")]);
+
+        assert_eq!(
+            render(
+                element! {
+                    EntryList(entries: create_entries(map))
+                }
+                .into_any()
+            ),
+            "id     : —\nname   : starbase\nversion: 1.0.0\n"
+        );
+    }
+
+    #[test]
+    fn supports_elements_and_custom_separator() {
+        let entries = create_entries([
+            ("a", EntryValue::from("text")),
+            (
+                "long",
+                element! { Text(content: "element") }.into_any().into(),
+            ),
+        ]);
+
+        assert_eq!(
+            render(
+                element! {
+                    EntryList(entries, separator: " =".to_owned())
+                }
+                .into_any()
+            ),
+            "a    = text\nlong = element\n"
+        );
+    }
+
+    #[test]
+    fn aligns_wide_names() {
+        let map = BTreeMap::from_iter([("名前名前", "starbase"), ("version", "1.0.0")]);
+
+        assert_eq!(
+            render(
+                element! {
+                    EntryList(entries: create_entries(map))
+                }
+                .into_any()
+            ),
+            "version : 1.0.0\n名前名前: starbase\n"
+        );
+    }
+}
@@ -0,0 +1,104 @@
+use starbase_console::utils::formats::*;
+use std::time::Duration;
+
+mod formats {
+    use super::*;
+
+    #[test]
+    fn bytes_binary() {
+        assert_eq!(format_bytes_binary(0), "0B");
+        assert_eq!(format_bytes_binary(1023), "1023B");
+        assert_eq!(format_bytes_binary(1024), "1 KiB");
+        assert_eq!(format_bytes_binary(1536), "1.5 KiB");
+        assert_eq!(format_bytes_binary(5 * 1024 * 1024 * 1024 * 1024), "5 TiB");
+        assert_eq!(format_bytes_binary(u64::MAX), "16384 PiB");
+    }
+
+    #[test]
+    fn bytes_decimal() {
+        assert_eq!(format_bytes_decimal(0), "0B");
+        assert_eq!(format_bytes_decimal(999), "999B");
+        assert_eq!(format_bytes_decimal(1000), "1 kB");
+        assert_eq!(format_bytes_decimal(2_500_000), "2.5 MB");
+        assert_eq!(format_bytes_decimal(3_000_000_000_000), "3 TB");
+    }
+
+    #[test]
+    fn duration() {
+        assert_eq!(format_duration(Duration::ZERO, true), "0s");
+        assert_eq!(format_duration(Duration::ZERO, false), "0s");
+        assert_eq!(format_duration(Duration::from_millis(250), true), "0s");
+        assert_eq!(format_duration(Duration::from_millis(1500), true), "1s");
+        assert_eq!(format_duration(Duration::from_secs(1), false), "1 second");
+        assert_eq!(
+            format_duration(Duration::from_secs(3930), true),
+            "1h 5m 30s"
+        );
+        assert_eq!(
+            format_duration(Duration::from_secs(3930), false),
+            "1 hour 5 minutes 30 seconds"
+        );
+    }
+
+    #[test]
+    fn duration_at_unit_boundaries() {
+        assert_eq!(format_duration(Duration::from_secs(59), true), "59s");
+        assert_eq!(format_duration(Duration::from_secs(60), true), "1m");
+        assert_eq!(format_duration(Duration::from_secs(60), false), "1 minute");
+        assert_eq!(format_duration(Duration::from_secs(61), true), "1m 1s");
+        assert_eq!(format_duration(Duration::from_secs(3600), true), "1h");
+        assert_eq!(format_duration(Duration::from_secs(3660), true), "1h 1m");
+        assert_eq!(
+            format_duration_precise(Duration::from_millis(1), true),
+            "1ms"
+        );
+    }
+
+    #[test]
+    fn duration_precise() {
+        assert_eq!(format_duration_precise(Duration::ZERO, true), "0s");
+        assert_eq!(format_duration_precise(Duration::ZERO, false), "0 seconds");
+        assert_eq!(
+            format_duration_precise(Duration::from_nanos(50), true),
+            "50ns"
+        );
+        assert_eq!(
+            format_duration_precise(Duration::from_micros(1500), true),
+            "1ms"
+        );
+        assert_eq!(
+            format_duration_precise(Duration::from_millis(250), true),
+            "250ms"
+        );
+        assert_eq!(
+            format_duration_precise(Duration::from_millis(250), false),
+            "250 milliseconds"
+        );
+        assert_eq!(
+            format_duration_precise(Duration::from_millis(1500), true),
+            "1s"
+        );
+        assert_eq!(
+            format_duration_precise(Duration::from_secs(3930), true),
+            "1h 5m 30s"
+        );
+    }
+
+    #[test]
+    fn number() {
+        assert_eq!(format_number(0), "0");
+        assert_eq!(format_number(999), "999");
+        assert_eq!(format_number(1000), "1,000");
+        assert_eq!(format_number(1_234_567u64), "1,234,567");
+        assert_eq!(format_number(-1_234_567), "-1,234,567");
+        assert_eq!(format_number(u64::MAX), "18,446,744,073,709,551,615");
+    }
+
+    #[test]
+    fn percent() {
+        assert_eq!(format_percent(0, 0), "0%");
+        assert_eq!(format_percent(1, 3), "33.3%");
+        assert_eq!(format_percent(50, 100), "50%");
+        assert_eq!(format_percent(200, 100), "100%");
+    }
+}
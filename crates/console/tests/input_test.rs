@@ -0,0 +1,94 @@
+use futures::stream::{self, StreamExt};
+use iocraft::prelude::*;
+use starbase_console::ui::*;
+use std::time::Duration;
+
+async fn render_with_keys(keys: Vec<KeyCode>, history: &mut Vec<String>) -> String {
+    let mut value = String::new();
+
+    element! {
+        ContextProvider(value: Context::owned(ConsoleTheme::default())) {
+            Input(
+                label: "Command",
+                history: Some(history),
+                on_value: &mut value,
+            )
+        }
+    }
+    .mock_terminal_render_loop(MockTerminalConfig::with_events(
+        stream::iter(
+            keys.into_iter()
+                .chain([KeyCode::Enter])
+                .map(|code| TerminalEvent::Key(KeyEvent::new(KeyEventKind::Press, code))),
+        )
+        .then(|event| async {
+            // Emulate typing, so that each key is handled separately
+            tokio::time::sleep(Duration::from_millis(5)).await;
+            event
+        }),
+    ))
+    .collect::<Vec<_>>()
+    .await;
+
+    value
+}
+
+mod input_history {
+    use super::*;
+
+    fn create_history() -> Vec<String> {
+        vec!["first".into(), "second".into()]
+    }
+
+    #[tokio::test]
+    async fn up_recalls_previous_values() {
+        let mut history = create_history();
+
+        assert_eq!(
+            render_with_keys(vec![KeyCode::Up], &mut history).await,
+            "second"
+        );
+        assert_eq!(
+            render_with_keys(vec![KeyCode::Up, KeyCode::Up], &mut history).await,
+            "first"
+        );
+        // Stops at the oldest value
+        assert_eq!(
+            render_with_keys(vec![KeyCode::Up, KeyCode::Up, KeyCode::Up], &mut history).await,
+            "first"
+        );
+    }
+
+    #[tokio::test]
+    async fn down_restores_current_value() {
+        let mut history = create_history();
+
+        assert_eq!(
+            render_with_keys(
+                vec![KeyCode::Char('n'), KeyCode::Up, KeyCode::Up, KeyCode::Down],
+                &mut history
+            )
+            .await,
+            "second"
+        );
+        assert_eq!(
+            render_with_keys(
+                vec![KeyCode::Char('n'), KeyCode::Up, KeyCode::Down],
+                &mut history
+            )
+            .await,
+            "n"
+        );
+    }
+
+    #[tokio::test]
+    async fn appends_submitted_values() {
+        let mut history = create_history();
+
+        render_with_keys(vec![KeyCode::Char('a')], &mut history).await;
+        render_with_keys(vec![KeyCode::Up], &mut history).await;
+        render_with_keys(vec![], &mut history).await;
+
+        assert_eq!(history, vec!["first", "second", "a"]);
+    }
+}
@@ -0,0 +1,36 @@
+use iocraft::prelude::*;
+use starbase_console::ui::*;
+
+fn render_group(wrap: bool) -> String {
+    element! {
+        ContextProvider(value: Context::owned(ConsoleTheme {
+            supports_color: false,
+            ..Default::default()
+        })) {
+            // Constrain to emulate a narrow terminal
+            View(width: 20) {
+                Group(gap: 1, wrap) {
+                    #((1..=6).map(|index| element! {
+                        Text(content: format!("tag-{index}"))
+                    }))
+                }
+            }
+        }
+    }
+    .render(None)
+    .to_string()
+}
+
+mod group {
+    use super::*;
+
+    #[test]
+    fn wraps_items_onto_next_line() {
+        assert_eq!(render_group(true), "tag-1 tag-2 tag-3\ntag-4 tag-5 tag-6\n");
+    }
+
+    #[test]
+    fn shrinks_items_by_default() {
+        assert!(!render_group(false).contains("tag-4"));
+    }
+}
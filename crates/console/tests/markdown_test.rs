@@ -0,0 +1,85 @@
+use iocraft::prelude::*;
+use starbase_console::ui::*;
+use starbase_sandbox::assert_snapshot;
+
+fn render_with_width(element: AnyElement<'static>, width: u16) -> String {
+    let theme = ConsoleTheme {
+        supports_color: false,
+        ..Default::default()
+    };
+
+    element! {
+        ContextProvider(value: Context::owned(theme)) {
+            // Constrain to emulate the terminal width
+            View(width) {
+                #(element)
+            }
+        }
+    }
+    .render(None)
+    .to_string()
+}
+
+fn render(element: AnyElement<'static>) -> String {
+    render_with_width(element, 80)
+}
+
+const DOCUMENT: &str = r#"
+# Usage
+
+Run the **build** command to compile all projects, or pass a
+*specific* project with `--project <id>`.
+
+## Options
+
+- `--force` Skip the cache
+- `--jobs` Number of **parallel** jobs
+- Write results to <file>.build/output.json</file>
+
+```
+$ tool build --force
+$ tool build --project app
+```
+
+Unterminated * and ` markers render as-is.
+"#;
+
+#[test]
+fn renders_document() {
+    assert_snapshot!(render(
+        element! {
+            Markdown(content: DOCUMENT)
+        }
+        .into_any()
+    ));
+}
+
+#[test]
+fn renders_headings_and_lists() {
+    let output = render(
+        element! {
+            Markdown(content: "# Title\n\n- one\n- two")
+        }
+        .into_any(),
+    );
+
+    assert!(output.contains("Title"));
+    assert!(output.contains("one"));
+    assert!(output.contains("two"));
+}
+
+#[test]
+fn wraps_paragraphs_to_width() {
+    let output = render_with_width(
+        element! {
+            Markdown(content: "Run the **build** command to compile all projects, or pass a `--project`.")
+        }
+        .into_any(),
+        20,
+    );
+
+    assert_eq!(
+        output,
+        "Run the build\ncommand to compile\nall projects, or\npass a --project.\n"
+    );
+}
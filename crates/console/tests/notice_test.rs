@@ -0,0 +1,150 @@
+use iocraft::prelude::*;
+use starbase_console::ui::*;
+
+fn render(theme: ConsoleTheme, element: AnyElement<'static>) -> String {
+    element! {
+        ContextProvider(value: Context::owned(ConsoleTheme {
+            supports_color: false,
+            ..theme
+        })) {
+            #(element)
+        }
+    }
+    .render(Some(40))
+    .to_string()
+}
+
+fn render_variant(variant: Variant, supports_unicode: bool) -> String {
+    render(
+        ConsoleTheme {
+            supports_unicode,
+            ..Default::default()
+        },
+        element! {
+            Notice(variant) {
+                Text(content: "Content")
+            }
+        }
+        .into_any(),
+    )
+}
+
+fn first_line(output: &str) -> &str {
+    output.lines().find(|line| !line.is_empty()).unwrap()
+}
+
+mod notice {
+    use super::*;
+
+    #[test]
+    fn renders_icon_per_variant() {
+        assert_eq!(
+            first_line(&render_variant(Variant::Success, true)),
+            "│ ✔ SUCCESS"
+        );
+        assert_eq!(
+            first_line(&render_variant(Variant::Failure, true)),
+            "│ ✘ FAILURE"
+        );
+        assert_eq!(first_line(&render_variant(Variant::Info, true)), "│ ℹ INFO");
+        assert_eq!(
+            first_line(&render_variant(Variant::Caution, true)),
+            "│ ⚠ CAUTION"
+        );
+        assert_eq!(
+            first_line(&render_variant(Variant::Neutral, true)),
+            "│ Content"
+        );
+    }
+
+    #[test]
+    fn falls_back_to_ascii_icons() {
+        assert_eq!(
+            first_line(&render_variant(Variant::Success, false)),
+            "│ + SUCCESS"
+        );
+        assert_eq!(
+            first_line(&render_variant(Variant::Failure, false)),
+            "│ x FAILURE"
+        );
+        assert_eq!(
+            first_line(&render_variant(Variant::Info, false)),
+            "│ i INFO"
+        );
+        assert_eq!(
+            first_line(&render_variant(Variant::Caution, false)),
+            "│ ! CAUTION"
+        );
+    }
+
+    #[test]
+    fn can_hide_icon() {
+        let output = render(
+            ConsoleTheme::default(),
+            element! {
+                Notice(variant: Variant::Success, no_icon: true) {
+                    Text(content: "Content")
+                }
+            }
+            .into_any(),
+        );
+
+        assert_eq!(first_line(&output), "│ SUCCESS");
+    }
+
+    #[test]
+    fn wraps_content_at_max_width() {
+        let output = render(
+            ConsoleTheme::default(),
+            element! {
+                Notice(variant: Variant::Info, max_width: 12) {
+                    Text(content: "Some content that wraps")
+                }
+            }
+            .into_any(),
+        );
+
+        assert!(output
+            .lines()
+            .all(|line| line.trim_end().chars().count() <= 12));
+        assert!(output.lines().filter(|line| !line.is_empty()).count() > 2);
+    }
+}
+
+mod result_notice {
+    use super::*;
+
+    fn render_result(variant: Variant, message: &str) -> String {
+        render(
+            ConsoleTheme {
+                supports_unicode: false,
+                ..Default::default()
+            },
+            create_result_notice(variant, message).into_any(),
+        )
+    }
+
+    #[test]
+    fn renders_success() {
+        let output = render_result(Variant::Success, "Installed <id>node</id>");
+
+        assert_eq!(first_line(&output), "│ + SUCCESS");
+        assert!(output.contains("│ Installed node"));
+    }
+
+    #[test]
+    fn renders_error() {
+        let output = render_result(Variant::Failure, "Failed to install");
+
+        assert_eq!(first_line(&output), "│ x FAILURE");
+        assert!(output.contains("│ Failed to install"));
+    }
+
+    #[test]
+    fn renders_warning() {
+        let output = render_result(Variant::Caution, "Already installed");
+
+        assert_eq!(first_line(&output), "│ ! CAUTION");
+        assert!(output.contains("│ Already installed"));
+    }
+}
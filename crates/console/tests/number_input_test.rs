@@ -0,0 +1,98 @@
+use futures::stream::{self, StreamExt};
+use iocraft::prelude::*;
+use starbase_console::ui::*;
+use std::time::Duration;
+
+fn create_events(keys: &str) -> Vec<TerminalEvent> {
+    keys.chars()
+        .map(|ch| match ch {
+            '\n' => KeyCode::Enter,
+            '\x08' => KeyCode::Backspace,
+            _ => KeyCode::Char(ch),
+        })
+        .map(|code| TerminalEvent::Key(KeyEvent::new(KeyEventKind::Press, code)))
+        .collect()
+}
+
+async fn render_with_keys(
+    keys: &str,
+    allow_decimal: bool,
+    min: Option<f64>,
+    max: Option<f64>,
+) -> (f64, i64, Vec<String>) {
+    let mut value = 0.0;
+    let mut integer = 0;
+
+    let output = element! {
+        ContextProvider(value: Context::owned(ConsoleTheme::default())) {
+            NumberInput(
+                label: "Number",
+                allow_decimal,
+                min,
+                max,
+                on_value: &mut value,
+                on_integer: &mut integer,
+            )
+        }
+    }
+    .mock_terminal_render_loop(MockTerminalConfig::with_events(
+        stream::iter(create_events(keys)).then(|event| async {
+            // Emulate typing, so that each key is handled separately
+            tokio::time::sleep(Duration::from_millis(5)).await;
+            event
+        }),
+    ))
+    .map(|canvas| canvas.to_string())
+    .collect::<Vec<_>>()
+    .await;
+
+    (value, integer, output)
+}
+
+mod number_input {
+    use super::*;
+
+    #[tokio::test]
+    async fn accepts_valid_value() {
+        let (value, integer, _) = render_with_keys("-42\n", false, None, None).await;
+
+        assert_eq!(value, -42.0);
+        assert_eq!(integer, -42);
+    }
+
+    #[tokio::test]
+    async fn accepts_decimals_when_allowed() {
+        let (value, integer, _) = render_with_keys("1.5\n", true, None, None).await;
+
+        assert_eq!(value, 1.5);
+        assert_eq!(integer, 1);
+    }
+
+    #[tokio::test]
+    async fn rejects_non_numeric_keys() {
+        let (value, _, _) = render_with_keys("1a2b.3\n", false, None, None).await;
+
+        assert_eq!(value, 123.0);
+    }
+
+    #[tokio::test]
+    async fn rejects_out_of_range() {
+        let (value, _, output) =
+            render_with_keys("150\n\x08\x08\x0850\n", false, Some(1.0), Some(100.0)).await;
+
+        assert_eq!(value, 50.0);
+        assert!(output
+            .iter()
+            .any(|out| out.contains("Please enter a number between 1 and 100")));
+    }
+
+    #[tokio::test]
+    async fn requires_a_number() {
+        let (value, _, output) = render_with_keys("-\n\x081\n", false, None, None).await;
+
+        assert_eq!(value, 1.0);
+        assert!(output
+            .iter()
+            .any(|out| out.contains("Please enter a number")));
+    }
+}
@@ -0,0 +1,169 @@
+use futures::stream::StreamExt;
+use iocraft::prelude::*;
+use starbase_console::ui::*;
+use starbase_console::{Console, EmptyReporter};
+use std::time::Duration;
+
+mod progress {
+    use super::*;
+
+    #[tokio::test]
+    async fn renders_plain_text_without_ansi() {
+        let mut console = Console::<EmptyReporter>::new_testing();
+        console.set_plain(true);
+
+        let reporter = ProgressReporter::default();
+        let reporter_clone = reporter.clone();
+
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+
+            for value in 1..=10 {
+                reporter_clone
+                    .set_message("Downloading")
+                    .set_value(value * 10);
+            }
+
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            reporter_clone.exit();
+        });
+
+        let mut output = Vec::new();
+
+        console
+            .render_plain_loop(
+                element! {
+                    Progress(reporter)
+                },
+                &mut output,
+            )
+            .await
+            .unwrap();
+
+        let output = String::from_utf8(output).unwrap();
+
+        assert!(!output.contains('\x1b'));
+        assert!(output.lines().count() >= 1);
+        assert_eq!(output.lines().last().unwrap(), "100/100 Downloading");
+    }
+
+    #[tokio::test]
+    async fn increments_value() {
+        let console = Console::<EmptyReporter>::new_testing();
+        let reporter = ProgressReporter::default();
+        let reporter_clone = reporter.clone();
+
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+
+            reporter_clone.set_max(20);
+
+            for _ in 0..5 {
+                reporter_clone.inc(3);
+            }
+
+            reporter_clone.inc_by_one();
+
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            reporter_clone.exit();
+        });
+
+        let mut output = Vec::new();
+
+        console
+            .render_plain_loop(
+                element! {
+                    Progress(reporter)
+                },
+                &mut output,
+            )
+            .await
+            .unwrap();
+
+        let output = String::from_utf8(output).unwrap();
+
+        assert_eq!(output.lines().last().unwrap(), "16/20");
+    }
+
+    #[tokio::test]
+    async fn finish_renders_full_bar() {
+        let reporter = ProgressReporter::default();
+        let reporter_clone = reporter.clone();
+
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+
+            reporter_clone.set_value(25).finish();
+        });
+
+        let canvases = element! {
+            ContextProvider(value: Context::owned(ConsoleTheme::default())) {
+                Progress(reporter, bar_width: 10u32)
+            }
+        }
+        .mock_terminal_render_loop(MockTerminalConfig::default())
+        .map(|canvas| canvas.to_string())
+        .collect::<Vec<_>>()
+        .await;
+
+        assert_eq!(canvases.last().unwrap().trim_end(), "██████████");
+    }
+}
+
+mod validate_template {
+    use super::*;
+
+    #[test]
+    fn accepts_known_tokens() {
+        assert_eq!(validate_template(""), Ok(()));
+        assert_eq!(validate_template("Downloading"), Ok(()));
+        assert_eq!(
+            validate_template("{bytes}/{total_bytes} ({percent}%) {bytes_per_sec}, {eta} left"),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn ignores_non_token_braces() {
+        assert_eq!(validate_template("{} { value } {Value} {a-b} {"), Ok(()));
+    }
+
+    #[test]
+    fn lists_unknown_tokens() {
+        assert_eq!(
+            validate_template("{value} {precent} {speed} {precent}"),
+            Err(vec!["precent".to_owned(), "speed".to_owned()])
+        );
+    }
+}
+
+mod with_spinner {
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_future_output() {
+        let console = Console::<EmptyReporter>::new_testing();
+
+        let value = console
+            .with_spinner("Loading", async {
+                tokio::time::sleep(Duration::from_millis(50)).await;
+                123
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(value, 123);
+    }
+
+    #[tokio::test]
+    async fn returns_output_of_immediate_future() {
+        let console = Console::<EmptyReporter>::new_testing();
+
+        let value = console
+            .with_spinner("Loading", async { "done" })
+            .await
+            .unwrap();
+
+        assert_eq!(value, "done");
+    }
+}
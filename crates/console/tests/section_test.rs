@@ -0,0 +1,87 @@
+use iocraft::prelude::*;
+use starbase_console::ui::*;
+
+fn render(element: AnyElement<'static>) -> String {
+    let theme = ConsoleTheme {
+        supports_color: false,
+        ..Default::default()
+    };
+
+    element! {
+        ContextProvider(value: Context::owned(theme)) {
+            #(element)
+        }
+    }
+    .render(Some(80))
+    .to_string()
+}
+
+mod section {
+    use super::*;
+
+    #[test]
+    fn renders_children_when_expanded() {
+        let output = render(
+            element! {
+                Section(title: "Title") {
+                    Text(content: "One")
+                    Text(content: "Two")
+                }
+            }
+            .into_any(),
+        );
+
+        assert!(output.contains("One"));
+        assert!(output.contains("Two"));
+        assert!(!output.contains("hidden"));
+    }
+
+    #[test]
+    fn renders_summary_when_collapsed() {
+        let output = render(
+            element! {
+                Section(title: "Title", collapsed: true) {
+                    Text(content: "One")
+                    Text(content: "Two")
+                }
+            }
+            .into_any(),
+        );
+
+        assert!(!output.contains("One"));
+        assert!(!output.contains("Two"));
+        assert!(output.contains("2 items hidden"));
+    }
+
+    #[test]
+    fn renders_custom_summary_when_collapsed() {
+        let output = render(
+            element! {
+                Section(title: "Title", collapsed: true, summary: "Custom".to_owned()) {
+                    Text(content: "One")
+                }
+            }
+            .into_any(),
+        );
+
+        assert!(!output.contains("One"));
+        assert!(output.contains("Custom"));
+    }
+
+    #[test]
+    fn offsets_by_indent() {
+        let output = render(
+            element! {
+                Section(title: "Title", indent: 2usize) {
+                    Text(content: "One")
+                }
+            }
+            .into_any(),
+        );
+
+        assert!(output
+            .lines()
+            .any(|line| line.starts_with("    ") && line.contains("Title")));
+        assert!(output.lines().any(|line| line.starts_with("      One")));
+    }
+}
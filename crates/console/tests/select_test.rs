@@ -0,0 +1,294 @@
+use futures::stream::{self, StreamExt};
+use iocraft::prelude::*;
+use starbase_console::ui::*;
+
+fn create_options() -> Vec<SelectOption> {
+    (0..30)
+        .map(|index| {
+            let option = SelectOption::new(format!("option-{index}"));
+
+            if index == 0 || index == 29 || index == 20 {
+                option.disabled()
+            } else {
+                option
+            }
+        })
+        .collect()
+}
+
+async fn select_with_keys(keys: Vec<KeyCode>) -> usize {
+    let mut events = vec![];
+
+    for code in keys.into_iter().chain([KeyCode::Char(' '), KeyCode::Enter]) {
+        events.push(TerminalEvent::Key(KeyEvent::new(KeyEventKind::Press, code)));
+    }
+
+    let mut index = 0;
+
+    element! {
+        ContextProvider(value: Context::owned(ConsoleTheme::default())) {
+            Select(
+                label: "Pick",
+                options: create_options(),
+                on_index: &mut index,
+            )
+        }
+    }
+    .mock_terminal_render_loop(MockTerminalConfig::with_events(stream::iter(events)))
+    .collect::<Vec<_>>()
+    .await;
+
+    index
+}
+
+async fn select_multiple_with_keys(keys: Vec<KeyCode>) -> Vec<usize> {
+    select_multiple_with_limits(keys, None, None).await
+}
+
+async fn select_multiple_with_limits(
+    keys: Vec<KeyCode>,
+    min_selected: Option<usize>,
+    max_selected: Option<usize>,
+) -> Vec<usize> {
+    let mut events = vec![];
+
+    for code in keys.into_iter().chain([KeyCode::Enter]) {
+        events.push(TerminalEvent::Key(KeyEvent::new(KeyEventKind::Press, code)));
+    }
+
+    let mut indexes = vec![];
+
+    element! {
+        ContextProvider(value: Context::owned(ConsoleTheme::default())) {
+            Select(
+                label: "Pick",
+                options: create_options(),
+                multiple: true,
+                min_selected,
+                max_selected,
+                on_indexes: &mut indexes,
+            )
+        }
+    }
+    .mock_terminal_render_loop(MockTerminalConfig::with_events(stream::iter(events)))
+    .collect::<Vec<_>>()
+    .await;
+
+    indexes.sort();
+    indexes
+}
+
+mod select {
+    use super::*;
+
+    #[tokio::test]
+    async fn home_selects_first_enabled() {
+        assert_eq!(
+            select_with_keys(vec![KeyCode::Down, KeyCode::Down, KeyCode::Home]).await,
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn end_selects_last_enabled() {
+        assert_eq!(select_with_keys(vec![KeyCode::End]).await, 28);
+    }
+
+    #[tokio::test]
+    async fn page_down_moves_by_page() {
+        assert_eq!(select_with_keys(vec![KeyCode::PageDown]).await, 10);
+    }
+
+    #[tokio::test]
+    async fn page_down_skips_disabled() {
+        assert_eq!(
+            select_with_keys(vec![KeyCode::PageDown, KeyCode::PageDown]).await,
+            21
+        );
+    }
+
+    #[tokio::test]
+    async fn page_down_stops_at_last_enabled() {
+        assert_eq!(
+            select_with_keys(vec![
+                KeyCode::PageDown,
+                KeyCode::PageDown,
+                KeyCode::PageDown,
+                KeyCode::PageDown
+            ])
+            .await,
+            28
+        );
+    }
+
+    #[tokio::test]
+    async fn page_up_moves_by_page() {
+        assert_eq!(
+            select_with_keys(vec![KeyCode::End, KeyCode::PageUp]).await,
+            18
+        );
+    }
+
+    #[tokio::test]
+    async fn page_up_stops_at_first_enabled() {
+        assert_eq!(
+            select_with_keys(vec![KeyCode::PageDown, KeyCode::PageUp, KeyCode::PageUp]).await,
+            1
+        );
+    }
+}
+
+mod select_multiple {
+    use super::*;
+
+    #[tokio::test]
+    async fn selects_all_enabled() {
+        let indexes = select_multiple_with_keys(vec![KeyCode::Char('a')]).await;
+
+        assert_eq!(
+            indexes,
+            (0..30)
+                .filter(|index| ![0, 20, 29].contains(index))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[tokio::test]
+    async fn clears_all() {
+        assert_eq!(
+            select_multiple_with_keys(vec![
+                KeyCode::Char('a'),
+                KeyCode::Char('n'),
+                KeyCode::Down,
+                KeyCode::Down,
+                KeyCode::Char(' ')
+            ])
+            .await,
+            vec![2]
+        );
+    }
+
+    #[tokio::test]
+    async fn toggles_off_after_select_all() {
+        let indexes = select_multiple_with_keys(vec![
+            KeyCode::Down,
+            KeyCode::Char(' '),
+            KeyCode::Char('a'),
+            KeyCode::Char(' '),
+        ])
+        .await;
+
+        assert_eq!(indexes.len(), 26);
+        assert!(!indexes.contains(&1));
+    }
+}
+
+mod select_limits {
+    use super::*;
+
+    // Submission is rejected so the loop never exits, wait for the error instead
+    async fn renders_error(
+        keys: Vec<KeyCode>,
+        min_selected: Option<usize>,
+        max_selected: Option<usize>,
+        message: &str,
+    ) -> bool {
+        let events = keys
+            .into_iter()
+            .map(|code| TerminalEvent::Key(KeyEvent::new(KeyEventKind::Press, code)))
+            .collect::<Vec<_>>();
+
+        element! {
+            ContextProvider(value: Context::owned(ConsoleTheme::default())) {
+                Select(
+                    label: "Pick",
+                    options: create_options(),
+                    multiple: true,
+                    min_selected,
+                    max_selected,
+                )
+            }
+        }
+        .mock_terminal_render_loop(MockTerminalConfig::with_events(stream::iter(events)))
+        .any(|canvas| async move { canvas.to_string().contains(message) })
+        .await
+    }
+
+    #[tokio::test]
+    async fn errors_below_min() {
+        assert!(
+            renders_error(
+                vec![KeyCode::Down, KeyCode::Char(' '), KeyCode::Enter],
+                Some(2),
+                None,
+                "Please select at least 2 options"
+            )
+            .await
+        );
+    }
+
+    #[tokio::test]
+    async fn submits_once_min_is_reached() {
+        assert_eq!(
+            select_multiple_with_limits(
+                vec![
+                    KeyCode::Down,
+                    KeyCode::Char(' '),
+                    KeyCode::Enter,
+                    KeyCode::Down,
+                    KeyCode::Char(' ')
+                ],
+                Some(2),
+                None
+            )
+            .await,
+            vec![1, 2]
+        );
+    }
+
+    #[tokio::test]
+    async fn doesnt_toggle_above_max() {
+        assert_eq!(
+            select_multiple_with_limits(
+                vec![
+                    KeyCode::Down,
+                    KeyCode::Char(' '),
+                    KeyCode::Down,
+                    KeyCode::Char(' '),
+                    KeyCode::Down,
+                    KeyCode::Char(' ')
+                ],
+                None,
+                Some(2)
+            )
+            .await,
+            vec![1, 2]
+        );
+    }
+
+    #[tokio::test]
+    async fn errors_when_toggling_above_max() {
+        assert!(
+            renders_error(
+                vec![
+                    KeyCode::Down,
+                    KeyCode::Char(' '),
+                    KeyCode::Down,
+                    KeyCode::Char(' ')
+                ],
+                None,
+                Some(1),
+                "Please select at most 1 option"
+            )
+            .await
+        );
+    }
+
+    #[tokio::test]
+    async fn select_all_respects_max() {
+        assert_eq!(
+            select_multiple_with_limits(vec![KeyCode::Char('a')], None, Some(3)).await,
+            vec![1, 2, 3]
+        );
+    }
+}
@@ -0,0 +1,183 @@
+use iocraft::prelude::*;
+use starbase_console::ui::*;
+
+fn render(element: AnyElement<'static>) -> String {
+    let theme = ConsoleTheme {
+        supports_color: false,
+        ..Default::default()
+    };
+
+    element! {
+        ContextProvider(value: Context::owned(theme)) {
+            #(element)
+        }
+    }
+    .render(Some(120))
+    .to_string()
+}
+
+fn create_table(max_width: Option<u32>) -> AnyElement<'static> {
+    element! {
+        Table(
+            headers: vec![
+                TableHeader::new("Name", Size::Length(10)),
+                TableHeader::new("Description", Size::Auto),
+                TableHeader::new("Notes", Size::Auto),
+            ],
+            max_width,
+        ) {
+            TableRow(row: 0) {
+                TableCol(col: 0) {
+                    Text(content: "starbase")
+                }
+                TableCol(col: 1) {
+                    Text(content: "Framework for building performant command line applications and developer tools.")
+                }
+                TableCol(col: 2) {
+                    Text(content: "Includes console, styles, utils, and more.")
+                }
+            }
+        }
+    }
+    .into_any()
+}
+
+fn max_line_width(output: &str) -> usize {
+    output
+        .lines()
+        .map(|line| line.trim_end().chars().count())
+        .max()
+        .unwrap_or_default()
+}
+
+mod table {
+    use super::*;
+
+    #[test]
+    fn overflows_without_fit() {
+        let output = render(create_table(None));
+
+        assert!(max_line_width(&output) > 60);
+    }
+
+    #[test]
+    fn fits_within_max_width() {
+        let output = render(create_table(Some(60)));
+
+        assert!(max_line_width(&output) <= 60);
+        assert!(output.contains("starbase"));
+        assert!(output.contains("Framework"));
+        assert!(output.contains("tools."));
+    }
+
+    #[test]
+    fn fits_within_narrow_width() {
+        let output = render(create_table(Some(40)));
+
+        assert!(max_line_width(&output) <= 40);
+    }
+
+    #[test]
+    fn fits_within_terminal_width() {
+        let output = render(
+            element! {
+                Table(
+                    headers: vec![
+                        TableHeader::new("Name", Size::Length(10)),
+                        TableHeader::new("Description", Size::Auto),
+                    ],
+                    auto_fit: true,
+                ) {
+                    TableRow(row: 0) {
+                        TableCol(col: 0) {
+                            Text(content: "starbase")
+                        }
+                        TableCol(col: 1) {
+                            Text(content: "word ".repeat(50))
+                        }
+                    }
+                }
+            }
+            .into_any(),
+        );
+
+        // Uses the same source as the `use_terminal_size` hook
+        match crossterm::terminal::size() {
+            Ok((width, _)) if width > 0 => {
+                assert!(max_line_width(&output) <= width as usize);
+            }
+            _ => {
+                assert!(max_line_width(&output) > 120);
+            }
+        };
+    }
+
+    #[test]
+    fn keeps_fixed_columns_that_fit() {
+        let output = render(
+            element! {
+                Table(
+                    headers: vec![
+                        TableHeader::new("A", Size::Length(10)),
+                        TableHeader::new("B", Size::Length(10)),
+                    ],
+                    max_width: 60,
+                ) {
+                    TableRow(row: 0) {
+                        TableCol(col: 0) {
+                            Text(content: "a")
+                        }
+                        TableCol(col: 1) {
+                            Text(content: "b")
+                        }
+                    }
+                }
+            }
+            .into_any(),
+        );
+
+        // 2 borders + 10 + 2 gap + 10
+        assert_eq!(max_line_width(&output), 24);
+    }
+
+    #[test]
+    fn shrinks_fixed_columns_that_overflow() {
+        let output = render(
+            element! {
+                Table(
+                    headers: vec![
+                        TableHeader::new("A", Size::Length(30)),
+                        TableHeader::new("B", Size::Length(30)),
+                        TableHeader::new("C", Size::Auto),
+                    ],
+                    max_width: 40,
+                ) {
+                    TableRow(row: 0) {
+                        TableCol(col: 0) {
+                            Text(content: "a ".repeat(20))
+                        }
+                        TableCol(col: 1) {
+                            Text(content: "b ".repeat(20))
+                        }
+                        TableCol(col: 2) {
+                            Text(content: "c")
+                        }
+                    }
+                }
+            }
+            .into_any(),
+        );
+
+        assert!(max_line_width(&output) <= 40);
+        assert!(output.contains('c'));
+
+        // Content must not overflow the columns into the bottom border
+        assert!(output
+            .trim_end()
+            .lines()
+            .last()
+            .unwrap()
+            .chars()
+            .all(|ch| matches!(ch, '╰' | '─' | '╯')));
+    }
+}
@@ -0,0 +1,67 @@
+use starbase_console::utils::text::truncate_display;
+
+mod truncate_display {
+    use super::*;
+
+    #[test]
+    fn returns_as_is_when_fits() {
+        assert_eq!(truncate_display("hello", 5, "…"), "hello");
+        assert_eq!(truncate_display("hello", 10, "…"), "hello");
+        assert_eq!(truncate_display("", 0, "…"), "");
+    }
+
+    #[test]
+    fn truncates_plain() {
+        assert_eq!(truncate_display("hello world", 8, "…"), "hello w…");
+        assert_eq!(truncate_display("hello world", 8, "..."), "hello...");
+        assert_eq!(truncate_display("hello world", 5, ""), "hello");
+    }
+
+    #[test]
+    fn truncates_to_ellipsis_only() {
+        assert_eq!(truncate_display("hello", 1, "…"), "…");
+        assert_eq!(truncate_display("hello", 3, "..."), "...");
+    }
+
+    #[test]
+    fn truncates_ellipsis_wider_than_width() {
+        assert_eq!(truncate_display("hello", 2, "..."), "..");
+        assert_eq!(truncate_display("hello", 0, "…"), "");
+        assert_eq!(truncate_display("hello", 1, "……"), "…");
+    }
+
+    #[test]
+    fn truncates_cjk() {
+        // Each character is 2 columns wide
+        assert_eq!(truncate_display("日本語テキスト", 7, "…"), "日本語…");
+        // A wide character that doesn't fit is dropped
+        assert_eq!(truncate_display("日本語テキスト", 6, "…"), "日本…");
+    }
+
+    #[test]
+    fn truncates_ansi_without_cutting_escapes() {
+        let value = "\x1b[38;5;111mhello world\x1b[0m";
+
+        assert_eq!(
+            truncate_display(value, 8, "…"),
+            "\x1b[38;5;111mhello w…\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn ignores_ansi_when_measuring() {
+        let value = "\x1b[1mbold\x1b[0m";
+
+        assert_eq!(truncate_display(value, 4, "…"), value);
+    }
+
+    #[test]
+    fn preserves_escapes_after_truncation() {
+        let value = "\x1b[31mred\x1b[0m \x1b[32mgreen\x1b[0m";
+
+        assert_eq!(
+            truncate_display(value, 4, "…"),
+            "\x1b[31mred\x1b[0m…\x1b[32m\x1b[0m"
+        );
+    }
+}
@@ -1,11 +1,14 @@
+use crate::emitter_error::*;
 use crate::event::*;
 use crate::subscriber::*;
+use crate::wildcard::*;
 use std::collections::HashSet;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 
 pub struct Emitter<E: Event> {
     subscribers: Arc<RwLock<Vec<BoxedSubscriber<E>>>>,
+    wildcards: Option<WildcardSubscribers>,
 }
 
 #[allow(clippy::new_without_default, clippy::len_without_is_empty)]
@@ -14,6 +17,17 @@ impl<E: Event + 'static> Emitter<E> {
     pub fn new() -> Self {
         Emitter {
             subscribers: Arc::new(RwLock::new(Vec::new())),
+            wildcards: None,
+        }
+    }
+
+    /// Create a new event emitter that will also call the provided wildcard
+    /// subscribers for every event emitted. The registry can be shared
+    /// across emitters of different event types.
+    pub fn new_with_wildcards(wildcards: WildcardSubscribers) -> Self {
+        Emitter {
+            subscribers: Arc::new(RwLock::new(Vec::new())),
+            wildcards: Some(wildcards),
         }
     }
 
@@ -46,13 +60,70 @@ impl<E: Event + 'static> Emitter<E> {
     ///
     /// If a subscriber returns [`EventState::Stop`], no further subscribers will be called.
     /// If a subscriber returns [`EventState::Continue`], the next subscriber will be called.
+    /// If a subscriber fails, no further subscribers will be called, but wildcard
+    /// "after" subscribers are still called before the error is returned.
     pub async fn emit(&self, event: E) -> miette::Result<E::Data> {
         let mut remove_indices = HashSet::new();
+        let mut failure = None;
+        let mut subscribers = self.subscribers.write().await;
+
+        let event = Arc::new(event);
+        let data = Arc::new(RwLock::new(E::Data::default()));
+
+        if let Some(wildcards) = &self.wildcards {
+            wildcards.call_before(event.as_ref());
+        }
+
+        for (index, subscriber) in subscribers.iter_mut().enumerate() {
+            let event = Arc::clone(&event);
+            let data = Arc::clone(&data);
+
+            if subscriber.is_once() {
+                remove_indices.insert(index);
+            }
+
+            match subscriber.on_emit(event, data).await {
+                Ok(EventState::Continue) => continue,
+                Ok(EventState::Stop) => break,
+                Err(error) => {
+                    failure = Some(error);
+                    break;
+                }
+            };
+        }
+
+        remove_once_subscribers(&mut subscribers, &remove_indices);
+
+        if let Some(wildcards) = &self.wildcards {
+            wildcards.call_after(event.as_ref());
+        }
+
+        if let Some(error) = failure {
+            return Err(error);
+        }
+
+        Ok(Arc::into_inner(data).unwrap().into_inner())
+    }
+
+    /// Emit the provided event to all registered subscribers, similar to
+    /// [`Emitter#emit`](#method.emit), but a subscriber failure will not stop
+    /// other subscribers from being called. Instead, all failures are collected
+    /// and returned as an [`EmitCollectedError`].
+    ///
+    /// This is useful for notification-style events, where one failed subscriber
+    /// shouldn't block the rest.
+    pub async fn emit_collecting(&self, event: E) -> miette::Result<E::Data> {
+        let mut remove_indices = HashSet::new();
+        let mut failures = vec![];
         let mut subscribers = self.subscribers.write().await;
 
         let event = Arc::new(event);
         let data = Arc::new(RwLock::new(E::Data::default()));
 
+        if let Some(wildcards) = &self.wildcards {
+            wildcards.call_before(event.as_ref());
+        }
+
         for (index, subscriber) in subscribers.iter_mut().enumerate() {
             let event = Arc::clone(&event);
             let data = Arc::clone(&data);
@@ -61,21 +132,47 @@ impl<E: Event + 'static> Emitter<E> {
                 remove_indices.insert(index);
             }
 
-            match subscriber.on_emit(event, data).await? {
-                EventState::Continue => continue,
-                EventState::Stop => break,
+            match subscriber.on_emit(event, data).await {
+                Ok(EventState::Continue) => continue,
+                Ok(EventState::Stop) => break,
+                Err(error) => {
+                    failures.push(SubscriberFailure {
+                        index,
+                        name: subscriber.name(),
+                        error,
+                    });
+                }
             };
         }
 
-        // Remove only once subscribers that were called
-        let mut i = 0;
+        remove_once_subscribers(&mut subscribers, &remove_indices);
+
+        if let Some(wildcards) = &self.wildcards {
+            wildcards.call_after(event.as_ref());
+        }
 
-        subscribers.retain(|_| {
-            let remove = remove_indices.contains(&i);
-            i += 1;
-            !remove
-        });
+        if !failures.is_empty() {
+            return Err(EmitCollectedError {
+                event: E::name(),
+                failures,
+            }
+            .into());
+        }
 
         Ok(Arc::into_inner(data).unwrap().into_inner())
     }
 }
+
+// Remove only once subscribers that were called
+fn remove_once_subscribers<E: Event>(
+    subscribers: &mut Vec<BoxedSubscriber<E>>,
+    remove_indices: &HashSet<usize>,
+) {
+    let mut i = 0;
+
+    subscribers.retain(|_| {
+        let remove = remove_indices.contains(&i);
+        i += 1;
+        !remove
+    });
+}
@@ -0,0 +1,30 @@
+use miette::Diagnostic;
+use thiserror::Error;
+
+/// A failure returned from a single subscriber.
+#[derive(Debug)]
+pub struct SubscriberFailure {
+    /// Index of the subscriber, in registration order.
+    pub index: usize,
+    /// Name of the subscriber, typically its type or function path.
+    pub name: &'static str,
+    pub error: miette::Report,
+}
+
+/// An aggregated error of all subscribers that failed while emitting
+/// an event with [`Emitter::emit_collecting`](crate::Emitter::emit_collecting).
+#[derive(Debug, Diagnostic, Error)]
+#[diagnostic(code(events::emit_failed))]
+#[error(
+    "{} subscriber(s) failed while emitting {event}:\n{}",
+    .failures.len(),
+    .failures
+        .iter()
+        .map(|failure| format!("  #{} {}: {}", failure.index, failure.name, failure.error))
+        .collect::<Vec<_>>()
+        .join("\n"),
+)]
+pub struct EmitCollectedError {
+    pub event: &'static str,
+    pub failures: Vec<SubscriberFailure>,
+}
@@ -1,5 +1,21 @@
 pub trait Event: Send + Sync {
+    /// The value threaded through all subscribers, and returned from the emitter.
+    /// Can be set with `#[event(value = Type)]` (or `dataset`) when deriving.
+    ///
+    /// Subscribers receive a write lock to the value, and should modify it in place
+    /// (for example, push to a list) instead of replacing it, so that contributions
+    /// from previous subscribers are preserved.
     type Data: Send + Sync + Default;
+
+    /// Return a stable name that identifies the event, used for logging, recording,
+    /// and errors. Defaults to the type name, but can be customized with
+    /// `#[event(name = "...")]` when deriving.
+    fn name() -> &'static str
+    where
+        Self: Sized,
+    {
+        std::any::type_name::<Self>()
+    }
 }
 
 pub enum EventState {
@@ -1,8 +1,14 @@
 mod emitter;
+mod emitter_error;
 mod event;
+mod recorder;
 mod subscriber;
+mod wildcard;
 
 pub use emitter::*;
+pub use emitter_error::*;
 pub use event::*;
+pub use recorder::*;
 pub use starbase_macros::{subscriber, Event};
 pub use subscriber::*;
+pub use wildcard::*;
@@ -0,0 +1,72 @@
+use crate::emitter::*;
+use crate::event::*;
+use std::ops::Deref;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+/// An [`Emitter`] wrapper that records every event emitted through it, so that
+/// they can be inspected or replayed into another emitter. This is useful for
+/// debugging and deterministic tests.
+pub struct RecordingEmitter<E: Event + Clone> {
+    emitter: Emitter<E>,
+    events: Arc<RwLock<Vec<E>>>,
+}
+
+#[allow(clippy::new_without_default)]
+impl<E: Event + Clone + 'static> RecordingEmitter<E> {
+    /// Create a new recording emitter.
+    pub fn new() -> Self {
+        Self::wrap(Emitter::new())
+    }
+
+    /// Wrap an existing emitter to record its events.
+    pub fn wrap(emitter: Emitter<E>) -> Self {
+        Self {
+            emitter,
+            events: Arc::new(RwLock::new(Vec::new())),
+        }
+    }
+
+    /// Record the provided event, and then emit it to all registered subscribers.
+    pub async fn emit(&self, event: E) -> miette::Result<E::Data> {
+        self.events.write().await.push(event.clone());
+        self.emitter.emit(event).await
+    }
+
+    /// Record the provided event, and then emit it to all registered subscribers,
+    /// collecting failures. See [`Emitter#emit_collecting`](Emitter::emit_collecting).
+    pub async fn emit_collecting(&self, event: E) -> miette::Result<E::Data> {
+        self.events.write().await.push(event.clone());
+        self.emitter.emit_collecting(event).await
+    }
+
+    /// Return a copy of all recorded events, in the order they were emitted.
+    pub async fn events(&self) -> Vec<E> {
+        self.events.read().await.clone()
+    }
+
+    /// Remove all recorded events.
+    pub async fn clear(&self) {
+        self.events.write().await.clear();
+    }
+
+    /// Re-emit all recorded events, in order, into the provided emitter,
+    /// and return the data of each event. If an event fails, replaying stops.
+    pub async fn replay(&self, into: &Emitter<E>) -> miette::Result<Vec<E::Data>> {
+        let mut results = vec![];
+
+        for event in self.events().await {
+            results.push(into.emit(event).await?);
+        }
+
+        Ok(results)
+    }
+}
+
+impl<E: Event + Clone> Deref for RecordingEmitter<E> {
+    type Target = Emitter<E>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.emitter
+    }
+}
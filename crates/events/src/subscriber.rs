@@ -6,6 +6,11 @@ use tokio::sync::RwLock;
 
 #[async_trait]
 pub trait Subscriber<E: Event>: Send + Sync {
+    /// Name of the subscriber, used to identify it in errors.
+    fn name(&self) -> &'static str {
+        std::any::type_name::<Self>()
+    }
+
     fn is_once(&self) -> bool;
     async fn on_emit(&mut self, event: Arc<E>, data: Arc<RwLock<E::Data>>) -> EventResult;
 }
@@ -30,6 +35,7 @@ where
 
 pub struct CallbackSubscriber<E: Event> {
     func: Box<dyn SubscriberFunc<E>>,
+    name: &'static str,
     once: bool,
 }
 
@@ -37,6 +43,7 @@ impl<E: Event> CallbackSubscriber<E> {
     pub fn new<F: SubscriberFunc<E> + 'static>(func: F, once: bool) -> Self {
         CallbackSubscriber {
             func: Box::new(func),
+            name: std::any::type_name::<F>(),
             once,
         }
     }
@@ -44,6 +51,10 @@ impl<E: Event> CallbackSubscriber<E> {
 
 #[async_trait]
 impl<E: Event> Subscriber<E> for CallbackSubscriber<E> {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
     fn is_once(&self) -> bool {
         self.once
     }
@@ -0,0 +1,75 @@
+use crate::event::Event;
+use std::any::Any;
+use std::sync::{Arc, RwLock};
+
+/// A type-erased event, passed to wildcard subscribers.
+pub struct AnyEvent<'a> {
+    /// Name of the event, as returned by [`Event::name`].
+    pub name: &'static str,
+    pub event: &'a dyn Any,
+}
+
+impl AnyEvent<'_> {
+    /// Attempt to downcast the event to a concrete type.
+    pub fn downcast_ref<T: 'static>(&self) -> Option<&T> {
+        self.event.downcast_ref::<T>()
+    }
+
+    /// Return true if the event is of the provided type.
+    pub fn is<T: 'static>(&self) -> bool {
+        self.event.is::<T>()
+    }
+}
+
+pub type WildcardFunc = Arc<dyn Fn(&AnyEvent) + Send + Sync>;
+
+/// A registry of wildcard subscribers, that are called for every event emitted,
+/// regardless of type, by all emitters that the registry has been attached to.
+/// This is useful for logging, auditing, and debugging the event bus.
+#[derive(Clone, Default)]
+pub struct WildcardSubscribers {
+    before: Arc<RwLock<Vec<WildcardFunc>>>,
+    after: Arc<RwLock<Vec<WildcardFunc>>>,
+}
+
+impl WildcardSubscribers {
+    /// Create a new and empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a subscriber function that will be called before
+    /// the typed subscribers of an emitter.
+    pub fn on_before<F: Fn(&AnyEvent) + Send + Sync + 'static>(&self, callback: F) -> &Self {
+        self.before.write().unwrap().push(Arc::new(callback));
+        self
+    }
+
+    /// Register a subscriber function that will be called after
+    /// the typed subscribers of an emitter.
+    pub fn on_after<F: Fn(&AnyEvent) + Send + Sync + 'static>(&self, callback: F) -> &Self {
+        self.after.write().unwrap().push(Arc::new(callback));
+        self
+    }
+
+    pub(crate) fn call_before<E: Event + 'static>(&self, event: &E) {
+        call_all(&self.before, event);
+    }
+
+    pub(crate) fn call_after<E: Event + 'static>(&self, event: &E) {
+        call_all(&self.after, event);
+    }
+}
+
+fn call_all<E: Event + 'static>(subscribers: &RwLock<Vec<WildcardFunc>>, event: &E) {
+    // Clone so that subscribers may register other subscribers
+    let subscribers = subscribers.read().unwrap().clone();
+    let event = AnyEvent {
+        name: E::name(),
+        event,
+    };
+
+    for subscriber in subscribers {
+        subscriber(&event);
+    }
+}
@@ -1,7 +1,7 @@
 #![allow(dead_code, unused_must_use)]
 
 use miette::Diagnostic;
-use starbase_events::{EventResult, EventState};
+use starbase_events::{Event, EventResult, EventState};
 use starbase_macros::*;
 use std::{path::PathBuf, sync::Arc};
 use thiserror::Error;
@@ -29,6 +29,14 @@ struct PathEvent(pub PathBuf);
 #[event(dataset = std::path::PathBuf)]
 struct FQPathEvent(pub PathBuf);
 
+#[derive(Event)]
+#[event(name = "build.started")]
+struct NamedEvent;
+
+#[derive(Event)]
+#[event(dataset = i32, name = "build.finished")]
+struct NamedIntEvent(pub i32);
+
 async fn callback_func(_event: Arc<IntEvent>, data: Arc<RwLock<i32>>) -> EventResult {
     let mut data = data.write().await;
     *data += 5;
@@ -66,3 +74,49 @@ async fn no_return(data: &mut IntEvent) -> EventResult {
 async fn err_return(_data: IntEvent) -> EventResult {
     Err(TestError::Test.into())
 }
+
+#[test]
+fn uses_type_name_by_default() {
+    assert_eq!(IntEvent::name(), "event_macros_test::IntEvent");
+}
+
+#[test]
+fn uses_custom_name() {
+    assert_eq!(NamedEvent::name(), "build.started");
+    assert_eq!(NamedIntEvent::name(), "build.finished");
+}
+
+#[derive(Event)]
+#[event(value = Vec<String>)]
+struct ListEvent;
+
+#[derive(Event)]
+#[event(value = std::collections::HashMap<String, usize>)]
+struct MapEvent;
+
+#[derive(Event)]
+#[event]
+struct BareEvent;
+
+#[derive(PartialEq)]
+enum Kind {
+    Build,
+    Test,
+}
+
+#[derive(Event)]
+#[event(dataset = i32)]
+struct KindEvent {
+    pub kind: Kind,
+}
+
+#[subscriber(when = "event.kind == Kind::Build")]
+async fn callback_when_string(data: &mut KindEvent) -> EventResult {
+    *data += 1;
+}
+
+#[subscriber(when = event.kind == Kind::Test)]
+async fn callback_when_expr(data: &mut KindEvent) {
+    *data += 10;
+    Ok(EventState::Continue)
+}
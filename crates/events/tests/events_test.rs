@@ -1,7 +1,10 @@
 #![allow(dead_code)]
 
 use async_trait::async_trait;
-use starbase_events::{Emitter, EventResult, EventState, Subscriber};
+use starbase_events::{
+    EmitCollectedError, Emitter, EventResult, EventState, RecordingEmitter, Subscriber,
+    WildcardSubscribers,
+};
 use starbase_macros::*;
 use std::sync::Arc;
 use tokio::sync::RwLock;
@@ -206,6 +209,299 @@ async fn preserves_onces_that_didnt_run() {
     assert_eq!(emitter.len().await, 3);
 }
 
+#[derive(Debug)]
+struct TestFailingSubscriber;
+
+#[async_trait]
+impl Subscriber<TestEvent> for TestFailingSubscriber {
+    fn is_once(&self) -> bool {
+        false
+    }
+
+    async fn on_emit(&mut self, _event: Arc<TestEvent>, _data: Arc<RwLock<i32>>) -> EventResult {
+        Err(miette::miette!("subscriber failed"))
+    }
+}
+
+#[subscriber]
+async fn callback_failing(_data: &mut TestEvent) -> EventResult {
+    Err(miette::miette!("callback failed"))
+}
+
+#[tokio::test]
+async fn emit_stops_on_failure() {
+    let emitter = Emitter::<TestEvent>::new();
+    emitter.subscribe(TestFailingSubscriber).await;
+    emitter.subscribe(TestSubscriber { inc: 1 }).await;
+
+    assert!(emitter.emit(TestEvent(0)).await.is_err());
+}
+
+#[tokio::test]
+async fn emit_collecting_runs_all_subscribers() {
+    let emitter = Emitter::<TestEvent>::new();
+    let counter = Arc::new(RwLock::new(0));
+    let counter_clone = Arc::clone(&counter);
+
+    emitter.subscribe(TestFailingSubscriber).await;
+    emitter
+        .on(move |_event: Arc<TestEvent>, _data: Arc<RwLock<i32>>| {
+            let counter = Arc::clone(&counter_clone);
+
+            async move {
+                *counter.write().await += 1;
+                Ok(EventState::Continue)
+            }
+        })
+        .await;
+    emitter.on(callback_failing).await;
+
+    let error = emitter.emit_collecting(TestEvent(0)).await.unwrap_err();
+    let error = error.downcast_ref::<EmitCollectedError>().unwrap();
+
+    assert_eq!(*counter.read().await, 1);
+    assert_eq!(error.failures.len(), 2);
+    assert_eq!(error.failures[0].index, 0);
+    assert!(error.failures[0].name.ends_with("TestFailingSubscriber"));
+    assert_eq!(error.failures[0].error.to_string(), "subscriber failed");
+    assert_eq!(error.failures[1].index, 2);
+    assert!(error.failures[1].name.contains("callback_failing"));
+    assert_eq!(error.failures[1].error.to_string(), "callback failed");
+    assert!(error.to_string().starts_with("2 subscriber(s) failed"));
+}
+
+#[tokio::test]
+async fn emit_collecting_returns_data_when_no_failures() {
+    let emitter = Emitter::<TestEvent>::new();
+    emitter.subscribe(TestSubscriber { inc: 1 }).await;
+    emitter.subscribe(TestSubscriber { inc: 2 }).await;
+
+    let data = emitter.emit_collecting(TestEvent(0)).await.unwrap();
+
+    assert_eq!(data, 3);
+}
+
+#[derive(Event)]
+#[event(dataset = String)]
+struct TestOtherEvent(pub String);
+
+#[tokio::test]
+async fn wildcards_observe_all_event_types() {
+    let wildcards = WildcardSubscribers::new();
+    let log = Arc::new(std::sync::Mutex::new(vec![]));
+    let before_log = Arc::clone(&log);
+    let after_log = Arc::clone(&log);
+
+    wildcards.on_before(move |event| {
+        if let Some(event) = event.downcast_ref::<TestEvent>() {
+            before_log
+                .lock()
+                .unwrap()
+                .push(format!("before test {}", event.0));
+        } else if let Some(event) = event.downcast_ref::<TestOtherEvent>() {
+            before_log
+                .lock()
+                .unwrap()
+                .push(format!("before other {}", event.0));
+        }
+    });
+    wildcards.on_after(move |event| {
+        after_log
+            .lock()
+            .unwrap()
+            .push(format!("after {}", event.name.rsplit("::").next().unwrap()));
+    });
+
+    let emitter = Emitter::<TestEvent>::new_with_wildcards(wildcards.clone());
+    emitter.subscribe(TestSubscriber { inc: 1 }).await;
+
+    let other_emitter = Emitter::<TestOtherEvent>::new_with_wildcards(wildcards);
+
+    assert_eq!(emitter.emit(TestEvent(5)).await.unwrap(), 1);
+
+    other_emitter
+        .emit(TestOtherEvent("foo".into()))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        *log.lock().unwrap(),
+        [
+            "before test 5",
+            "after TestEvent",
+            "before other foo",
+            "after TestOtherEvent"
+        ]
+    );
+}
+
+#[tokio::test]
+async fn wildcards_observe_failed_events() {
+    let wildcards = WildcardSubscribers::new();
+    let log = Arc::new(std::sync::Mutex::new(vec![]));
+    let after_log = Arc::clone(&log);
+
+    wildcards.on_after(move |event| {
+        after_log.lock().unwrap().push(event.name);
+    });
+
+    let emitter = Emitter::<TestEvent>::new_with_wildcards(wildcards);
+    emitter.subscribe(TestFailingSubscriber).await;
+
+    assert!(emitter.emit(TestEvent(0)).await.is_err());
+    assert!(emitter.emit_collecting(TestEvent(0)).await.is_err());
+    assert_eq!(log.lock().unwrap().len(), 2);
+}
+
+#[derive(Clone, Event)]
+#[event(dataset = i32)]
+struct TestCloneEvent(pub i32);
+
+#[subscriber]
+async fn callback_clone(data: &mut TestCloneEvent) -> EventResult {
+    *data += event.0;
+    Ok(EventState::Continue)
+}
+
+#[tokio::test]
+async fn records_and_replays_events() {
+    let recorder = RecordingEmitter::<TestCloneEvent>::new();
+    recorder.on(callback_clone).await;
+
+    assert_eq!(recorder.emit(TestCloneEvent(1)).await.unwrap(), 1);
+    assert_eq!(recorder.emit(TestCloneEvent(2)).await.unwrap(), 2);
+    assert_eq!(recorder.emit(TestCloneEvent(3)).await.unwrap(), 3);
+
+    assert_eq!(
+        recorder
+            .events()
+            .await
+            .into_iter()
+            .map(|event| event.0)
+            .collect::<Vec<_>>(),
+        [1, 2, 3]
+    );
+
+    let counter = Arc::new(RwLock::new(vec![]));
+    let counter_clone = Arc::clone(&counter);
+    let emitter = Emitter::<TestCloneEvent>::new();
+
+    emitter
+        .on(move |event: Arc<TestCloneEvent>, _data: Arc<RwLock<i32>>| {
+            let counter = Arc::clone(&counter_clone);
+
+            async move {
+                counter.write().await.push(event.0);
+                Ok(EventState::Continue)
+            }
+        })
+        .await;
+    emitter.on(callback_clone).await;
+
+    assert_eq!(recorder.replay(&emitter).await.unwrap(), [1, 2, 3]);
+    assert_eq!(*counter.read().await, [1, 2, 3]);
+
+    recorder.clear().await;
+
+    assert!(recorder.events().await.is_empty());
+}
+
+#[tokio::test]
+async fn records_collected_events() {
+    let recorder = RecordingEmitter::<TestCloneEvent>::new();
+    recorder.on(callback_clone).await;
+
+    assert_eq!(
+        recorder.emit_collecting(TestCloneEvent(1)).await.unwrap(),
+        1
+    );
+    assert_eq!(recorder.events().await.len(), 1);
+}
+
+#[derive(Event)]
+#[event(value = Vec<String>)]
+struct TestListEvent(pub String);
+
+#[subscriber]
+async fn callback_list_one(data: &mut TestListEvent) -> EventResult {
+    data.push(format!("{}-one", event.0));
+    Ok(EventState::Continue)
+}
+
+#[subscriber]
+async fn callback_list_two(data: &mut TestListEvent) -> EventResult {
+    data.push(format!("{}-two", event.0));
+    Ok(EventState::Continue)
+}
+
+#[tokio::test]
+async fn accumulates_value_across_subscribers() {
+    let emitter = Emitter::<TestListEvent>::new();
+    emitter.on(callback_list_one).await;
+    emitter.on(callback_list_two).await;
+    emitter
+        .on(
+            |_event: Arc<TestListEvent>, data: Arc<RwLock<Vec<String>>>| async move {
+                data.write().await.push("three".into());
+                Ok(EventState::Continue)
+            },
+        )
+        .await;
+
+    let data: Vec<String> = emitter.emit(TestListEvent("a".into())).await.unwrap();
+
+    assert_eq!(data, ["a-one", "a-two", "three"]);
+}
+
+#[derive(Debug, PartialEq)]
+enum TestKind {
+    Build,
+    Test,
+}
+
+#[derive(Event)]
+#[event(dataset = i32)]
+struct TestKindEvent {
+    pub kind: TestKind,
+}
+
+#[subscriber(when = "event.kind == TestKind::Build")]
+async fn callback_build_only(data: &mut TestKindEvent) -> EventResult {
+    *data += 1;
+    Ok(EventState::Continue)
+}
+
+#[subscriber(when = event.kind == TestKind::Test)]
+async fn callback_test_only(data: &mut TestKindEvent) -> EventResult {
+    *data += 10;
+    Ok(EventState::Continue)
+}
+
+#[tokio::test]
+async fn skips_subscribers_when_condition_false() {
+    let emitter = Emitter::<TestKindEvent>::new();
+    emitter.on(callback_build_only).await;
+    emitter.on(callback_test_only).await;
+
+    let data = emitter
+        .emit(TestKindEvent {
+            kind: TestKind::Build,
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(data, 1);
+
+    let data = emitter
+        .emit(TestKindEvent {
+            kind: TestKind::Test,
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(data, 10);
+}
+
 // #[derive(Event)]
 // #[event(dataset = String)]
 // struct TestRefEvent<'e> {
@@ -1,32 +1,74 @@
-use darling::FromDeriveInput;
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{parse_macro_input, DeriveInput, ExprPath};
+use syn::{parse_macro_input, DeriveInput, LitStr, Meta, Type};
 
-#[derive(FromDeriveInput, Default)]
-#[darling(default, attributes(event))]
+#[derive(Default)]
 struct EventArgs {
-    dataset: Option<ExprPath>,
+    name: Option<LitStr>,
+    value: Option<Type>,
+}
+
+impl EventArgs {
+    // Parsed manually instead of with `darling`, as generic types
+    // like `Vec<String>` are not valid meta expressions.
+    fn from_derive_input(input: &DeriveInput) -> syn::Result<Self> {
+        let mut args = Self::default();
+
+        for attr in &input.attrs {
+            // Allow a bare `#[event]` without arguments
+            if !attr.path().is_ident("event") || matches!(attr.meta, Meta::Path(_)) {
+                continue;
+            }
+
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("dataset") || meta.path.is_ident("value") {
+                    args.value = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("name") {
+                    args.name = Some(meta.value()?.parse()?);
+                } else {
+                    return Err(meta.error("unsupported event argument"));
+                }
+
+                Ok(())
+            })?;
+        }
+
+        Ok(args)
+    }
 }
 
 // #[derive(Event)]
 // #[event]
-// #[event(data = String)]
+// #[event(dataset = String)]
+// #[event(value = Vec<String>)]
+// #[event(name = "build.started")]
 pub fn macro_impl(item: TokenStream) -> TokenStream {
     let input: DeriveInput = parse_macro_input!(item);
-    let args = EventArgs::from_derive_input(&input).unwrap_or_default();
+    let args = match EventArgs::from_derive_input(&input) {
+        Ok(args) => args,
+        Err(error) => return error.to_compile_error().into(),
+    };
 
     let struct_name = input.ident;
     let generics = input.generics;
-    let data_type = match args.dataset {
+    let data_type = match args.value {
         Some(value) => quote! { #value },
         None => quote! { () },
     };
+    let name_fn = args.name.map(|name| {
+        quote! {
+            fn name() -> &'static str {
+                #name
+            }
+        }
+    });
 
     quote! {
         #[automatically_derived]
         impl #generics starbase_events::Event for #struct_name #generics {
             type Data = #data_type;
+
+            #name_fn
         }
     }
     .into()
@@ -1,7 +1,7 @@
 use darling::FromMeta;
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{parse_macro_input, Expr, ExprCall, ExprPath, FnArg, Pat, Stmt, Type, TypePath};
+use syn::{parse_macro_input, Expr, ExprCall, ExprPath, FnArg, LitStr, Pat, Stmt, Type, TypePath};
 
 fn is_event_state(path: &ExprPath) -> bool {
     let Some(state) = path.path.segments.first() else {
@@ -70,7 +70,28 @@ fn has_return_statement(block: &syn::Block) -> bool {
 }
 
 // #[subscriber]
-pub fn macro_impl(_args: TokenStream, item: TokenStream) -> TokenStream {
+// #[subscriber(when = "event.kind == Kind::Build")]
+// #[subscriber(when = event.kind == Kind::Build)]
+pub fn macro_impl(args: TokenStream, item: TokenStream) -> TokenStream {
+    let mut when: Option<Expr> = None;
+    let args_parser = syn::meta::parser(|meta| {
+        if meta.path.is_ident("when") {
+            let value = meta.value()?;
+
+            when = Some(if value.peek(LitStr) {
+                value.parse::<LitStr>()?.parse()?
+            } else {
+                value.parse()?
+            });
+
+            Ok(())
+        } else {
+            Err(meta.error("unsupported subscriber argument"))
+        }
+    });
+
+    parse_macro_input!(args with args_parser);
+
     let func = parse_macro_input!(item as syn::ItemFn);
     let func_name = func.sig.ident;
     let func_body = func.block;
@@ -110,6 +131,15 @@ pub fn macro_impl(_args: TokenStream, item: TokenStream) -> TokenStream {
         }
     };
 
+    // Skip the subscriber when the condition is false
+    let guard = when.map(|condition| {
+        quote! {
+            if !(#condition) {
+                return Ok(starbase_events::EventState::Continue);
+            }
+        }
+    });
+
     let acquire_lock = if is_mutable {
         quote! { let mut #data_name = #data_name.write().await; }
     } else {
@@ -136,6 +166,7 @@ pub fn macro_impl(_args: TokenStream, item: TokenStream) -> TokenStream {
             event: std::sync::Arc<#event_type>,
             #data_name: std::sync::Arc<tokio::sync::RwLock<<#event_type as starbase_events::Event>::Data>>
         ) -> starbase_events::EventResult {
+            #guard
             #acquire_lock
             #func_body
             #return_flow
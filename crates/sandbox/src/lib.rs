@@ -1,11 +1,15 @@
 mod fixture;
 mod process;
+#[cfg(any(feature = "json", feature = "toml", feature = "yaml"))]
+mod round_trip;
 mod sandbox;
 mod settings;
 
 pub use fixture::*;
 pub use insta::{assert_debug_snapshot, assert_snapshot};
 pub use process::*;
+#[cfg(any(feature = "json", feature = "toml", feature = "yaml"))]
+pub use round_trip::*;
 pub use sandbox::*;
 pub use settings::*;
 
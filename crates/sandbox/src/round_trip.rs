@@ -0,0 +1,83 @@
+use crate::sandbox::create_temp_dir;
+use pretty_assertions::assert_eq;
+use starbase_utils::fs;
+use std::fmt::Debug;
+use std::path::Path;
+
+fn assert_value_eq<T: Debug + PartialEq>(path: &Path, value: T, written_value: T) {
+    assert_eq!(
+        value, written_value,
+        "Round-trip of {path:?} is not semantically equal!"
+    );
+}
+
+#[cfg(feature = "json")]
+fn round_trip_json(path: &Path, temp_path: &Path) {
+    use starbase_utils::json::{self, JsonValue};
+
+    let value: JsonValue = json::read_file(path).unwrap();
+    json::write_file(temp_path, &value, true).unwrap();
+
+    assert_value_eq(path, value, json::read_file(temp_path).unwrap());
+}
+
+#[cfg(feature = "toml")]
+fn round_trip_toml(path: &Path, temp_path: &Path) {
+    use starbase_utils::toml::{self, TomlValue};
+
+    let value: TomlValue = toml::read_file(path).unwrap();
+    toml::write_file(temp_path, &value, true).unwrap();
+
+    assert_value_eq(path, value, toml::read_file(temp_path).unwrap());
+}
+
+#[cfg(feature = "yaml")]
+fn round_trip_yaml(path: &Path, temp_path: &Path) {
+    use starbase_utils::yaml::{self, YamlValue};
+
+    let value: YamlValue = yaml::read_file(path).unwrap();
+    yaml::write_file(temp_path, &value).unwrap();
+
+    assert_value_eq(path, value, yaml::read_file(temp_path).unwrap());
+}
+
+fn round_trip(path: &Path) -> String {
+    let temp_dir = create_temp_dir();
+    let temp_path = temp_dir.path().join(path.file_name().unwrap());
+
+    match path.extension().and_then(|ext| ext.to_str()) {
+        #[cfg(feature = "json")]
+        Some("json") => round_trip_json(path, &temp_path),
+        #[cfg(feature = "toml")]
+        Some("toml") => round_trip_toml(path, &temp_path),
+        #[cfg(feature = "yaml")]
+        Some("yaml" | "yml") => round_trip_yaml(path, &temp_path),
+        _ => panic!(
+            "Unable to round-trip {path:?}, unsupported file format! Is the feature enabled?"
+        ),
+    };
+
+    fs::read_file(&temp_path).unwrap()
+}
+
+/// Read the JSON, TOML, or YAML file at the provided path (based on its extension),
+/// write it back through the respective `starbase_utils` module, and assert that the
+/// written data is semantically equal to the original. Formatting is not compared.
+///
+/// Requires the `json`, `toml`, or `yaml` feature.
+pub fn assert_round_trip<P: AsRef<Path>>(path: P) {
+    round_trip(path.as_ref());
+}
+
+/// Like [`assert_round_trip`], but also assert that the written file is byte-for-byte
+/// equal to the original, to guard against serializers reordering or reformatting data.
+pub fn assert_exact_round_trip<P: AsRef<Path>>(path: P) {
+    let path = path.as_ref();
+    let original = fs::read_file(path).unwrap();
+
+    assert_eq!(
+        original,
+        round_trip(path),
+        "Round-trip of {path:?} is not byte equal!"
+    );
+}
@@ -0,0 +1,67 @@
+use starbase_sandbox::{assert_exact_round_trip, assert_round_trip, locate_fixture};
+
+mod json {
+    use super::*;
+
+    #[test]
+    fn sorted_is_exact() {
+        assert_exact_round_trip(locate_fixture("round-trip").join("sorted.json"));
+    }
+
+    #[test]
+    fn unsorted_is_semantic() {
+        assert_round_trip(locate_fixture("round-trip").join("unsorted.json"));
+    }
+
+    #[test]
+    #[should_panic(expected = "not byte equal")]
+    fn unsorted_is_not_exact() {
+        assert_exact_round_trip(locate_fixture("round-trip").join("unsorted.json"));
+    }
+}
+
+mod toml {
+    use super::*;
+
+    #[test]
+    fn sorted_is_exact() {
+        assert_exact_round_trip(locate_fixture("round-trip").join("sorted.toml"));
+    }
+
+    #[test]
+    fn commented_is_semantic() {
+        assert_round_trip(locate_fixture("round-trip").join("commented.toml"));
+    }
+
+    #[test]
+    #[should_panic(expected = "not byte equal")]
+    fn commented_is_not_exact() {
+        assert_exact_round_trip(locate_fixture("round-trip").join("commented.toml"));
+    }
+}
+
+mod yaml {
+    use super::*;
+
+    #[test]
+    fn sorted_is_exact() {
+        assert_exact_round_trip(locate_fixture("round-trip").join("sorted.yaml"));
+    }
+
+    #[test]
+    fn quoted_is_semantic() {
+        assert_round_trip(locate_fixture("round-trip").join("quoted.yaml"));
+    }
+
+    #[test]
+    #[should_panic(expected = "not byte equal")]
+    fn quoted_is_not_exact() {
+        assert_exact_round_trip(locate_fixture("round-trip").join("quoted.yaml"));
+    }
+}
+
+#[test]
+#[should_panic(expected = "unsupported file format")]
+fn errors_for_unknown_format() {
+    assert_round_trip(locate_fixture("round-trip").join("file.txt"));
+}
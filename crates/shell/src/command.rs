@@ -0,0 +1,90 @@
+use crate::shells::Shell;
+
+/// A part of a command line, either an argument that requires quoting,
+/// or an operator (redirection, pipe, etc) that must be passed through as-is.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CommandPart<'data> {
+    /// An argument that will be quoted for the target shell.
+    Arg(&'data str),
+    /// An operator such as `>`, `>>`, `|`, or `&&`, that will not be quoted.
+    Operator(&'data str),
+}
+
+impl<'data> From<&'data str> for CommandPart<'data> {
+    fn from(value: &'data str) -> Self {
+        Self::Arg(value)
+    }
+}
+
+/// Join the provided parts into a single command line for the target shell.
+/// Arguments will be quoted, while operators will be passed through verbatim.
+pub fn join_command_parts<'data, I, P>(shell: &dyn Shell, parts: I) -> String
+where
+    I: IntoIterator<Item = P>,
+    P: Into<CommandPart<'data>>,
+{
+    parts
+        .into_iter()
+        .map(|part| match part.into() {
+            CommandPart::Arg(arg) => shell.quote(arg),
+            CommandPart::Operator(op) => op.to_owned(),
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shells::{Bash, Fish, Pwsh};
+
+    #[test]
+    fn joins_args() {
+        assert_eq!(
+            join_command_parts(&Bash, ["echo", "hello world"]),
+            "echo \"hello world\""
+        );
+    }
+
+    #[test]
+    fn passes_through_redirects() {
+        assert_eq!(
+            join_command_parts(
+                &Bash,
+                [
+                    CommandPart::Arg("echo"),
+                    CommandPart::Arg("a > b"),
+                    CommandPart::Operator(">"),
+                    CommandPart::Arg("out file.txt"),
+                ]
+            ),
+            "echo \"a > b\" > \"out file.txt\""
+        );
+    }
+
+    #[test]
+    fn passes_through_pipes() {
+        let parts = [
+            CommandPart::Arg("cat"),
+            CommandPart::Arg("file.txt"),
+            CommandPart::Operator("|"),
+            CommandPart::Arg("grep"),
+            CommandPart::Arg("some value"),
+            CommandPart::Operator("&&"),
+            CommandPart::Arg("exit"),
+        ];
+
+        assert_eq!(
+            join_command_parts(&Bash, parts),
+            "cat \"file.txt\" | grep \"some value\" && exit"
+        );
+        assert_eq!(
+            join_command_parts(&Fish, parts),
+            r#""cat" "file.txt" | "grep" 'some value' && "exit""#
+        );
+        assert_eq!(
+            join_command_parts(&Pwsh, parts),
+            "'cat' 'file.txt' | 'grep' 'some value' && 'exit'"
+        );
+    }
+}
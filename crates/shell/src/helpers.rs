@@ -25,10 +25,65 @@ pub fn get_config_dir(home_dir: &Path) -> PathBuf {
         .unwrap_or_else(|| home_dir.join(".config"))
 }
 
+#[cfg(unix)]
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+
+    path.metadata()
+        .is_ok_and(|meta| meta.is_file() && meta.permissions().mode() & 0o111 != 0)
+}
+
+#[cfg(not(unix))]
+fn is_executable(path: &Path) -> bool {
+    path.is_file()
+}
+
+/// Search the directories in the `PATH` environment variable for an executable
+/// with the provided name, and return the first match. On Windows, each extension
+/// in `PATHEXT` is appended to the name while searching.
+pub fn find_executable_on_path(name: &str) -> Option<PathBuf> {
+    let extensions = if cfg!(windows) {
+        env::var("PATHEXT")
+            .unwrap_or_else(|_| ".COM;.EXE;.BAT;.CMD".into())
+            .split(';')
+            .filter(|ext| !ext.is_empty())
+            .map(|ext| ext.to_lowercase())
+            .collect::<Vec<_>>()
+    } else {
+        vec![String::new()]
+    };
+
+    env::split_paths(&env::var_os("PATH")?).find_map(|dir| {
+        extensions
+            .iter()
+            .map(|ext| dir.join(format!("{name}{ext}")))
+            .find(|path| is_executable(path))
+    })
+}
+
 pub fn get_env_var_regex() -> regex::Regex {
     regex::Regex::new(r"\$(?<name>[A-Z0-9_]+)").unwrap()
 }
 
+/// Format a POSIX compatible command that removes the provided paths from
+/// a delimited environment variable, by filtering out exact line matches.
+/// When no paths are provided, the variable is referenced as-is, as `grep`
+/// requires at least one pattern.
+pub fn format_posix_path_remove(key: &str, paths: &[String]) -> String {
+    if paths.is_empty() {
+        return format!("${key}");
+    }
+
+    format!(
+        r#"$(printf '%s' "${key}" | tr ':' '\n' | grep -vxF{} | paste -sd ':' -)"#,
+        paths
+            .iter()
+            .map(|path| format!(r#" -e "{path}""#))
+            .collect::<Vec<_>>()
+            .join("")
+    )
+}
+
 pub fn normalize_newlines(content: impl AsRef<str>) -> String {
     let content = content.as_ref().trim();
 
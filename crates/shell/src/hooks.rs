@@ -1,9 +1,17 @@
 pub enum Statement<'data> {
+    /// A comment, prefixed with the shell's comment character.
+    Comment(&'data str),
+    /// Raw content that is passed through as-is.
+    Raw(&'data str),
     PrependPath {
         paths: &'data [String],
         key: Option<&'data str>,
         orig_key: Option<&'data str>,
     },
+    RemovePath {
+        paths: &'data [String],
+        key: Option<&'data str>,
+    },
     SetEnv {
         key: &'data str,
         value: &'data str,
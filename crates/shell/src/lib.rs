@@ -1,10 +1,12 @@
+mod command;
 mod helpers;
 mod hooks;
 mod shell;
 mod shell_error;
 mod shells;
 
+pub use command::*;
 pub use hooks::*;
-pub use shell::ShellType;
+pub use shell::{ShellInfo, ShellType};
 pub use shell_error::ShellError;
 pub use shells::*;
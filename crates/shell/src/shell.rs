@@ -1,9 +1,20 @@
-use crate::{shell_error::ShellError, shells::*};
-use std::path::Path;
+use crate::{helpers::find_executable_on_path, shell_error::ShellError, shells::*};
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::{env, fmt};
 use tracing::{debug, instrument};
 
+/// Static metadata about a supported shell.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ShellInfo {
+    /// Human readable name of the shell.
+    pub display_name: &'static str,
+    /// File name of the default profile/rc file that is written to.
+    pub profile_file: &'static str,
+    /// Whether the shell supports [hooks](crate::Hook).
+    pub supports_hooks: bool,
+}
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum ShellType {
     Bash,
@@ -19,9 +30,9 @@ pub enum ShellType {
 }
 
 impl ShellType {
-    /// Return a list of all shell types.
-    pub fn variants() -> Vec<Self> {
-        vec![
+    /// Return a slice of all shell types.
+    pub fn all() -> &'static [Self] {
+        &[
             Self::Bash,
             Self::Elvish,
             Self::Fish,
@@ -35,6 +46,11 @@ impl ShellType {
         ]
     }
 
+    /// Return a list of all shell types.
+    pub fn variants() -> Vec<Self> {
+        Self::all().to_vec()
+    }
+
     /// Return a list of shell types for the current operating system.
     pub fn os_variants() -> Vec<Self> {
         #[cfg(windows)]
@@ -100,6 +116,90 @@ impl ShellType {
         Err(ShellError::CouldNotDetectShell)
     }
 
+    /// Determine the shell type from a path to a shell binary, by matching
+    /// the file name against known shell names. Handles Windows style paths,
+    /// `.exe` extensions, login shell prefixes (`-zsh`), and versioned names
+    /// (`bash-5.2`, `bash5`).
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Option<Self> {
+        let path = path.as_ref().to_str()?;
+
+        // Split manually so that Windows paths work on Unix (and vice versa)
+        let mut name = path.rsplit(['/', '\\']).next()?;
+
+        if let Some(index) = name.len().checked_sub(4) {
+            if name
+                .get(index..)
+                .is_some_and(|ext| ext.eq_ignore_ascii_case(".exe"))
+            {
+                name = &name[..index];
+            }
+        }
+
+        // Remove login shell leading `-`
+        name = name.strip_prefix('-').unwrap_or(name);
+
+        if let Ok(shell) = Self::from_str(name) {
+            return Some(shell);
+        }
+
+        // Remove trailing versions, like `-5.2` or `5`
+        let unversioned = name
+            .trim_end_matches(|c: char| c.is_ascii_digit() || c == '.')
+            .trim_end_matches('-');
+
+        if unversioned.is_empty() || unversioned == name {
+            return None;
+        }
+
+        Self::from_str(unversioned).ok()
+    }
+
+    /// Locate the executable for the current type by searching the directories
+    /// in the `PATH` environment variable, and return the first match. Pairs
+    /// with [`Shell::get_exec_command`] when spawning the shell.
+    #[instrument]
+    pub fn find_binary(&self) -> Option<PathBuf> {
+        let name = self.to_string();
+
+        // Windows PowerShell is the fallback when PowerShell Core is not installed
+        let names = match self {
+            Self::Pwsh => vec!["pwsh", "powershell"],
+            _ => vec![name.as_str()],
+        };
+
+        let path = names.into_iter().find_map(find_executable_on_path);
+
+        match &path {
+            Some(path) => debug!(bin = ?path, "Found {} binary", name),
+            None => debug!("Could not find {} binary on PATH", name),
+        };
+
+        path
+    }
+
+    /// Return static metadata about the current type, like its display name,
+    /// default profile file name, and supported capabilities.
+    pub fn info(&self) -> ShellInfo {
+        let (display_name, profile_file, supports_hooks) = match self {
+            Self::Bash => ("Bash", ".bashrc", true),
+            Self::Elvish => ("Elvish", "rc.elv", true),
+            Self::Fish => ("Fish", "config.fish", true),
+            Self::Ion => ("Ion", "initrc", false),
+            Self::Murex => ("Murex", ".murex_profile", true),
+            Self::Nu => ("Nushell", "config.nu", true),
+            Self::Pwsh => ("PowerShell", "Microsoft.PowerShell_profile.ps1", true),
+            Self::Sh => ("POSIX sh", ".profile", false),
+            Self::Xonsh => ("Xonsh", "rc.xsh", false),
+            Self::Zsh => ("Zsh", ".zshrc", true),
+        };
+
+        ShellInfo {
+            display_name,
+            profile_file,
+            supports_hooks,
+        }
+    }
+
     /// Build a [`Shell`] instance from the current type.
     pub fn build(&self) -> BoxedShell {
         match self {
@@ -190,11 +290,7 @@ impl TryFrom<String> for ShellType {
 }
 
 pub fn parse_shell_from_path<P: AsRef<Path>>(path: P) -> Option<ShellType> {
-    // Remove trailing extensions (like `.exe`)
-    let name = path.as_ref().file_stem()?.to_str()?;
-
-    // Remove login shell leading `-`
-    ShellType::from_str(name.strip_prefix('-').unwrap_or(name)).ok()
+    ShellType::from_path(path)
 }
 
 fn detect_from_os() -> Option<ShellType> {
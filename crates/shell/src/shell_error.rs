@@ -7,6 +7,10 @@ pub enum ShellError {
     #[error("Could not detect your terminal shell. Scanned the $SHELL environment variable and parent processes.")]
     CouldNotDetectShell,
 
+    #[cfg_attr(feature = "miette", diagnostic(code(shell::no_abbr_support)))]
+    #[error("Your shell, {name}, does not support abbreviations.")]
+    NoAbbrSupport { name: String },
+
     #[cfg_attr(feature = "miette", diagnostic(code(shell::no_hook_support)))]
     #[error("Your shell, {name}, does not support \"{info}\" hooks. Please remove the command from your shell that is triggering this hook.")]
     NoHookSupport { name: String, info: String },
@@ -1,5 +1,5 @@
 use super::Shell;
-use crate::helpers::normalize_newlines;
+use crate::helpers::{format_posix_path_remove, normalize_newlines};
 use crate::hooks::*;
 use std::fmt;
 use std::path::{Path, PathBuf};
@@ -33,6 +33,8 @@ fn profile_for_bash(home_dir: &Path) -> PathBuf {
 impl Shell for Bash {
     fn format(&self, statement: Statement<'_>) -> String {
         match statement {
+            Statement::Comment(text) => self.format_comment(text),
+            Statement::Raw(text) => text.to_owned(),
             Statement::PrependPath {
                 paths,
                 key,
@@ -43,6 +45,14 @@ impl Shell for Bash {
 
                 format!(r#"export {key}="{}:${orig_key}";"#, paths.join(":"))
             }
+            Statement::RemovePath { paths, key } => {
+                let key = key.unwrap_or("PATH");
+
+                format!(
+                    r#"export {key}="{}";"#,
+                    format_posix_path_remove(key, paths)
+                )
+            }
             Statement::SetEnv { key, value } => {
                 format!("export {}={};", self.quote(key), self.quote(value))
             }
@@ -142,6 +152,26 @@ mod tests {
     use super::*;
     use starbase_sandbox::assert_snapshot;
 
+    #[test]
+    fn formats_statements() {
+        assert_eq!(
+            Bash.format_all(vec![
+                Statement::Comment("Setup proto\n(managed)"),
+                Statement::SetEnv {
+                    key: "PROTO_HOME",
+                    value: "$HOME/.proto",
+                },
+                Statement::Raw("source ~/.proto/env"),
+                Statement::UnsetEnv { key: "PROTO_DEBUG" },
+            ]),
+            r#"# Setup proto
+# (managed)
+export PROTO_HOME="$HOME/.proto";
+source ~/.proto/env
+unset PROTO_DEBUG;"#
+        );
+    }
+
     #[test]
     fn formats_env_var() {
         assert_eq!(
@@ -150,6 +180,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn formats_env_batch() {
+        assert_eq!(
+            Bash.format_env_batch(&[
+                ("PROTO_HOME".into(), Some("$HOME/.proto".into())),
+                ("PROTO_ROOT".into(), None),
+                ("PROTO_DEBUG".into(), Some("true".into())),
+            ]),
+            "export PROTO_HOME=\"$HOME/.proto\";\nunset PROTO_ROOT;\nexport PROTO_DEBUG=true;"
+        );
+    }
+
+    #[test]
+    fn formats_path_remove() {
+        assert_eq!(
+            Bash.format_path_remove(&["$PROTO_HOME/shims".into(), "/abs/bin".into()]),
+            r#"export PATH="$(printf '%s' "$PATH" | tr ':' '\n' | grep -vxF -e "$PROTO_HOME/shims" -e "/abs/bin" | paste -sd ':' -)";"#
+        );
+    }
+
+    #[test]
+    fn formats_path_remove_without_paths() {
+        assert_eq!(Bash.format_path_remove(&[]), "");
+        assert_eq!(
+            Bash.format(Statement::RemovePath {
+                paths: &[],
+                key: Some("PROTO_PATH"),
+            }),
+            r#"export PROTO_PATH="$PROTO_PATH";"#
+        );
+    }
+
     #[test]
     fn formats_path() {
         assert_eq!(
@@ -158,6 +220,14 @@ mod tests {
         );
     }
 
+    #[test]
+    fn errors_for_abbr() {
+        assert!(matches!(
+            Bash.format_abbr("gco", "git checkout"),
+            Err(crate::ShellError::NoAbbrSupport { .. })
+        ));
+    }
+
     #[test]
     fn formats_cd_hook() {
         let hook = Hook::OnChangeDir {
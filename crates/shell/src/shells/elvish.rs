@@ -26,6 +26,8 @@ fn format(value: impl AsRef<str>) -> String {
 impl Shell for Elvish {
     fn format(&self, statement: Statement<'_>) -> String {
         match statement {
+            Statement::Comment(text) => self.format_comment(text),
+            Statement::Raw(text) => text.to_owned(),
             Statement::PrependPath {
                 paths,
                 key,
@@ -53,6 +55,25 @@ impl Shell for Elvish {
                     )
                 }
             }
+            Statement::RemovePath { paths, key } => {
+                let key = key.unwrap_or("PATH");
+                let filter = format!(
+                    "each {{|p| if (not (has-value [{}] $p)) {{ put $p }} }}",
+                    paths
+                        .iter()
+                        .map(|p| self.quote(&format(p)))
+                        .collect::<Vec<_>>()
+                        .join(" ")
+                );
+
+                if key == "PATH" {
+                    format!("set paths = [({filter} $paths)];")
+                } else {
+                    format!(
+                        r#"use str; set-env {key} (str:join "{PATH_DELIMITER}" [(str:split "{PATH_DELIMITER}" $E:{key} | {filter})]);"#
+                    )
+                }
+            }
             Statement::SetEnv { key, value } => {
                 format!(
                     "set-env {} {};",
@@ -181,6 +202,14 @@ mod tests {
         assert_eq!(Elvish.format_env_set("FOO", "bar"), "set-env FOO bar;");
     }
 
+    #[test]
+    fn formats_path_remove() {
+        assert_eq!(
+            Elvish.format_path_remove(&["$PROTO_HOME/shims".into(), "/abs/bin".into()]),
+            r#"set paths = [(each {|p| if (not (has-value ["$E:PROTO_HOME/shims" /abs/bin] $p)) { put $p } } $paths)];"#
+        );
+    }
+
     #[test]
     fn formats_path() {
         assert_eq!(
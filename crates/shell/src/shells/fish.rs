@@ -18,6 +18,8 @@ impl Fish {
 impl Shell for Fish {
     fn format(&self, statement: Statement<'_>) -> String {
         match statement {
+            Statement::Comment(text) => self.format_comment(text),
+            Statement::Raw(text) => text.to_owned(),
             Statement::PrependPath {
                 paths,
                 key,
@@ -35,6 +37,22 @@ impl Shell for Fish {
                         .join(" ")
                 )
             }
+            Statement::RemovePath { paths, key } => {
+                let key = key.unwrap_or("PATH");
+
+                format!(
+                    r#"for path in {}
+  while set -l index (contains --index -- $path ${key})
+    set -e {key}[$index]
+  end
+end;"#,
+                    paths
+                        .iter()
+                        .map(|p| self.quote(p))
+                        .collect::<Vec<_>>()
+                        .join(" ")
+                )
+            }
             Statement::SetEnv { key, value } => {
                 format!("set -gx {} {};", key, self.quote(value))
             }
@@ -44,6 +62,11 @@ impl Shell for Fish {
         }
     }
 
+    // https://fishshell.com/docs/current/cmds/abbr.html
+    fn format_abbr(&self, name: &str, expansion: &str) -> Result<String, crate::ShellError> {
+        Ok(format!("abbr -a {name} {};", self.quote(expansion)))
+    }
+
     fn format_hook(&self, hook: Hook) -> Result<String, crate::ShellError> {
         Ok(normalize_newlines(match hook {
             Hook::OnChangeDir { command, function } => {
@@ -145,6 +168,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn formats_env_batch() {
+        assert_eq!(
+            Fish.format_env_batch(&[
+                ("PROTO_HOME".into(), Some("$HOME/.proto".into())),
+                ("PROTO_ROOT".into(), None),
+                ("PROTO_DEBUG".into(), Some("true".into())),
+            ]),
+            "set -gx PROTO_HOME \"$HOME/.proto\";\nset -ge PROTO_ROOT;\nset -gx PROTO_DEBUG \"true\";"
+        );
+    }
+
+    #[test]
+    fn formats_path_remove() {
+        assert_eq!(
+            Fish.format_path_remove(&["$PROTO_HOME/shims".into(), "/abs/bin".into()]),
+            r#"for path in "$PROTO_HOME/shims" "/abs/bin"
+  while set -l index (contains --index -- $path $PATH)
+    set -e PATH[$index]
+  end
+end;"#
+        );
+    }
+
     #[test]
     fn formats_path() {
         assert_eq!(
@@ -153,6 +200,15 @@ mod tests {
         );
     }
 
+    #[test]
+    fn formats_abbr() {
+        assert_eq!(
+            Fish.format_abbr("gco", "git checkout").unwrap(),
+            "abbr -a gco 'git checkout';"
+        );
+        assert_eq!(Fish.format_abbr("ll", "ls").unwrap(), r#"abbr -a ll "ls";"#);
+    }
+
     #[test]
     fn formats_cd_hook() {
         let hook = Hook::OnChangeDir {
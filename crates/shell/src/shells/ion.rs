@@ -1,6 +1,6 @@
 use super::Shell;
-use crate::helpers::get_config_dir;
 use crate::helpers::ProfileSet;
+use crate::helpers::{format_posix_path_remove, get_config_dir};
 use crate::hooks::*;
 use std::fmt;
 use std::path::{Path, PathBuf};
@@ -19,6 +19,8 @@ impl Shell for Ion {
     // https://doc.redox-os.org/ion-manual/variables/05-exporting.html
     fn format(&self, statement: Statement<'_>) -> String {
         match statement {
+            Statement::Comment(text) => self.format_comment(text),
+            Statement::Raw(text) => text.to_owned(),
             Statement::PrependPath {
                 paths,
                 key,
@@ -32,6 +34,14 @@ impl Shell for Ion {
                     paths.join(":"),
                 )
             }
+            Statement::RemovePath { paths, key } => {
+                let key = key.unwrap_or("PATH");
+
+                format!(
+                    "export {key} = {}",
+                    format_posix_path_remove(&format!("{{env::{key}}}"), paths)
+                )
+            }
             Statement::SetEnv { key, value } => {
                 format!("export {}={}", self.quote(key), self.quote(value))
             }
@@ -96,6 +106,14 @@ mod tests {
         );
     }
 
+    #[test]
+    fn formats_path_remove() {
+        assert_eq!(
+            Ion.format_path_remove(&["$PROTO_HOME/shims".into(), "/abs/bin".into()]),
+            r#"export PATH = $(printf '%s' "${env::PATH}" | tr ':' '\n' | grep -vxF -e "$PROTO_HOME/shims" -e "/abs/bin" | paste -sd ':' -)"#
+        );
+    }
+
     #[test]
     fn formats_path() {
         assert_eq!(
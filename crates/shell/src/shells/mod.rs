@@ -47,6 +47,50 @@ pub trait Shell: Display + Send + Sync {
     /// Format the provided statement.
     fn format(&self, data: Statement<'_>) -> String;
 
+    /// Format the provided statements, in order, and join them with newlines.
+    /// This can be used to build an entire script or profile fragment.
+    fn format_all(&self, statements: Vec<Statement<'_>>) -> String {
+        statements
+            .into_iter()
+            .map(|statement| self.format(statement))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Format an abbreviation that expands the provided name into the expansion
+    /// when typed interactively. Not all shells support abbreviations.
+    fn format_abbr(&self, _name: &str, _expansion: &str) -> Result<String, ShellError> {
+        Err(ShellError::NoAbbrSupport {
+            name: self.to_string(),
+        })
+    }
+
+    /// Format a comment, prefixing each line of the provided text with the
+    /// comment character. All supported shells use `#` for line comments.
+    fn format_comment(&self, text: &str) -> String {
+        text.lines()
+            .map(|line| {
+                if line.is_empty() {
+                    "#".to_owned()
+                } else {
+                    format!("# {line}")
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Format the provided content wrapped in start and end marker comments,
+    /// so that the block can be detected in a profile file at a later time.
+    fn format_marked_block(&self, marker: &str, content: &str) -> String {
+        format!(
+            "{}\n{}\n{}",
+            self.format_comment(&format!(">>> {marker} >>>")),
+            content.trim(),
+            self.format_comment(&format!("<<< {marker} <<<"))
+        )
+    }
+
     /// Format an environment variable by either setting or unsetting the value.
     fn format_env(&self, key: &str, value: Option<&str>) -> String {
         match value {
@@ -55,6 +99,15 @@ pub trait Shell: Display + Send + Sync {
         }
     }
 
+    /// Format a batch of environment variables, in order, by either setting
+    /// or unsetting each value. Each statement is rendered on its own line.
+    fn format_env_batch(&self, vars: &[(String, Option<String>)]) -> String {
+        vars.iter()
+            .map(|(key, value)| self.format_env(key, value.as_deref()))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
     /// Format an environment variable that will be set to the entire shell,
     /// and be written to a profile file.
     fn format_env_set(&self, key: &str, value: &str) -> String {
@@ -77,6 +130,17 @@ pub trait Shell: Display + Send + Sync {
         })
     }
 
+    /// Format the provided paths to be removed from the `PATH` environment variable,
+    /// and be written to a profile file. All occurrences of each path are removed.
+    /// If no paths are provided, an empty string is returned.
+    fn format_path_remove(&self, paths: &[String]) -> String {
+        if paths.is_empty() {
+            return String::new();
+        }
+
+        self.format(Statement::RemovePath { paths, key: None })
+    }
+
     /// Format a hook for the current shell.
     fn format_hook(&self, hook: Hook) -> Result<String, ShellError> {
         Err(ShellError::NoHookSupport {
@@ -100,6 +164,15 @@ pub trait Shell: Display + Send + Sync {
     /// Ordered from most to least common/applicable.
     fn get_profile_paths(&self, home_dir: &Path) -> Vec<PathBuf>;
 
+    /// Return true if the provided profile contents contains a block previously
+    /// written with [`Shell::format_marked_block`] for the provided marker. This can
+    /// be used to avoid writing the same `PATH` modifications multiple times.
+    fn path_already_modified(&self, profile_contents: &str, marker: &str) -> bool {
+        let start = self.format_comment(&format!(">>> {marker} >>>"));
+
+        profile_contents.lines().any(|line| line.trim() == start)
+    }
+
     /// Quote method for shell-specific quoting
     fn quote(&self, value: &str) -> String;
 }
@@ -17,6 +17,8 @@ impl Murex {
 impl Shell for Murex {
     fn format(&self, statement: Statement<'_>) -> String {
         match statement {
+            Statement::Comment(text) => self.format_comment(text),
+            Statement::Raw(text) => text.to_owned(),
             Statement::PrependPath {
                 paths,
                 key,
@@ -31,6 +33,23 @@ impl Shell for Murex {
                     PATH_DELIMITER,
                 )
             }
+            Statement::RemovePath { paths, key } => {
+                let key = key.unwrap_or("PATH");
+
+                // `grep` requires at least one pattern
+                if paths.is_empty() {
+                    return format!("$ENV.{key}=$ENV.{key}");
+                }
+
+                format!(
+                    r#"$ENV.{key}=${{out $ENV.{key} -> tr '{PATH_DELIMITER}' '\n' -> grep -vxF{} -> paste -sd '{PATH_DELIMITER}' -}}"#,
+                    paths
+                        .iter()
+                        .map(|path| format!(" -e {}", self.quote(path)))
+                        .collect::<Vec<_>>()
+                        .join("")
+                )
+            }
             Statement::SetEnv { key, value } => {
                 format!("$ENV.{}={}", self.quote(key), self.quote(value))
             }
@@ -127,6 +146,27 @@ mod tests {
         );
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn formats_path_remove() {
+        assert_eq!(
+            Murex.format_path_remove(&["$PROTO_HOME/shims".into(), "/abs/bin".into()]),
+            r#"$ENV.PATH=${out $ENV.PATH -> tr ':' '\n' -> grep -vxF -e "$PROTO_HOME/shims" -e /abs/bin -> paste -sd ':' -}"#
+        );
+    }
+
+    #[test]
+    fn formats_path_remove_without_paths() {
+        assert_eq!(Murex.format_path_remove(&[]), "");
+        assert_eq!(
+            Murex.format(Statement::RemovePath {
+                paths: &[],
+                key: None,
+            }),
+            "$ENV.PATH=$ENV.PATH"
+        );
+    }
+
     #[cfg(unix)]
     #[test]
     fn formats_path() {
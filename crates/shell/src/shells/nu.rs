@@ -25,6 +25,20 @@ fn join_path(value: impl AsRef<str>) -> String {
     format!("path join {}", parts.join(" "))
 }
 
+fn format_path(value: &str) -> String {
+    if let Some(cap) = get_env_var_regex().captures(value) {
+        let path_without_env = value.replace(cap.get(0).unwrap().as_str(), "");
+
+        format!(
+            "($env.{} | {})",
+            cap.name("name").unwrap().as_str(),
+            join_path(path_without_env)
+        )
+    } else {
+        value.to_owned()
+    }
+}
+
 impl Shell for Nu {
     // https://www.nushell.sh/book/configuration.html#environment
     fn format(&self, statement: Statement<'_>) -> String {
@@ -35,12 +49,13 @@ impl Shell for Nu {
         };
 
         match statement {
+            Statement::Comment(text) => self.format_comment(text),
+            Statement::Raw(text) => text.to_owned(),
             Statement::PrependPath {
                 paths,
                 key,
                 orig_key,
             } => {
-                let env_regex = get_env_var_regex();
                 let key = key.unwrap_or(path_name);
                 let orig_key = orig_key.unwrap_or(key);
                 let mut value = format!("$env.{key} = ($env.{orig_key} | split row (char esep)\n");
@@ -48,23 +63,27 @@ impl Shell for Nu {
                 // https://www.nushell.sh/book/configuration.html#path-configuration
                 for path in paths.iter().rev() {
                     value.push_str("  | prepend ");
+                    value.push_str(&format_path(path));
+                    value.push('\n');
+                }
 
-                    if let Some(cap) = env_regex.captures(path) {
-                        let path_without_env = path.replace(cap.get(0).unwrap().as_str(), "");
+                value.push_str("  | uniq)");
 
-                        value.push('(');
-                        value.push_str(&format!("$env.{}", cap.name("name").unwrap().as_str()));
-                        value.push_str(" | ");
-                        value.push_str(&join_path(path_without_env));
-                        value.push(')');
-                    } else {
-                        value.push_str(path);
-                    }
+                normalize_newlines(value)
+            }
+            Statement::RemovePath { paths, key } => {
+                let key = key.unwrap_or(path_name);
+                let mut value = format!(
+                    "$env.{key} = ($env.{key} | split row (char esep)\n  | where {{|path| $path not-in [\n"
+                );
 
+                for path in paths {
+                    value.push_str("    ");
+                    value.push_str(&format_path(path));
                     value.push('\n');
                 }
 
-                value.push_str("  | uniq)");
+                value.push_str("  ]})");
 
                 normalize_newlines(value)
             }
@@ -206,6 +225,26 @@ impl fmt::Display for Nu {
 mod tests {
     use super::*;
 
+    #[test]
+    fn formats_statements() {
+        assert_eq!(
+            Nu.format_all(vec![
+                Statement::Comment("Setup proto\n(managed)"),
+                Statement::SetEnv {
+                    key: "PROTO_HOME",
+                    value: "$HOME/.proto",
+                },
+                Statement::Raw("source ~/.proto/env.nu"),
+                Statement::UnsetEnv { key: "PROTO_DEBUG" },
+            ]),
+            r#"# Setup proto
+# (managed)
+$env.PROTO_HOME = ($env.HOME | path join '.proto')
+source ~/.proto/env.nu
+hide-env PROTO_DEBUG"#
+        );
+    }
+
     #[test]
     fn formats_env_var() {
         assert_eq!(
@@ -214,6 +253,19 @@ mod tests {
         );
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn formats_path_remove() {
+        assert_eq!(
+            Nu.format_path_remove(&["$PROTO_HOME/shims".into(), "/abs/bin".into()]),
+            r#"$env.PATH = ($env.PATH | split row (char esep)
+  | where {|path| $path not-in [
+    ($env.PROTO_HOME | path join shims)
+    /abs/bin
+  ]})"#
+        );
+    }
+
     #[cfg(unix)]
     #[test]
     fn formats_path() {
@@ -48,12 +48,24 @@ impl Pwsh {
 
         format!("Join-Path {}", parts.join(" "))
     }
+
+    fn format_path(&self, value: impl AsRef<str>) -> String {
+        let path = self.join_path(value);
+
+        if path.starts_with("Join-Path") {
+            format!("({path})")
+        } else {
+            path
+        }
+    }
 }
 
 // https://learn.microsoft.com/en-us/powershell/module/microsoft.powershell.core/about/about_profiles?view=powershell-7.4
 impl Shell for Pwsh {
     fn format(&self, statement: Statement<'_>) -> String {
         match statement {
+            Statement::Comment(text) => self.format_comment(text),
+            Statement::Raw(text) => text.to_owned(),
             Statement::PrependPath {
                 paths,
                 key,
@@ -64,13 +76,7 @@ impl Shell for Pwsh {
                 let mut value = format!("$env:{key} = @(\n");
 
                 for path in paths {
-                    let path = self.join_path(path);
-
-                    if path.starts_with("Join-Path") {
-                        value.push_str(&format!("  ({})\n", path));
-                    } else {
-                        value.push_str(&format!("  {}\n", path));
-                    }
+                    value.push_str(&format!("  {}\n", self.format_path(path)));
                 }
 
                 value.push_str("  $env:");
@@ -79,6 +85,20 @@ impl Shell for Pwsh {
 
                 normalize_newlines(value)
             }
+            Statement::RemovePath { paths, key } => {
+                let key = key.unwrap_or("PATH");
+                let mut value = format!(
+                    "$env:{key} = @(\n  $env:{key} -split [IO.PATH]::PathSeparator | Where-Object {{\n    $_ -notin @(\n"
+                );
+
+                for path in paths {
+                    value.push_str(&format!("      {}\n", self.format_path(path)));
+                }
+
+                value.push_str("    )\n  }\n) -join [IO.PATH]::PathSeparator;");
+
+                normalize_newlines(value)
+            }
             Statement::SetEnv { key, value } => {
                 if value.contains('/') || value.contains('\\') {
                     format!("$env:{} = {};", key, self.join_path(value))
@@ -292,6 +312,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn formats_path_remove() {
+        assert_eq!(
+            Pwsh.format_path_remove(&["$PROTO_HOME/shims".into(), "/abs/bin".into()])
+                .replace("\r\n", "\n"),
+            r#"$env:PATH = @(
+  $env:PATH -split [IO.PATH]::PathSeparator | Where-Object {
+    $_ -notin @(
+      (Join-Path $env:PROTO_HOME "shims")
+      "/abs/bin"
+    )
+  }
+) -join [IO.PATH]::PathSeparator;"#
+        );
+    }
+
     #[test]
     fn formats_path() {
         assert_eq!(
@@ -1,4 +1,5 @@
 use super::Shell;
+use crate::helpers::format_posix_path_remove;
 use crate::hooks::*;
 use std::fmt;
 use std::path::{Path, PathBuf};
@@ -16,6 +17,8 @@ impl Sh {
 impl Shell for Sh {
     fn format(&self, statement: Statement<'_>) -> String {
         match statement {
+            Statement::Comment(text) => self.format_comment(text),
+            Statement::Raw(text) => text.to_owned(),
             Statement::PrependPath {
                 paths,
                 key,
@@ -26,6 +29,14 @@ impl Shell for Sh {
 
                 format!(r#"export {key}="{}:${orig_key}";"#, paths.join(":"))
             }
+            Statement::RemovePath { paths, key } => {
+                let key = key.unwrap_or("PATH");
+
+                format!(
+                    r#"export {key}="{}";"#,
+                    format_posix_path_remove(key, paths)
+                )
+            }
             Statement::SetEnv { key, value } => {
                 format!("export {}={};", self.quote(key), self.quote(value))
             }
@@ -107,6 +118,14 @@ mod tests {
         );
     }
 
+    #[test]
+    fn formats_path_remove() {
+        assert_eq!(
+            Sh.format_path_remove(&["$PROTO_HOME/shims".into(), "/abs/bin".into()]),
+            r#"export PATH="$(printf '%s' "$PATH" | tr ':' '\n' | grep -vxF -e "$PROTO_HOME/shims" -e "/abs/bin" | paste -sd ':' -)";"#
+        );
+    }
+
     #[test]
     fn formats_path() {
         assert_eq!(
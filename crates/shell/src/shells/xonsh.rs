@@ -1,5 +1,5 @@
 use super::Shell;
-use crate::helpers::{get_config_dir, ProfileSet};
+use crate::helpers::{get_config_dir, get_env_var_regex, ProfileSet};
 use crate::hooks::*;
 use std::fmt;
 use std::path::{Path, PathBuf};
@@ -19,6 +19,8 @@ impl Xonsh {
 impl Shell for Xonsh {
     fn format(&self, statement: Statement<'_>) -> String {
         match statement {
+            Statement::Comment(text) => self.format_comment(text),
+            Statement::Raw(text) => text.to_owned(),
             Statement::PrependPath {
                 paths,
                 key,
@@ -29,6 +31,21 @@ impl Shell for Xonsh {
 
                 format!(r#"${key} = "{}:${orig_key}""#, paths.join(":"))
             }
+            // Variables ending in `PATH` are lists of paths in xonsh,
+            // and f-strings are used to expand variables within each path
+            Statement::RemovePath { paths, key } => {
+                let key = key.unwrap_or("PATH");
+                let env_regex = get_env_var_regex();
+
+                format!(
+                    "${key} = [p for p in ${key} if p not in [{}]]",
+                    paths
+                        .iter()
+                        .map(|p| format!("f{}", self.quote(&env_regex.replace_all(p, "{$$$name}"))))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+            }
             Statement::SetEnv { key, value } => {
                 format!("${key} = {}", self.quote(value))
             }
@@ -92,6 +109,14 @@ mod tests {
         );
     }
 
+    #[test]
+    fn formats_path_remove() {
+        assert_eq!(
+            Xonsh.format_path_remove(&["$PROTO_HOME/shims".into(), "/abs/bin".into()]),
+            r#"$PATH = [p for p in $PATH if p not in [f"{$PROTO_HOME}/shims", f"/abs/bin"]]"#
+        );
+    }
+
     #[test]
     fn formats_path() {
         assert_eq!(
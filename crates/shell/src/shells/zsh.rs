@@ -1,5 +1,5 @@
 use super::Shell;
-use crate::helpers::{is_absolute_dir, normalize_newlines};
+use crate::helpers::{format_posix_path_remove, is_absolute_dir, normalize_newlines};
 use crate::hooks::*;
 use std::env;
 use std::fmt;
@@ -23,6 +23,8 @@ impl Zsh {
 impl Shell for Zsh {
     fn format(&self, statement: Statement<'_>) -> String {
         match statement {
+            Statement::Comment(text) => self.format_comment(text),
+            Statement::Raw(text) => text.to_owned(),
             Statement::PrependPath {
                 paths,
                 key,
@@ -33,6 +35,14 @@ impl Shell for Zsh {
 
                 format!(r#"export {key}="{}:${orig_key}";"#, paths.join(":"))
             }
+            Statement::RemovePath { paths, key } => {
+                let key = key.unwrap_or("PATH");
+
+                format!(
+                    r#"export {key}="{}";"#,
+                    format_posix_path_remove(key, paths)
+                )
+            }
             Statement::SetEnv { key, value } => {
                 format!("export {}={};", self.quote(key), self.quote(value))
             }
@@ -146,6 +156,14 @@ mod tests {
         );
     }
 
+    #[test]
+    fn formats_path_remove() {
+        assert_eq!(
+            Zsh::default().format_path_remove(&["$PROTO_HOME/shims".into(), "/abs/bin".into()]),
+            r#"export PATH="$(printf '%s' "$PATH" | tr ':' '\n' | grep -vxF -e "$PROTO_HOME/shims" -e "/abs/bin" | paste -sd ':' -)";"#
+        );
+    }
+
     #[test]
     fn formats_path() {
         assert_eq!(
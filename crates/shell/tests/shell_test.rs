@@ -17,3 +17,269 @@ fn detects_a_shell_from_os() {
 
     assert!(ShellType::detect().is_some());
 }
+
+mod from_path {
+    use super::*;
+    use std::path::Path;
+
+    #[test]
+    fn unix_paths() {
+        assert_eq!(ShellType::from_path("/bin/zsh"), Some(ShellType::Zsh));
+        assert_eq!(ShellType::from_path("/usr/bin/bash"), Some(ShellType::Bash));
+        assert_eq!(
+            ShellType::from_path(Path::new("/usr/local/bin/fish")),
+            Some(ShellType::Fish)
+        );
+        assert_eq!(
+            ShellType::from_path("/usr/bin/xon.sh"),
+            Some(ShellType::Xonsh)
+        );
+    }
+
+    #[test]
+    fn windows_paths() {
+        assert_eq!(
+            ShellType::from_path(r"C:\Program Files\PowerShell\7\pwsh.exe"),
+            Some(ShellType::Pwsh)
+        );
+        assert_eq!(
+            ShellType::from_path(r"C:\Windows\System32\WindowsPowerShell\v1.0\powershell.EXE"),
+            Some(ShellType::Pwsh)
+        );
+        assert_eq!(ShellType::from_path("nu.exe"), Some(ShellType::Nu));
+    }
+
+    #[test]
+    fn login_and_versioned_names() {
+        assert_eq!(ShellType::from_path("-zsh"), Some(ShellType::Zsh));
+        assert_eq!(
+            ShellType::from_path("/opt/bin/bash-5.2"),
+            Some(ShellType::Bash)
+        );
+        assert_eq!(
+            ShellType::from_path("/usr/bin/bash5"),
+            Some(ShellType::Bash)
+        );
+    }
+
+    #[test]
+    fn unknown_binaries() {
+        assert_eq!(ShellType::from_path("/usr/bin/python3"), None);
+        assert_eq!(ShellType::from_path(r"C:\tools\node.exe"), None);
+        assert_eq!(ShellType::from_path("/usr/bin/5.2"), None);
+        assert_eq!(ShellType::from_path(""), None);
+    }
+}
+
+mod build {
+    use super::*;
+
+    #[test]
+    fn builds_every_variant() {
+        for shell_type in ShellType::variants() {
+            let shell = shell_type.build();
+
+            assert_eq!(shell.to_string(), shell_type.to_string());
+            assert!(shell
+                .format_env_set("STARBASE_KEY", "value")
+                .contains("STARBASE_KEY"));
+        }
+    }
+
+    #[test]
+    fn removes_nothing_without_paths() {
+        for shell_type in ShellType::variants() {
+            assert_eq!(
+                shell_type.build().format_path_remove(&[]),
+                "",
+                "{shell_type}"
+            );
+        }
+    }
+}
+
+mod format_comment {
+    use super::*;
+
+    #[test]
+    fn single_line() {
+        for shell_type in ShellType::variants() {
+            assert_eq!(
+                shell_type.build().format_comment("Added by starbase"),
+                "# Added by starbase"
+            );
+        }
+    }
+
+    #[test]
+    fn multi_line() {
+        for shell_type in ShellType::variants() {
+            assert_eq!(
+                shell_type
+                    .build()
+                    .format_comment("Line one\n\nLine three\n"),
+                "# Line one\n#\n# Line three"
+            );
+        }
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(ShellType::Bash.build().format_comment(""), "");
+    }
+}
+
+mod path_already_modified {
+    use super::*;
+
+    #[test]
+    fn formats_marked_block() {
+        let shell = ShellType::Bash.build();
+
+        assert_eq!(
+            shell.format_marked_block("starbase", "export PATH=\"/bin:$PATH\";\n"),
+            "# >>> starbase >>>\nexport PATH=\"/bin:$PATH\";\n# <<< starbase <<<"
+        );
+    }
+
+    #[test]
+    fn detects_present_marker() {
+        for shell_type in ShellType::variants() {
+            let shell = shell_type.build();
+            let block =
+                shell.format_marked_block("starbase", &shell.format_path_set(&["/bin".into()]));
+            let profile = format!("# Existing content\nalias ll='ls'\n\n{block}\n");
+
+            assert!(shell.path_already_modified(&profile, "starbase"));
+        }
+    }
+
+    #[test]
+    fn ignores_absent_marker() {
+        let shell = ShellType::Zsh.build();
+
+        assert!(!shell.path_already_modified("", "starbase"));
+        assert!(!shell.path_already_modified("export PATH=\"/bin:$PATH\";", "starbase"));
+        assert!(!shell.path_already_modified(
+            &shell.format_marked_block("other", "export PATH=\"/bin:$PATH\";"),
+            "starbase"
+        ));
+    }
+}
+
+mod info {
+    use super::*;
+    use starbase_shell::Hook;
+    use std::path::Path;
+
+    #[test]
+    fn all_matches_shells_module() {
+        // One for each shell exported from the `shells` module
+        assert_eq!(ShellType::all().len(), 10);
+        assert_eq!(ShellType::all(), ShellType::variants());
+    }
+
+    #[test]
+    fn matches_shell_implementations() {
+        for shell_type in ShellType::all() {
+            let info = shell_type.info();
+            let shell = shell_type.build();
+
+            assert_eq!(shell.to_string(), shell_type.to_string());
+            assert_eq!(
+                shell
+                    .format_hook(Hook::OnChangeDir {
+                        command: "starbase".into(),
+                        function: "_starbase_hook".into(),
+                    })
+                    .is_ok(),
+                info.supports_hooks,
+                "{shell_type}"
+            );
+            assert!(
+                shell
+                    .get_profile_paths(Path::new("/home"))
+                    .iter()
+                    .any(|path| path.ends_with(info.profile_file)),
+                "{shell_type}"
+            );
+        }
+    }
+}
+
+mod find_binary {
+    use super::*;
+    use starbase_sandbox::create_empty_sandbox;
+
+    fn create_executable(sandbox: &starbase_sandbox::Sandbox, name: &str) {
+        let name = if cfg!(windows) {
+            format!("{name}.exe")
+        } else {
+            name.to_owned()
+        };
+
+        sandbox.create_file(&name, "");
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+
+            std::fs::set_permissions(
+                sandbox.path().join(name),
+                std::fs::Permissions::from_mode(0o755),
+            )
+            .unwrap();
+        }
+    }
+
+    #[test]
+    #[serial]
+    fn finds_shell_on_path() {
+        let sandbox = create_empty_sandbox();
+        sandbox.create_file("other/zsh", "");
+        create_executable(&sandbox, "bin/zsh");
+
+        let original = env::var_os("PATH");
+
+        env::set_var(
+            "PATH",
+            env::join_paths([sandbox.path().join("other"), sandbox.path().join("bin")]).unwrap(),
+        );
+
+        let path = ShellType::Zsh.find_binary();
+        let missing = ShellType::Fish.find_binary();
+
+        if let Some(original) = original {
+            env::set_var("PATH", original);
+        }
+
+        assert_eq!(
+            path.unwrap(),
+            sandbox.path().join(if cfg!(windows) {
+                "bin/zsh.exe"
+            } else {
+                "bin/zsh"
+            })
+        );
+        assert_eq!(missing, None);
+    }
+
+    #[test]
+    #[serial]
+    fn falls_back_to_windows_powershell() {
+        let sandbox = create_empty_sandbox();
+        create_executable(&sandbox, "powershell");
+
+        let original = env::var_os("PATH");
+
+        env::set_var("PATH", sandbox.path());
+
+        let path = ShellType::Pwsh.find_binary();
+
+        if let Some(original) = original {
+            env::set_var("PATH", original);
+        }
+
+        assert!(path.unwrap().starts_with(sandbox.path()));
+    }
+}
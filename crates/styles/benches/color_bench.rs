@@ -0,0 +1,72 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use starbase_styles::color::{apply_style_tags, write_style_tags};
+
+fn create_messages(tagged: bool) -> Vec<String> {
+    (0..10_000)
+        .map(|index| {
+            if tagged {
+                format!(
+                    "Running task <id>pkg-{index}:build</id> in <path>/packages/pkg-{index}</path>"
+                )
+            } else {
+                format!("Running task pkg-{index}:build in /packages/pkg-{index}")
+            }
+        })
+        .collect()
+}
+
+fn apply_style_tags_benchmark(c: &mut Criterion) {
+    let untagged = create_messages(false);
+    let tagged = create_messages(true);
+
+    let mut group = c.benchmark_group("apply_style_tags");
+
+    group.bench_function("untagged", |b| {
+        b.iter(|| {
+            for message in &untagged {
+                black_box(apply_style_tags(message));
+            }
+        })
+    });
+
+    group.bench_function("tagged", |b| {
+        b.iter(|| {
+            for message in &tagged {
+                black_box(apply_style_tags(message));
+            }
+        })
+    });
+
+    group.finish();
+
+    let mut group = c.benchmark_group("write_style_tags");
+
+    group.bench_function("untagged", |b| {
+        let mut output = String::new();
+
+        b.iter(|| {
+            for message in &untagged {
+                output.clear();
+                write_style_tags(&mut output, message).unwrap();
+                black_box(&output);
+            }
+        })
+    });
+
+    group.bench_function("tagged", |b| {
+        let mut output = String::new();
+
+        b.iter(|| {
+            for message in &tagged {
+                output.clear();
+                write_style_tags(&mut output, message).unwrap();
+                black_box(&output);
+            }
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, apply_style_tags_benchmark);
+criterion_main!(benches);
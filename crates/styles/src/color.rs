@@ -4,8 +4,11 @@
 use owo_colors::{OwoColorize, XtermColors};
 use std::collections::HashMap;
 use std::env;
+use std::fmt;
 use std::path::Path;
-use std::sync::LazyLock;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::{LazyLock, RwLock};
+use unicode_width::UnicodeWidthStr;
 
 pub use owo_colors as owo;
 pub use owo_colors::Style as OwoStyle;
@@ -29,7 +32,7 @@ pub enum Color {
     GrayLight = 246,
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum Style {
     Tag(String),
 
@@ -75,6 +78,35 @@ impl Style {
             Style::Tag(_) => Color::White,
         }
     }
+
+    /// Return the ANSI color code for the style. This is the [color](Style::color)
+    /// by default, unless it has been overridden with [`set_style_color`].
+    pub fn color_code(&self) -> u8 {
+        STYLE_COLORS
+            .read()
+            .unwrap()
+            .get(self)
+            .copied()
+            .unwrap_or_else(|| self.color() as u8)
+    }
+}
+
+static STYLE_COLORS: LazyLock<RwLock<HashMap<Style, u8>>> = LazyLock::new(RwLock::default);
+
+/// Override the ANSI color code of the provided style at runtime, for all painting
+/// functions and the [`Stylize`](crate::Stylize) trait. This can be used for theming.
+pub fn set_style_color(style: Style, color: u8) {
+    STYLE_COLORS.write().unwrap().insert(style, color);
+}
+
+/// Reset the provided style back to its default color.
+pub fn reset_style_color(style: &Style) {
+    STYLE_COLORS.write().unwrap().remove(style);
+}
+
+/// Reset all styles back to their default colors.
+pub fn reset_style_colors() {
+    STYLE_COLORS.write().unwrap().clear();
 }
 
 /// Create a new `owo_colors` [Style][OwoStyle] instance and apply the given color.
@@ -95,35 +127,19 @@ pub fn paint<T: AsRef<str>>(color: u8, value: T) -> String {
 /// Paint the string with the given style.
 pub fn paint_style<T: AsRef<str>>(style: Style, value: T) -> String {
     if matches!(style, Style::File | Style::Path | Style::Shell) {
-        paint(style.color() as u8, clean_path(value.as_ref()))
+        paint(style.color_code(), clean_path(value.as_ref()))
     } else {
-        paint(style.color() as u8, value)
+        paint(style.color_code(), value)
     }
 }
 
-/// Parses a string with HTML-like tags into a list of tagged pieces.
-/// For example: `<file>starbase.json</file>`
-pub fn parse_tags<T: AsRef<str>>(value: T) -> Vec<(String, Option<String>)> {
-    let message = value.as_ref().to_owned();
-
-    if !message.contains('<') {
-        return vec![(message, None)];
-    }
-
-    let mut results: Vec<(String, Option<String>)> = vec![];
-
-    let mut add_result = |text: &str, tag: Option<String>| {
-        if let Some(last) = results.last_mut() {
-            if last.1 == tag {
-                last.0.push_str(text);
-                return;
-            }
-        }
-
-        results.push((text.to_owned(), tag));
-    };
-
-    let mut text = message.as_str();
+// Visit each piece of the string, and the tag it's wrapped in, without allocating
+// the pieces. Pieces may be empty, and adjacent pieces may have the same tag.
+fn visit_tags<'a, F>(message: &'a str, mut visit: F) -> fmt::Result
+where
+    F: FnMut(&'a str, Option<&'a str>) -> fmt::Result,
+{
+    let mut text = message;
     let mut tag_stack = vec![];
 
     while let Some(open_index) = text.find('<') {
@@ -132,7 +148,7 @@ pub fn parse_tags<T: AsRef<str>>(value: T) -> Vec<(String, Option<String>)> {
 
             // Definitely not a tag
             if tag.is_empty() || tag.contains(' ') {
-                add_result(text.get(..=open_index).unwrap(), None);
+                visit(text.get(..=open_index).unwrap(), None)?;
 
                 text = text.get(open_index + 1..).unwrap();
                 continue;
@@ -157,29 +173,56 @@ pub fn parse_tags<T: AsRef<str>>(value: T) -> Vec<(String, Option<String>)> {
                     );
                 }
 
-                add_result(prev_text, Some(tag.to_owned()));
+                visit(prev_text, Some(tag))?;
 
                 tag_stack.pop();
             }
             // Open tag, preserve the current tag
             else {
-                add_result(prev_text, tag_stack.last().cloned());
+                visit(prev_text, tag_stack.last().copied())?;
 
-                tag_stack.push(tag.to_owned());
+                tag_stack.push(tag);
             }
 
             text = text.get(close_index + 1..).unwrap();
         } else {
-            add_result(text.get(..=open_index).unwrap(), None);
+            visit(text.get(..=open_index).unwrap(), None)?;
 
             text = text.get(open_index + 1..).unwrap();
         }
     }
 
     if !text.is_empty() {
-        add_result(text, None);
+        visit(text, None)?;
     }
 
+    Ok(())
+}
+
+/// Parses a string with HTML-like tags into a list of tagged pieces.
+/// For example: `<file>starbase.json</file>`
+pub fn parse_tags<T: AsRef<str>>(value: T) -> Vec<(String, Option<String>)> {
+    let message = value.as_ref();
+
+    if !message.contains('<') {
+        return vec![(message.to_owned(), None)];
+    }
+
+    let mut results: Vec<(String, Option<String>)> = vec![];
+
+    let _ = visit_tags(message, |text, tag| {
+        if let Some(last) = results.last_mut() {
+            if last.1.as_deref() == tag {
+                last.0.push_str(text);
+                return Ok(());
+            }
+        }
+
+        results.push((text.to_owned(), tag.map(|tag| tag.to_owned())));
+
+        Ok(())
+    });
+
     results
         .into_iter()
         .filter(|item| !item.0.is_empty())
@@ -238,6 +281,13 @@ pub fn parse_style_tags<T: AsRef<str>>(value: T) -> Vec<(String, Option<Style>)>
 /// Apply styles to a string by replacing style specific tags.
 /// For example: `<file>starbase.json</file>`
 pub fn apply_style_tags<T: AsRef<str>>(value: T) -> String {
+    let value = value.as_ref();
+
+    // Most strings have no tags, so avoid parsing them
+    if !value.contains('<') {
+        return value.to_owned();
+    }
+
     let mut result = vec![];
 
     for (text, style) in parse_style_tags(value) {
@@ -250,6 +300,71 @@ pub fn apply_style_tags<T: AsRef<str>>(value: T) -> String {
     result.join("")
 }
 
+struct StylePrefix(OwoStyle);
+
+impl fmt::Display for StylePrefix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt_prefix(f)
+    }
+}
+
+struct StyleSuffix(OwoStyle);
+
+impl fmt::Display for StyleSuffix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt_suffix(f)
+    }
+}
+
+/// Apply styles to a string by replacing style specific tags, like [`apply_style_tags`],
+/// but write the result directly to the provided writer, instead of allocating a new
+/// string. Adjacent pieces with the same style share a single escape sequence.
+pub fn write_style_tags<W: fmt::Write + ?Sized>(writer: &mut W, value: &str) -> fmt::Result {
+    if !value.contains('<') {
+        return writer.write_str(value);
+    }
+
+    let with_color = !no_color();
+    let mut current: Option<Style> = None;
+
+    visit_tags(value, |text, tag| {
+        if text.is_empty() {
+            return Ok(());
+        }
+
+        let style = tag.map(|tag| {
+            TAGS_MAP
+                .get(tag)
+                .cloned()
+                .unwrap_or_else(|| panic!("Unknown tag `{}`!", tag))
+        });
+
+        if with_color && style != current {
+            if let Some(prev) = &current {
+                write!(writer, "{}", StyleSuffix(create_style(prev.color_code())))?;
+            }
+
+            if let Some(next) = &style {
+                write!(writer, "{}", StylePrefix(create_style(next.color_code())))?;
+            }
+
+            current = style.clone();
+        }
+
+        if matches!(style, Some(Style::File | Style::Path | Style::Shell)) {
+            writer.write_str(&clean_path(text))
+        } else {
+            writer.write_str(text)
+        }
+    })?;
+
+    if let Some(prev) = &current {
+        write!(writer, "{}", StyleSuffix(create_style(prev.color_code())))?;
+    }
+
+    Ok(())
+}
+
 // States
 
 /// Paint a caution state.
@@ -371,15 +486,220 @@ pub fn log_target<T: AsRef<str>>(value: T) -> String {
     paint(COLOR_LIST_UNSUPPORTED[index], value)
 }
 
+// Return the byte length of the ANSI escape sequence at the start of the string,
+// supporting CSI (`ESC [ ... final`) and OSC (`ESC ] ... BEL/ST`) sequences.
+fn escape_len(value: &str) -> Option<usize> {
+    let bytes = value.as_bytes();
+
+    if bytes.first() != Some(&0x1b) {
+        return None;
+    }
+
+    match bytes.get(1) {
+        Some(b'[') => bytes[2..]
+            .iter()
+            .position(|byte| (0x40..=0x7e).contains(byte))
+            .map(|index| index + 3),
+        Some(b']') => bytes[2..]
+            .iter()
+            .enumerate()
+            .position(|(index, byte)| *byte == 0x07 || (*byte == b'\\' && bytes[index + 1] == 0x1b))
+            .map(|index| index + 3),
+        Some(byte) if byte.is_ascii() => Some(2),
+        _ => Some(1),
+    }
+    // Unterminated sequences consume the rest of the string
+    .or(Some(bytes.len()))
+}
+
+/// Split the string into segments of text and ANSI escape sequences, where
+/// the boolean is true for escape sequences.
+pub fn ansi_segments(value: &str) -> impl Iterator<Item = (&str, bool)> {
+    let mut rest = value;
+
+    std::iter::from_fn(move || {
+        if rest.is_empty() {
+            return None;
+        }
+
+        let (segment, is_escape) = match escape_len(rest) {
+            Some(len) => (&rest[..len], true),
+            None => (&rest[..rest.find('\x1b').unwrap_or(rest.len())], false),
+        };
+
+        rest = &rest[segment.len()..];
+
+        Some((segment, is_escape))
+    })
+}
+
+/// Return the number of columns the string occupies when displayed in a
+/// terminal, ignoring ANSI escape sequences, and accounting for wide
+/// characters (e.g. CJK and emoji).
+pub fn display_width<T: AsRef<str>>(value: T) -> usize {
+    ansi_segments(value.as_ref())
+        .filter(|(_, is_escape)| !is_escape)
+        .map(|(text, _)| UnicodeWidthStr::width(text))
+        .sum()
+}
+
+// The 16 system colors, based on the xterm defaults
+const SYSTEM_COLORS: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (205, 0, 0),
+    (0, 205, 0),
+    (205, 205, 0),
+    (0, 0, 238),
+    (205, 0, 205),
+    (0, 205, 205),
+    (229, 229, 229),
+    (127, 127, 127),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (92, 92, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
+
+// The channel levels of the 6x6x6 color cube (16-231)
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+fn color_distance(a: (u8, u8, u8), b: (u8, u8, u8)) -> u32 {
+    let dr = a.0 as i32 - b.0 as i32;
+    let dg = a.1 as i32 - b.1 as i32;
+    let db = a.2 as i32 - b.2 as i32;
+
+    (dr * dr + dg * dg + db * db) as u32
+}
+
+/// Convert an xterm 256 color index into its RGB values.
+pub fn ansi256_to_rgb(index: u8) -> (u8, u8, u8) {
+    match index {
+        0..=15 => SYSTEM_COLORS[index as usize],
+        16..=231 => {
+            let index = index - 16;
+
+            (
+                CUBE_LEVELS[(index / 36) as usize],
+                CUBE_LEVELS[((index / 6) % 6) as usize],
+                CUBE_LEVELS[(index % 6) as usize],
+            )
+        }
+        232..=255 => {
+            let level = 8 + (index - 232) * 10;
+
+            (level, level, level)
+        }
+    }
+}
+
+/// Convert a true-color RGB value into the nearest xterm 256 color index,
+/// by comparing the closest color in the 6x6x6 color cube (16-231) with the
+/// closest color in the grayscale ramp (232-255). The 16 system colors are
+/// never returned, as they can be customized by the terminal.
+pub fn rgb_to_ansi256(r: u8, g: u8, b: u8) -> u8 {
+    let to_cube = |value: u8| -> u8 {
+        match value {
+            0..48 => 0,
+            48..115 => 1,
+            _ => (value - 35) / 40,
+        }
+    };
+
+    let cube_index = 16 + 36 * to_cube(r) + 6 * to_cube(g) + to_cube(b);
+
+    let average = ((r as u16 + g as u16 + b as u16) / 3) as u8;
+    let gray_index = if average > 238 {
+        255
+    } else {
+        232 + average.saturating_sub(3) / 10
+    };
+
+    if color_distance((r, g, b), ansi256_to_rgb(gray_index))
+        < color_distance((r, g, b), ansi256_to_rgb(cube_index))
+    {
+        gray_index
+    } else {
+        cube_index
+    }
+}
+
+/// Process-wide mode that controls whether colors are applied.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ColorMode {
+    /// Detect support from the `NO_COLOR` and `FORCE_COLOR`
+    /// environment variables, and whether `stderr` is a TTY.
+    #[default]
+    Auto,
+    /// Always apply colors.
+    Always,
+    /// Never apply colors.
+    Never,
+}
+
+static COLOR_MODE: AtomicU8 = AtomicU8::new(0);
+
+/// Return the current process-wide [`ColorMode`].
+pub fn get_color_mode() -> ColorMode {
+    match COLOR_MODE.load(Ordering::Relaxed) {
+        1 => ColorMode::Always,
+        2 => ColorMode::Never,
+        _ => ColorMode::Auto,
+    }
+}
+
+/// Set the process-wide [`ColorMode`]. This applies to all painting functions
+/// and the [`Stylize`](crate::Stylize) trait.
+pub fn set_color_mode(mode: ColorMode) {
+    COLOR_MODE.store(
+        match mode {
+            ColorMode::Auto => 0,
+            ColorMode::Always => 1,
+            ColorMode::Never => 2,
+        },
+        Ordering::Relaxed,
+    );
+}
+
+// Return whether the `FORCE_COLOR` environment variable forces color on
+// (any non-empty value) or off (`0` or `false`), or `None` if not set.
+fn force_color() -> Option<bool> {
+    match env::var("FORCE_COLOR") {
+        Ok(value) if !value.is_empty() => Some(value != "0" && value != "false"),
+        _ => None,
+    }
+}
+
 /// Return true if color has been disabled for the `stderr` stream.
 #[cfg(not(target_arch = "wasm32"))]
 pub fn no_color() -> bool {
-    env::var("NO_COLOR").is_ok() || supports_color::on(supports_color::Stream::Stderr).is_none()
+    match get_color_mode() {
+        ColorMode::Always => false,
+        ColorMode::Never => true,
+        ColorMode::Auto => {
+            if env::var("NO_COLOR").is_ok() {
+                return true;
+            }
+
+            if let Some(force) = force_color() {
+                return !force;
+            }
+
+            supports_color::on(supports_color::Stream::Stderr).is_none()
+        }
+    }
 }
 
+/// Return true if color has been disabled for the `stderr` stream.
 #[cfg(target_arch = "wasm32")]
 pub fn no_color() -> bool {
-    true
+    match get_color_mode() {
+        ColorMode::Always => false,
+        ColorMode::Never => true,
+        ColorMode::Auto => env::var("NO_COLOR").is_ok() || force_color() != Some(true),
+    }
 }
 
 /// Return a color level support for the `stderr` stream. 0 = no support, 1 = basic support,
@@ -1,8 +1,14 @@
-use starbase_styles::color::{apply_style_tags, parse_style_tags};
-use starbase_styles::Style;
+use serial_test::serial;
+use starbase_styles::color::{
+    ansi256_to_rgb, ansi_segments, apply_style_tags, display_width, get_color_mode, paint_style,
+    parse_style_tags, reset_style_color, reset_style_colors, rgb_to_ansi256, set_color_mode,
+    set_style_color, write_style_tags, ColorMode,
+};
+use starbase_styles::{Style, Stylize};
 use std::env;
 
 #[test]
+#[serial]
 fn replaces_tags() {
     env::set_var("FORCE_COLOR", "1");
     env::remove_var("NO_COLOR");
@@ -10,6 +16,304 @@ fn replaces_tags() {
     assert_eq!(apply_style_tags("this <file>is</file> a <caution>string <property>with</property></caution> many <success>style</success> tags!"), "this \u{1b}[38;5;36mis\u{1b}[0m a \u{1b}[38;5;208mstring \u{1b}[0m\u{1b}[38;5;147mwith\u{1b}[0m many \u{1b}[38;5;41mstyle\u{1b}[0m tags!");
 }
 
+mod apply_style_tags {
+    use super::*;
+
+    #[test]
+    #[serial]
+    fn returns_untagged_as_is() {
+        env::remove_var("NO_COLOR");
+        env::set_var("FORCE_COLOR", "1");
+
+        assert_eq!(apply_style_tags(""), "");
+        assert_eq!(apply_style_tags("no tags > here"), "no tags > here");
+        assert_eq!(
+            apply_style_tags("/some/path/file.txt"),
+            "/some/path/file.txt"
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn applies_tagged() {
+        env::remove_var("NO_COLOR");
+        env::set_var("FORCE_COLOR", "1");
+
+        assert_eq!(
+            apply_style_tags("<id>id</id>"),
+            "\u{1b}[38;5;111mid\u{1b}[0m"
+        );
+        assert_eq!(apply_style_tags("a < b"), "a < b");
+        assert_eq!(apply_style_tags("a <"), "a <");
+    }
+}
+
+mod write_style_tags {
+    use super::*;
+
+    const INPUTS: [&str; 6] = [
+        "no tags",
+        "<file>file</file>",
+        "this <file>is</file> a <caution>string <property>with</property></caution> many <success>style</success> tags!",
+        "<id>a</id> < b and c > d",
+        "unclosed <",
+        "",
+    ];
+
+    fn write(value: &str) -> String {
+        let mut output = String::new();
+        write_style_tags(&mut output, value).unwrap();
+        output
+    }
+
+    #[test]
+    #[serial]
+    fn matches_apply_with_color() {
+        env::remove_var("NO_COLOR");
+        env::set_var("FORCE_COLOR", "1");
+
+        for input in INPUTS {
+            assert_eq!(write(input), apply_style_tags(input));
+        }
+    }
+
+    #[test]
+    #[serial]
+    fn matches_apply_without_color() {
+        set_color_mode(ColorMode::Never);
+
+        let results = INPUTS
+            .into_iter()
+            .map(|input| (write(input), apply_style_tags(input)))
+            .collect::<Vec<_>>();
+
+        set_color_mode(ColorMode::Auto);
+
+        for (written, applied) in results {
+            assert_eq!(written, applied);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Unknown tag `unknown`!")]
+    fn errors_unknown_tag() {
+        write("<unknown>value</unknown>");
+    }
+}
+
+mod color_mode {
+    use super::*;
+
+    #[test]
+    #[serial]
+    fn defaults_to_auto() {
+        assert_eq!(get_color_mode(), ColorMode::Auto);
+    }
+
+    #[test]
+    #[serial]
+    fn always_applies_color() {
+        env::set_var("NO_COLOR", "1");
+        set_color_mode(ColorMode::Always);
+
+        let value = "id".style(Style::Id);
+
+        set_color_mode(ColorMode::Auto);
+        env::remove_var("NO_COLOR");
+
+        assert_eq!(value, "\u{1b}[38;5;111mid\u{1b}[0m");
+    }
+
+    #[test]
+    #[serial]
+    fn never_applies_color() {
+        env::set_var("FORCE_COLOR", "1");
+        set_color_mode(ColorMode::Never);
+
+        let value = "id".style(Style::Id);
+
+        set_color_mode(ColorMode::Auto);
+
+        assert_eq!(value, "id");
+    }
+
+    #[test]
+    #[serial]
+    fn auto_respects_no_color() {
+        env::set_var("FORCE_COLOR", "1");
+        env::set_var("NO_COLOR", "1");
+
+        let value = "id".style(Style::Id);
+
+        env::remove_var("NO_COLOR");
+
+        assert_eq!(value, "id");
+    }
+
+    #[test]
+    #[serial]
+    fn auto_respects_force_color() {
+        env::remove_var("NO_COLOR");
+        env::set_var("FORCE_COLOR", "1");
+
+        assert_eq!("id".style(Style::Id), "\u{1b}[38;5;111mid\u{1b}[0m");
+    }
+
+    #[test]
+    #[serial]
+    fn auto_respects_force_color_disabled() {
+        env::remove_var("NO_COLOR");
+
+        for value in ["0", "false"] {
+            env::set_var("FORCE_COLOR", value);
+
+            assert_eq!("id".style(Style::Id), "id");
+        }
+
+        env::set_var("FORCE_COLOR", "1");
+    }
+}
+
+mod style_colors {
+    use super::*;
+
+    #[test]
+    #[serial]
+    fn uses_default_color() {
+        env::remove_var("NO_COLOR");
+        env::set_var("FORCE_COLOR", "1");
+
+        assert_eq!(Style::File.color_code(), 36);
+        assert_eq!(
+            paint_style(Style::File, "file"),
+            "\u{1b}[38;5;36mfile\u{1b}[0m"
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn can_override_color() {
+        env::remove_var("NO_COLOR");
+        env::set_var("FORCE_COLOR", "1");
+
+        set_style_color(Style::File, 200);
+
+        let painted = paint_style(Style::File, "file");
+        let stylized = "file".style(Style::File);
+        let tagged = apply_style_tags("<file>file</file>");
+        let other = "id".style(Style::Id);
+
+        reset_style_colors();
+
+        assert_eq!(painted, "\u{1b}[38;5;200mfile\u{1b}[0m");
+        assert_eq!(stylized, painted);
+        assert_eq!(tagged, painted);
+        assert_eq!(other, "\u{1b}[38;5;111mid\u{1b}[0m");
+    }
+
+    #[test]
+    #[serial]
+    fn can_reset_color() {
+        set_style_color(Style::Id, 1);
+        set_style_color(Style::File, 2);
+        reset_style_color(&Style::Id);
+
+        let id = Style::Id.color_code();
+        let file = Style::File.color_code();
+
+        reset_style_colors();
+
+        assert_eq!(id, 111);
+        assert_eq!(file, 2);
+        assert_eq!(Style::File.color_code(), 36);
+    }
+}
+
+mod display_width {
+    use super::*;
+
+    #[test]
+    fn measures_plain() {
+        assert_eq!(display_width(""), 0);
+        assert_eq!(display_width("hello"), 5);
+    }
+
+    #[test]
+    fn measures_wide_chars() {
+        assert_eq!(display_width("日本語"), 6);
+    }
+
+    #[test]
+    fn ignores_escapes() {
+        assert_eq!(display_width("\x1b[38;5;111mid\x1b[0m"), 2);
+        assert_eq!(
+            display_width("\x1b]8;;https://moonrepo.dev\x07link\x1b]8;;\x07"),
+            4
+        );
+        assert_eq!(display_width("\x1b]52;c;abc\x1b\\text"), 4);
+    }
+
+    #[test]
+    fn splits_segments() {
+        assert_eq!(
+            ansi_segments("a\x1b[1mb\x1b[0m").collect::<Vec<_>>(),
+            vec![
+                ("a", false),
+                ("\x1b[1m", true),
+                ("b", false),
+                ("\x1b[0m", true)
+            ]
+        );
+    }
+}
+
+mod ansi256 {
+    use super::*;
+
+    #[test]
+    fn converts_index_to_rgb() {
+        assert_eq!(ansi256_to_rgb(1), (205, 0, 0));
+        assert_eq!(ansi256_to_rgb(16), (0, 0, 0));
+        assert_eq!(ansi256_to_rgb(196), (255, 0, 0));
+        assert_eq!(ansi256_to_rgb(39), (0, 175, 255));
+        assert_eq!(ansi256_to_rgb(231), (255, 255, 255));
+        assert_eq!(ansi256_to_rgb(232), (8, 8, 8));
+        assert_eq!(ansi256_to_rgb(255), (238, 238, 238));
+    }
+
+    #[test]
+    fn converts_primary_colors() {
+        assert_eq!(rgb_to_ansi256(255, 0, 0), 196);
+        assert_eq!(rgb_to_ansi256(0, 255, 0), 46);
+        assert_eq!(rgb_to_ansi256(0, 0, 255), 21);
+        assert_eq!(rgb_to_ansi256(0, 0, 0), 16);
+        assert_eq!(rgb_to_ansi256(255, 255, 255), 231);
+    }
+
+    #[test]
+    fn converts_grayscale_ramp() {
+        assert_eq!(rgb_to_ansi256(8, 8, 8), 232);
+        assert_eq!(rgb_to_ansi256(128, 128, 128), 244);
+        assert_eq!(rgb_to_ansi256(238, 238, 238), 255);
+        assert_eq!(rgb_to_ansi256(130, 128, 126), 244);
+    }
+
+    #[test]
+    fn converts_to_nearest_color() {
+        assert_eq!(rgb_to_ansi256(250, 10, 5), 196);
+        assert_eq!(rgb_to_ansi256(0, 170, 250), 39);
+    }
+
+    #[test]
+    fn round_trips_extended_colors() {
+        for index in 16..=255 {
+            let (r, g, b) = ansi256_to_rgb(index);
+
+            assert_eq!(rgb_to_ansi256(r, g, b), index);
+        }
+    }
+}
+
 mod parse_tags {
     use super::*;
 
@@ -0,0 +1,82 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use starbase_sandbox::{create_empty_sandbox, Sandbox};
+use starbase_utils::glob::{walk_with_options, GlobSet, GlobWalkOptions};
+
+fn create_paths() -> Vec<String> {
+    (0..50_000)
+        .map(|index| match index % 4 {
+            0 => format!("packages/pkg-{index}/src/index.ts"),
+            1 => format!("packages/pkg-{index}/src/index.test.ts"),
+            2 => format!("packages/pkg-{index}/node_modules/dep/index.js"),
+            _ => format!("packages/pkg-{index}/README.md"),
+        })
+        .collect()
+}
+
+fn matches_benchmark(c: &mut Criterion) {
+    let paths = create_paths();
+    let set = GlobSet::new(["**/*.{ts,js}", "!**/*.test.ts"]).unwrap();
+
+    let mut group = c.benchmark_group("glob_set");
+
+    group.bench_function("matches", |b| {
+        b.iter(|| {
+            black_box(
+                paths
+                    .iter()
+                    .map(|path| set.matches(path))
+                    .collect::<Vec<_>>(),
+            )
+        })
+    });
+
+    group.bench_function("matches_many", |b| {
+        b.iter(|| black_box(set.matches_many(&paths)))
+    });
+
+    group.finish();
+}
+
+fn create_partitions() -> (Sandbox, Vec<String>) {
+    let sandbox = create_empty_sandbox();
+    let mut patterns = vec![];
+
+    for partition in 0..16 {
+        for index in 0..250 {
+            sandbox.create_file(format!("pkg-{partition}/src/file-{index}.ts"), "");
+        }
+
+        patterns.push(format!("pkg-{partition}/**/*.ts"));
+    }
+
+    (sandbox, patterns)
+}
+
+fn walk_benchmark(c: &mut Criterion) {
+    let (sandbox, patterns) = create_partitions();
+
+    let mut group = c.benchmark_group("glob_walk");
+
+    for parallel in [false, true] {
+        group.bench_function(if parallel { "parallel" } else { "sequential" }, |b| {
+            b.iter(|| {
+                black_box(
+                    walk_with_options(
+                        sandbox.path(),
+                        &patterns,
+                        GlobWalkOptions {
+                            parallel,
+                            ..GlobWalkOptions::default()
+                        },
+                    )
+                    .unwrap(),
+                )
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, matches_benchmark, walk_benchmark);
+criterion_main!(benches);
@@ -11,6 +11,59 @@ pub fn paths() -> Vec<PathBuf> {
     env::split_paths(&path).collect::<Vec<_>>()
 }
 
+/// Substitute all `${VAR}` references in the provided value using the provided
+/// lookup function. References without a value (the lookup returned `None`)
+/// are left as-is. Use [`lookup_var`] to substitute from the current process.
+pub fn interpolate<F: Fn(&str) -> Option<String>>(value: &str, lookup: F) -> String {
+    let mut output = String::with_capacity(value.len());
+    let mut rest = value;
+
+    while let Some(start) = rest.find("${") {
+        let Some(end) = rest[start..].find('}') else {
+            break;
+        };
+
+        let name = &rest[start + 2..start + end];
+
+        output.push_str(&rest[..start]);
+
+        match lookup(name) {
+            Some(var) if !name.is_empty() => output.push_str(&var),
+            _ => output.push_str(&rest[start..=start + end]),
+        };
+
+        rest = &rest[start + end + 1..];
+    }
+
+    output.push_str(rest);
+    output
+}
+
+/// A tree of parsed values (JSON, TOML, YAML, etc) that contains strings.
+#[cfg(any(feature = "json", feature = "toml", feature = "yaml"))]
+pub(crate) trait StringVisitor {
+    /// Call the provided function with every string value in the tree, recursively.
+    fn visit_strings(&mut self, op: &mut dyn FnMut(&mut String));
+}
+
+/// Substitute all `${VAR}` references in every string value of the tree,
+/// using [`interpolate`].
+#[cfg(any(feature = "json", feature = "toml", feature = "yaml"))]
+pub(crate) fn interpolate_strings<T, F>(tree: &mut T, lookup: F)
+where
+    T: StringVisitor,
+    F: Fn(&str) -> Option<String>,
+{
+    tree.visit_strings(&mut |value| *value = interpolate(value, &lookup));
+}
+
+/// Lookup an environment variable from the current process,
+/// and return its value if it exists and is valid unicode.
+#[inline]
+pub fn lookup_var(key: &str) -> Option<String> {
+    env::var(key).ok()
+}
+
 /// Return an environment variable as a boolean value. If the value is a `1`, `true`,
 /// `yes`, `on`, or `enable`, return true, otherwise return false for all other cases.
 pub fn bool_var(key: &str) -> bool {
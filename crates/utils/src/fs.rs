@@ -1,6 +1,7 @@
+use crate::cancel::CancellationToken;
 use std::cmp;
 use std::ffi::OsStr;
-use std::fmt::Debug;
+use std::fmt::{self, Debug};
 use std::fs::{self, File, OpenOptions};
 use std::path::{Path, PathBuf};
 use std::time::{Duration, SystemTime};
@@ -111,6 +112,92 @@ pub fn copy_dir_all<R: AsRef<Path> + Debug, F: AsRef<Path> + Debug, T: AsRef<Pat
     Ok(())
 }
 
+fn copy_dir_all_cancellable(
+    from_root: &Path,
+    from: &Path,
+    to_root: &Path,
+    token: &CancellationToken,
+    created: &mut Vec<PathBuf>,
+) -> Result<(), FsError> {
+    let to = to_root.join(from.strip_prefix(from_root).unwrap());
+    let mut dirs = vec![];
+
+    if !to.exists() {
+        created.push(to);
+    }
+
+    for entry in read_dir(from)? {
+        if token.is_cancelled() {
+            return Err(FsError::Cancelled {
+                path: from.to_path_buf(),
+            });
+        }
+
+        if let Ok(file_type) = entry.file_type() {
+            if file_type.is_file() {
+                let path = entry.path();
+                let to_path = to_root.join(path.strip_prefix(from_root).unwrap());
+
+                if !to_path.exists() {
+                    created.push(to_path.clone());
+                }
+
+                copy_file(&path, to_path)?;
+            } else if file_type.is_dir() {
+                dirs.push(entry.path());
+            }
+        }
+    }
+
+    for dir in dirs {
+        copy_dir_all_cancellable(from_root, &dir, to_root, token, created)?;
+    }
+
+    Ok(())
+}
+
+/// Copy a directory and all of its contents from source to destination, like
+/// [`copy_dir_all`], but check the provided token between each file. If the token
+/// is cancelled, a [`FsError::Cancelled`] error is returned, and all files and
+/// directories created by the copy are removed. Pre-existing files are left as-is.
+#[instrument(skip(token))]
+pub fn copy_dir_all_with_cancel<
+    R: AsRef<Path> + Debug,
+    F: AsRef<Path> + Debug,
+    T: AsRef<Path> + Debug,
+>(
+    from_root: R,
+    from: F,
+    to_root: T,
+    token: &CancellationToken,
+) -> Result<(), FsError> {
+    let from_root = from_root.as_ref();
+    let from = from.as_ref();
+    let to_root = to_root.as_ref();
+    let mut created = vec![];
+
+    trace!(
+        from = ?from,
+        to = ?to_root,
+        "Copying directory (cancellable)"
+    );
+
+    let result = copy_dir_all_cancellable(from_root, from, to_root, token, &mut created);
+
+    if matches!(result, Err(FsError::Cancelled { .. })) {
+        trace!(
+            to = ?to_root,
+            "Copy was cancelled, removing copied files"
+        );
+
+        for path in created.iter().rev() {
+            let _ = remove(path);
+        }
+    }
+
+    result
+}
+
 /// Create a file and return a [`File`] instance. If the parent directory does not exist,
 /// it will be created.
 #[inline]
@@ -177,9 +264,56 @@ pub fn create_dir_all<T: AsRef<Path> + Debug>(path: T) -> Result<(), FsError> {
     Ok(())
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IndentationKind {
+    Spaces,
+    Tabs,
+}
+
+/// Indentation style and width, as detected by [`detect_indentation_info`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Indentation {
+    pub kind: IndentationKind,
+    pub width: usize,
+}
+
+impl Indentation {
+    /// Return the indentation as a string of spaces or tabs.
+    pub fn as_string(&self) -> String {
+        match self.kind {
+            IndentationKind::Spaces => " ".repeat(self.width),
+            IndentationKind::Tabs => "\t".repeat(self.width),
+        }
+    }
+}
+
+impl fmt::Display for Indentation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self.kind {
+            IndentationKind::Spaces => "space",
+            IndentationKind::Tabs => "tab",
+        };
+
+        write!(
+            f,
+            "{} {label}{}",
+            self.width,
+            if self.width == 1 { "" } else { "s" }
+        )
+    }
+}
+
 /// Detect the indentation of the provided string, by scanning and comparing each line.
 #[instrument(skip(content))]
 pub fn detect_indentation<T: AsRef<str>>(content: T) -> String {
+    detect_indentation_info(content).as_string()
+}
+
+/// Detect the indentation style and width of the provided string, by scanning
+/// and comparing each line. Lines with an odd number of spaces are ignored,
+/// so that comments don't skew the result. Defaults to 2 spaces.
+#[instrument(skip(content))]
+pub fn detect_indentation_info<T: AsRef<str>>(content: T) -> Indentation {
     let mut spaces = 0;
     let mut tabs = 0;
     let mut lowest_space_width = 0;
@@ -225,9 +359,15 @@ pub fn detect_indentation<T: AsRef<str>>(content: T) -> String {
     }
 
     if tabs > spaces {
-        "\t".repeat(cmp::max(lowest_tab_width, 1))
+        Indentation {
+            kind: IndentationKind::Tabs,
+            width: cmp::max(lowest_tab_width, 1),
+        }
     } else {
-        " ".repeat(cmp::max(lowest_space_width, 2))
+        Indentation {
+            kind: IndentationKind::Spaces,
+            width: cmp::max(lowest_space_width, 2),
+        }
     }
 }
 
@@ -329,6 +469,16 @@ impl EditorConfigProps {
             data.push_str(&self.eof);
         }
     }
+
+    // Some serializers always append a trailing newline, so strip it
+    // when a final newline has been disabled.
+    pub(crate) fn apply_serialized_eof(&self, data: &mut String) {
+        if self.eof.is_empty() {
+            data.truncate(data.trim_end_matches(['\r', '\n']).len());
+        } else {
+            self.apply_eof(data);
+        }
+    }
 }
 
 /// Load properties from the closest `.editorconfig` file.
@@ -377,6 +527,154 @@ pub fn get_editor_config_props<T: AsRef<Path> + Debug>(
     })
 }
 
+/// Hash the contents of the file at the provided path using SHA-256,
+/// and return the digest as a lowercase hexadecimal string.
+#[cfg(feature = "fs-hash")]
+#[inline]
+#[instrument]
+pub fn hash_file<T: AsRef<Path> + Debug>(path: T) -> Result<String, FsError> {
+    use sha2::{Digest, Sha256};
+
+    let path = path.as_ref();
+
+    trace!(file = ?path, "Hashing file");
+
+    let mut file = open_file(path)?;
+    let mut hasher = Sha256::new();
+
+    std::io::copy(&mut file, &mut hasher).map_err(|error| FsError::Read {
+        path: path.to_path_buf(),
+        error: Box::new(error),
+    })?;
+
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+/// Options to customize the hashing of [`hash_dir`].
+#[cfg(all(feature = "fs-hash", feature = "glob"))]
+#[derive(Clone, Debug)]
+pub struct HashDirOptions {
+    /// Glob patterns of files to include in the hash, relative to the directory.
+    /// Global negations are always applied. Defaults to all files.
+    pub patterns: Vec<String>,
+
+    /// Include the last modified time of each file in the hash.
+    pub include_mtime: bool,
+
+    /// Include the permissions of each file in the hash.
+    pub include_permissions: bool,
+}
+
+#[cfg(all(feature = "fs-hash", feature = "glob"))]
+impl Default for HashDirOptions {
+    fn default() -> Self {
+        Self {
+            patterns: vec!["**/*".into()],
+            include_mtime: false,
+            include_permissions: false,
+        }
+    }
+}
+
+/// Hash the contents and layout of the directory at the provided path using SHA-256,
+/// and return the digest as a lowercase hexadecimal string. Files are walked with
+/// the provided glob patterns, and the relative path and content hash of each file
+/// is included in a deterministic (sorted) order, so the digest is stable across
+/// runs and platforms.
+#[cfg(all(feature = "fs-hash", feature = "glob"))]
+#[instrument]
+pub fn hash_dir<T: AsRef<Path> + Debug>(
+    path: T,
+    options: HashDirOptions,
+) -> Result<String, crate::glob::GlobError> {
+    use sha2::{Digest, Sha256};
+
+    let path = path.as_ref();
+
+    trace!(dir = ?path, "Hashing directory");
+
+    let mut files = crate::glob::walk_files(path, &options.patterns)?
+        .into_iter()
+        .filter_map(|file| {
+            let rel_path = file
+                .strip_prefix(path)
+                .ok()?
+                .to_string_lossy()
+                .replace('\\', "/");
+
+            Some((rel_path, file))
+        })
+        .collect::<Vec<_>>();
+
+    files.sort_by(|a, b| a.0.cmp(&b.0));
+    files.dedup_by(|a, b| a.0 == b.0);
+
+    let mut hasher = Sha256::new();
+
+    for (rel_path, file) in files {
+        hasher.update(rel_path.as_bytes());
+        hasher.update([0]);
+        hasher.update(hash_file(&file)?.as_bytes());
+
+        if options.include_mtime || options.include_permissions {
+            let meta = metadata(&file)?;
+
+            if options.include_mtime {
+                let mtime = meta
+                    .modified()
+                    .ok()
+                    .and_then(|time| time.duration_since(SystemTime::UNIX_EPOCH).ok())
+                    .unwrap_or_default();
+
+                hasher.update([0]);
+                hasher.update(mtime.as_nanos().to_le_bytes());
+            }
+
+            if options.include_permissions {
+                #[cfg(unix)]
+                let mode = {
+                    use std::os::unix::fs::PermissionsExt;
+
+                    meta.permissions().mode()
+                };
+
+                #[cfg(not(unix))]
+                let mode = meta.permissions().readonly() as u32;
+
+                hasher.update([0]);
+                hasher.update(mode.to_le_bytes());
+            }
+        }
+
+        hasher.update([b'\n']);
+    }
+
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+/// Return true if the directory at the provided path is empty (has no entries),
+/// otherwise false. If the path is not a directory, an error is returned.
+#[inline]
+#[instrument]
+pub fn is_dir_empty<T: AsRef<Path> + Debug>(path: T) -> Result<bool, FsError> {
+    let path = path.as_ref();
+
+    if path.exists() && !path.is_dir() {
+        return Err(FsError::RequireDir {
+            path: path.to_path_buf(),
+        });
+    }
+
+    trace!(dir = ?path, "Checking if directory is empty");
+
+    let mut entries = fs::read_dir(path).map_err(|error| FsError::Read {
+        path: path.to_path_buf(),
+        error: Box::new(error),
+    })?;
+
+    Ok(entries.next().is_none())
+}
+
 /// Check if the provided path is a stale file, by comparing modified, created, or accessed
 /// timestamps against the current timestamp and duration. If stale, return the file size
 /// and timestamp, otherwise return `None`.
@@ -424,6 +722,60 @@ pub fn metadata<T: AsRef<Path> + Debug>(path: T) -> Result<fs::Metadata, FsError
     })
 }
 
+/// Line ending styles.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum LineEnding {
+    /// `\n`, used by Unix-like systems.
+    #[default]
+    Lf,
+    /// `\r\n`, used by Windows.
+    Crlf,
+}
+
+impl LineEnding {
+    /// Return the line ending for the current operating system.
+    pub fn native() -> Self {
+        if cfg!(windows) {
+            Self::Crlf
+        } else {
+            Self::Lf
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Lf => "\n",
+            Self::Crlf => "\r\n",
+        }
+    }
+}
+
+/// Normalize all line endings in the provided content (`\r\n`, `\n`, and
+/// lone `\r`) to the provided line ending style.
+pub fn normalize_line_endings<T: AsRef<str>>(content: T, style: LineEnding) -> String {
+    let content = content.as_ref();
+    let eol = style.as_str();
+    let mut output = String::with_capacity(content.len());
+    let mut chars = content.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        match ch {
+            '\r' => {
+                chars.next_if_eq(&'\n');
+                output.push_str(eol);
+            }
+            '\n' => {
+                output.push_str(eol);
+            }
+            _ => {
+                output.push(ch);
+            }
+        };
+    }
+
+    output
+}
+
 /// Open a file at the provided path and return a [`File`] instance.
 /// The path must already exist.
 #[inline]
@@ -475,6 +827,46 @@ pub fn read_dir<T: AsRef<Path> + Debug>(path: T) -> Result<Vec<fs::DirEntry>, Fs
     Ok(results)
 }
 
+/// Read direct contents for the provided directory path, sorted alphabetically
+/// by file name, so that the order is deterministic across platforms. If `dirs_first`
+/// is true, directories are listed before files. If the directory does not exist,
+/// an empty vector is returned.
+#[inline]
+#[instrument]
+pub fn read_dir_sorted<T: AsRef<Path> + Debug>(
+    path: T,
+    dirs_first: bool,
+) -> Result<Vec<fs::DirEntry>, FsError> {
+    let mut results = read_dir(path)?;
+
+    results.sort_by_cached_key(|entry| {
+        (
+            !(dirs_first && entry.file_type().is_ok_and(|file_type| file_type.is_dir())),
+            entry.file_name(),
+        )
+    });
+
+    Ok(results)
+}
+
+/// Read direct contents for the provided directory path, and only include
+/// entries that pass the provided predicate. If the directory does not exist,
+/// an empty vector is returned.
+#[inline]
+#[instrument(skip(predicate))]
+pub fn read_dir_filtered<T, F>(path: T, predicate: F) -> Result<Vec<fs::DirEntry>, FsError>
+where
+    T: AsRef<Path> + Debug,
+    F: FnMut(&fs::DirEntry) -> bool,
+{
+    let mut predicate = predicate;
+    let mut results = read_dir(path)?;
+
+    results.retain(|entry| predicate(entry));
+
+    Ok(results)
+}
+
 /// Read all contents recursively for the provided directory path.
 #[inline]
 #[instrument]
@@ -695,6 +1087,148 @@ pub fn rename<F: AsRef<Path> + Debug, T: AsRef<Path> + Debug>(
     })
 }
 
+/// Options for [`sync_dirs`].
+#[derive(Debug, Default)]
+pub struct SyncDirsOptions {
+    /// Compute and return the changes, but do not modify the destination.
+    pub dry_run: bool,
+    /// Do not remove files from the destination that do not exist in the source.
+    pub keep_stale: bool,
+}
+
+/// Files that were added, changed, or removed while syncing,
+/// relative from the source and destination directories.
+#[derive(Debug, Default, PartialEq)]
+pub struct SyncDirsResult {
+    pub added: Vec<PathBuf>,
+    pub changed: Vec<PathBuf>,
+    pub removed: Vec<PathBuf>,
+    pub unchanged: usize,
+}
+
+fn are_files_equal(a: &Path, b: &Path) -> Result<bool, FsError> {
+    use std::io::Read;
+
+    if metadata(a)?.len() != metadata(b)?.len() {
+        return Ok(false);
+    }
+
+    let mut a_reader = std::io::BufReader::new(open_file(a)?);
+    let mut b_reader = std::io::BufReader::new(open_file(b)?);
+    let mut a_buf = [0; 4096];
+    let mut b_buf = [0; 4096];
+
+    loop {
+        let a_len = a_reader.read(&mut a_buf).map_err(|error| FsError::Read {
+            path: a.to_path_buf(),
+            error: Box::new(error),
+        })?;
+
+        if a_len == 0 {
+            return Ok(true);
+        }
+
+        b_reader
+            .read_exact(&mut b_buf[..a_len])
+            .map_err(|error| FsError::Read {
+                path: b.to_path_buf(),
+                error: Box::new(error),
+            })?;
+
+        if a_buf[..a_len] != b_buf[..a_len] {
+            return Ok(false);
+        }
+    }
+}
+
+/// Incrementally sync the destination directory with the source directory, by copying
+/// files that are new or have changed (by size and content), and removing files from the
+/// destination that do not exist in the source. Unchanged files are not touched.
+#[instrument]
+pub fn sync_dirs<S: AsRef<Path> + Debug, D: AsRef<Path> + Debug>(
+    src: S,
+    dest: D,
+    options: SyncDirsOptions,
+) -> Result<SyncDirsResult, FsError> {
+    let src = src.as_ref();
+    let dest = dest.as_ref();
+    let mut result = SyncDirsResult::default();
+
+    trace!(
+        src = ?src,
+        dest = ?dest,
+        dry_run = options.dry_run,
+        "Syncing directories"
+    );
+
+    let mut src_files = read_dir_all(src)?
+        .into_iter()
+        .map(|entry| entry.path().strip_prefix(src).unwrap().to_path_buf())
+        .collect::<Vec<_>>();
+
+    src_files.sort();
+
+    for rel_file in &src_files {
+        let src_file = src.join(rel_file);
+        let dest_file = dest.join(rel_file);
+
+        if !dest_file.exists() {
+            result.added.push(rel_file.to_owned());
+        } else if are_files_equal(&src_file, &dest_file)? {
+            result.unchanged += 1;
+
+            continue;
+        } else {
+            result.changed.push(rel_file.to_owned());
+        }
+
+        if !options.dry_run {
+            copy_file(&src_file, &dest_file)?;
+        }
+    }
+
+    if !options.keep_stale {
+        let mut dest_files = read_dir_all(dest)?
+            .into_iter()
+            .map(|entry| entry.path().strip_prefix(dest).unwrap().to_path_buf())
+            .filter(|rel_file| src_files.binary_search(rel_file).is_err())
+            .collect::<Vec<_>>();
+
+        dest_files.sort();
+
+        for rel_file in dest_files {
+            if !options.dry_run {
+                remove_file(dest.join(&rel_file))?;
+            }
+
+            result.removed.push(rel_file);
+        }
+    }
+
+    Ok(result)
+}
+
+/// Move a file or directory at the provided path to the operating system's
+/// trash (or recycle bin), instead of permanently deleting it.
+/// If the path does not exist, this is a no-op.
+#[cfg(feature = "trash")]
+#[inline]
+#[instrument]
+pub fn trash<T: AsRef<Path> + Debug>(path: T) -> Result<(), FsError> {
+    let path = path.as_ref();
+
+    if path.exists() || path.is_symlink() {
+        trace!(path = ?path, "Moving to trash");
+
+        trash::delete(path).map_err(|error| FsError::Trash {
+            path: path.to_path_buf(),
+            error: Box::new(error),
+        })?;
+    }
+
+    Ok(())
+}
+
 /// Update the permissions of a file at the provided path. If a mode is not provided,
 /// the default of 0o755 will be used. The path must already exist.
 #[cfg(unix)]
@@ -744,6 +1278,42 @@ pub fn write_file<T: AsRef<Path> + Debug, D: AsRef<[u8]>>(path: T, data: D) -> R
     })
 }
 
+/// Write a file with the provided data to the provided path, but only if the file
+/// does not exist, or its current content differs from the provided data. This avoids
+/// bumping the modified time of unchanged files. Returns true if the file was written.
+#[inline]
+#[instrument(skip(data))]
+pub fn write_file_if_changed<T: AsRef<Path> + Debug, D: AsRef<[u8]>>(
+    path: T,
+    data: D,
+) -> Result<bool, FsError> {
+    let path = path.as_ref();
+    let data = data.as_ref();
+
+    if path.is_file() && read_file_bytes(path)? == data {
+        trace!(file = ?path, "File is unchanged, skipping write");
+
+        return Ok(false);
+    }
+
+    write_file(path, data)?;
+
+    Ok(true)
+}
+
+/// Write a file with the provided content to the provided path, after normalizing
+/// all line endings to the provided style. If the parent directory does not exist,
+/// it will be created.
+#[inline]
+#[instrument(skip(content))]
+pub fn write_file_normalized<T: AsRef<Path> + Debug, C: AsRef<str>>(
+    path: T,
+    content: C,
+    style: LineEnding,
+) -> Result<(), FsError> {
+    write_file(path, normalize_line_endings(content, style))
+}
+
 /// Write a file with the provided data to the provided path, while taking the
 /// closest `.editorconfig` into account
 #[cfg(feature = "editor-config")]
@@ -5,6 +5,9 @@ use thiserror::Error;
 #[cfg(not(feature = "miette"))]
 #[derive(Error, Debug)]
 pub enum FsError {
+    #[error("Operation on {} was cancelled.", .path.style(Style::Path))]
+    Cancelled { path: PathBuf },
+
     #[error("Failed to copy {} to {}.\n{error}", .from.style(Style::Path), .to.style(Style::Path))]
     Copy {
         from: PathBuf,
@@ -27,6 +30,9 @@ pub enum FsError {
         error: Box<std::io::Error>,
     },
 
+    #[error("Timed out waiting to acquire lock {}.", .path.style(Style::Path))]
+    LockTimeout { path: PathBuf },
+
     #[error("Failed to update permissions for {}.\n{error}", .path.style(Style::Path))]
     Perms {
         path: PathBuf,
@@ -62,6 +68,14 @@ pub enum FsError {
         error: Box<std::io::Error>,
     },
 
+    #[cfg(feature = "trash")]
+    #[error("Failed to move {} to the trash.\n{error}", .path.style(Style::Path))]
+    Trash {
+        path: PathBuf,
+        #[source]
+        error: Box<trash::Error>,
+    },
+
     #[error("Failed to unlock {}.\n{error}", .path.style(Style::Path))]
     Unlock {
         path: PathBuf,
@@ -80,6 +94,10 @@ pub enum FsError {
 #[cfg(feature = "miette")]
 #[derive(Error, Debug, miette::Diagnostic)]
 pub enum FsError {
+    #[diagnostic(code(fs::cancelled))]
+    #[error("Operation on {} was cancelled.", .path.style(Style::Path))]
+    Cancelled { path: PathBuf },
+
     #[diagnostic(code(fs::copy), help("Does the source file exist?"))]
     #[error("Failed to copy {} to {}.", .from.style(Style::Path), .to.style(Style::Path))]
     Copy {
@@ -105,6 +123,10 @@ pub enum FsError {
         error: Box<std::io::Error>,
     },
 
+    #[diagnostic(code(fs::lock_timeout))]
+    #[error("Timed out waiting to acquire lock {}.", .path.style(Style::Path))]
+    LockTimeout { path: PathBuf },
+
     #[diagnostic(code(fs::perms))]
     #[error("Failed to update permissions for {}.", .path.style(Style::Path))]
     Perms {
@@ -146,6 +168,15 @@ pub enum FsError {
         error: Box<std::io::Error>,
     },
 
+    #[cfg(feature = "trash")]
+    #[diagnostic(code(fs::trash))]
+    #[error("Failed to move {} to the trash.", .path.style(Style::Path))]
+    Trash {
+        path: PathBuf,
+        #[source]
+        error: Box<trash::Error>,
+    },
+
     #[diagnostic(code(fs::unlock))]
     #[error("Failed to unlock {}.", .path.style(Style::Path))]
     Unlock {
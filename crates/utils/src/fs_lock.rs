@@ -5,53 +5,140 @@ use fs4::fs_std::FileExt;
 use std::fmt::Debug;
 use std::fs::File;
 use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, Instant};
 use tracing::{instrument, trace};
 
 pub const LOCK_FILE: &str = ".lock";
 
+const LOCK_POLL_INTERVAL: Duration = Duration::from_millis(25);
+
+fn is_contended(error: &std::io::Error) -> bool {
+    error.raw_os_error() == fs4::lock_contended_error().raw_os_error()
+}
+
 pub struct FileLock {
     lock: PathBuf,
     file: File,
+    shared: bool,
     unlocked: bool,
 }
 
 impl FileLock {
     pub fn new(path: PathBuf) -> Result<Self, FsError> {
+        Self::acquire(path, None, false).map(|lock| lock.expect("Blocking lock always acquired"))
+    }
+
+    /// Acquire a shared lock, which can be held by many processes at once,
+    /// but blocks while an exclusive lock is held (and vice versa). The PID
+    /// is not written for shared locks, as there may be many owners.
+    pub fn new_shared(path: PathBuf) -> Result<Self, FsError> {
+        Self::acquire(path, None, true).map(|lock| lock.expect("Blocking lock always acquired"))
+    }
+
+    /// Attempt to acquire the lock, waiting up to the provided timeout.
+    /// If the timeout is zero, will only try once. If the lock could not
+    /// be acquired in time, returns `None`.
+    pub fn new_with_timeout(path: PathBuf, timeout: Duration) -> Result<Option<Self>, FsError> {
+        Self::acquire(path, Some(timeout), false)
+    }
+
+    /// Return true if this is a shared lock.
+    pub fn is_shared(&self) -> bool {
+        self.shared
+    }
+
+    fn acquire(
+        path: PathBuf,
+        timeout: Option<Duration>,
+        shared: bool,
+    ) -> Result<Option<Self>, FsError> {
         use std::io::prelude::*;
 
         let mut file = fs::create_file_if_missing(&path)?;
 
+        let handle_lock_error = |error: std::io::Error| FsError::Lock {
+            path: path.clone(),
+            error: Box::new(error),
+        };
+
         trace!(
             lock = ?path,
+            timeout = ?timeout,
+            shared,
             "Waiting to acquire lock",
         );
 
-        // This blocks if another process has access!
-        file.lock_exclusive().map_err(|error| FsError::Lock {
-            path: path.clone(),
-            error: Box::new(error),
-        })?;
+        match timeout {
+            // This blocks if another process has access!
+            None => {
+                if shared {
+                    file.lock_shared().map_err(handle_lock_error)?;
+                } else {
+                    file.lock_exclusive().map_err(handle_lock_error)?;
+                }
+            }
+            // Otherwise poll until acquired or timed out
+            Some(timeout) => {
+                let start = Instant::now();
+
+                loop {
+                    let result = if shared {
+                        file.try_lock_shared()
+                    } else {
+                        file.try_lock_exclusive()
+                    };
+
+                    match result {
+                        Ok(_) => break,
+                        Err(error) if is_contended(&error) => {
+                            let elapsed = start.elapsed();
+
+                            if elapsed >= timeout {
+                                trace!(lock = ?path, "Timed out waiting to acquire lock");
+
+                                return Ok(None);
+                            }
+
+                            thread::sleep(LOCK_POLL_INTERVAL.min(timeout - elapsed));
+                        }
+                        Err(error) => {
+                            return Err(handle_lock_error(error));
+                        }
+                    };
+                }
+            }
+        };
 
-        let pid = std::process::id();
+        if shared {
+            trace!(lock = ?path, "Acquired shared lock");
+        } else {
+            let pid = std::process::id();
 
-        trace!(
-            lock = ?path,
-            pid,
-            "Acquired lock, writing PID",
-        );
+            trace!(
+                lock = ?path,
+                pid,
+                "Acquired lock, writing PID",
+            );
 
-        // Let other processes know that we have locked it
-        file.write(format!("{}", pid).as_ref())
-            .map_err(|error| FsError::Write {
+            let handle_write_error = |error: std::io::Error| FsError::Write {
                 path: path.clone(),
                 error: Box::new(error),
-            })?;
+            };
 
-        Ok(Self {
+            // Let other processes know that we have locked it, and truncate
+            // first in case a stale PID was left behind
+            file.set_len(0).map_err(handle_write_error)?;
+            file.write(format!("{}", pid).as_ref())
+                .map_err(handle_write_error)?;
+        }
+
+        Ok(Some(Self {
             lock: path,
             file,
+            shared,
             unlocked: false,
-        })
+        }))
     }
 
     pub fn unlock(&mut self) -> Result<(), FsError> {
@@ -83,6 +170,16 @@ impl FileLock {
 
         self.unlocked = true;
 
+        // Other processes may still hold a shared lock, so only remove
+        // the file if we're the last one (we can acquire exclusive)
+        if self.shared {
+            if self.file.try_lock_exclusive().is_err() {
+                return Ok(());
+            }
+
+            self.file.unlock().map_err(handle_error)?;
+        }
+
         fs::remove_file(&self.lock)
     }
 }
@@ -107,6 +204,80 @@ pub fn is_dir_locked<T: AsRef<Path>>(path: T) -> bool {
     path.as_ref().join(LOCK_FILE).exists()
 }
 
+/// Read the process ID (PID) that was written to the provided lock file
+/// when it was acquired. Returns `None` if the file does not exist,
+/// or does not contain a valid PID.
+pub fn read_lock_pid<T: AsRef<Path>>(path: T) -> Option<u32> {
+    std::fs::read_to_string(path).ok()?.trim().parse().ok()
+}
+
+/// Return true if a process with the provided ID is currently running.
+#[cfg(unix)]
+pub fn is_process_alive(pid: u32) -> bool {
+    let Ok(pid) = libc::pid_t::try_from(pid) else {
+        return false;
+    };
+
+    // Signal 0 performs error checking only, and doesn't send a signal
+    if unsafe { libc::kill(pid, 0) } == 0 {
+        return true;
+    }
+
+    // If permission is denied, the process exists but is owned by another user
+    std::io::Error::last_os_error().raw_os_error() == Some(libc::EPERM)
+}
+
+/// Return true if a process with the provided ID is currently running.
+#[cfg(windows)]
+pub fn is_process_alive(pid: u32) -> bool {
+    use sysinfo::{Pid, ProcessesToUpdate, System};
+
+    let pid = Pid::from_u32(pid);
+    let mut system = System::new();
+
+    system.refresh_processes(ProcessesToUpdate::Some(&[pid]), true);
+    system.process(pid).is_some()
+}
+
+/// Return true if a process with the provided ID is currently running.
+/// On this platform process lookups are not supported, so the process is
+/// always assumed to be running, and locks are never considered stale.
+#[cfg(not(any(unix, windows)))]
+pub fn is_process_alive(_pid: u32) -> bool {
+    true
+}
+
+/// Return true if the directory has a `.lock` file (via [`lock_directory`]),
+/// but the process that acquired it is no longer running. This can happen
+/// when a process is killed before the lock is dropped.
+pub fn is_dir_lock_stale<T: AsRef<Path>>(path: T) -> bool {
+    read_lock_pid(path.as_ref().join(LOCK_FILE)).is_some_and(|pid| !is_process_alive(pid))
+}
+
+/// Reclaim the `.lock` file of a directory by removing it, if the lock is stale
+/// (see [`is_dir_lock_stale`]). If `force` is true, the file will be removed
+/// even if the owning process is still running. Returns true if removed.
+#[instrument]
+pub fn reclaim_dir_lock<T: AsRef<Path> + Debug>(path: T, force: bool) -> Result<bool, FsError> {
+    let path = path.as_ref();
+    let lock = path.join(LOCK_FILE);
+
+    if !lock.exists() || (!force && !is_dir_lock_stale(path)) {
+        return Ok(false);
+    }
+
+    trace!(
+        lock = ?lock,
+        pid = read_lock_pid(&lock),
+        force,
+        "Reclaiming directory lock",
+    );
+
+    fs::remove_file(lock)?;
+
+    Ok(true)
+}
+
 /// Return true if the file is currently locked (using exclusive).
 /// This function operates by locking the file and checking for
 /// an "is locked/contended" error, which can be brittle.
@@ -124,6 +295,20 @@ pub fn is_file_locked<T: AsRef<Path>>(path: T) -> bool {
     }
 }
 
+fn prepare_lock_directory(path: &Path) -> Result<PathBuf, FsError> {
+    fs::create_dir_all(path)?;
+
+    if !path.is_dir() {
+        return Err(FsError::RequireDir {
+            path: path.to_path_buf(),
+        });
+    }
+
+    trace!(dir = ?path, "Locking directory");
+
+    Ok(path.join(LOCK_FILE))
+}
+
 /// Lock a directory so that other processes cannot interact with it.
 /// The locking mechanism works by creating a `.lock` file in the directory,
 /// with the current process ID (PID) as content. If another process attempts
@@ -135,17 +320,7 @@ pub fn is_file_locked<T: AsRef<Path>>(path: T) -> bool {
 #[inline]
 #[instrument]
 pub fn lock_directory<T: AsRef<Path> + Debug>(path: T) -> Result<DirLock, FsError> {
-    let path = path.as_ref();
-
-    fs::create_dir_all(path)?;
-
-    if !path.is_dir() {
-        return Err(FsError::RequireDir {
-            path: path.to_path_buf(),
-        });
-    }
-
-    trace!(dir = ?path, "Locking directory");
+    let lock = prepare_lock_directory(path.as_ref())?;
 
     // We can't rely on the existence of the `.lock` file, because if the
     // process is killed, the `DirLock` is not dropped, and the file is not removed!
@@ -155,7 +330,47 @@ pub fn lock_directory<T: AsRef<Path> + Debug>(path: T) -> Result<DirLock, FsErro
     // for write access, and will be "unlocked" automatically by the kernel.
     //
     // Context: https://www.reddit.com/r/rust/comments/14hlx8u/comment/jpbmsh2/?utm_source=reddit&utm_medium=web2x&context=3
-    DirLock::new(path.join(LOCK_FILE))
+    DirLock::new(lock)
+}
+
+/// Lock a directory with shared access, so that many processes can read
+/// from it concurrently. If another process holds an exclusive lock (via
+/// [`lock_directory`]), it will block waiting for it to be unlocked, and
+/// exclusive locks will block until all shared locks have been unlocked.
+///
+/// This function returns a `DirLock` guard that will automatically unlock
+/// when being dropped. The `.lock` file is only removed when the last
+/// shared lock has been unlocked.
+#[inline]
+#[instrument]
+pub fn lock_directory_shared<T: AsRef<Path> + Debug>(path: T) -> Result<DirLock, FsError> {
+    let lock = prepare_lock_directory(path.as_ref())?;
+
+    DirLock::new_shared(lock)
+}
+
+/// Lock a directory similar to [`lock_directory`], but only wait up to the
+/// provided timeout for the lock to be acquired. If the timeout is reached,
+/// a [`FsError::LockTimeout`] error is returned.
+#[inline]
+#[instrument]
+pub fn lock_directory_with_timeout<T: AsRef<Path> + Debug>(
+    path: T,
+    timeout: Duration,
+) -> Result<DirLock, FsError> {
+    let lock = prepare_lock_directory(path.as_ref())?;
+
+    DirLock::new_with_timeout(lock.clone(), timeout)?.ok_or(FsError::LockTimeout { path: lock })
+}
+
+/// Attempt to lock a directory similar to [`lock_directory`], but without
+/// waiting. If the directory is already locked, returns `None`.
+#[inline]
+#[instrument]
+pub fn try_lock_directory<T: AsRef<Path> + Debug>(path: T) -> Result<Option<DirLock>, FsError> {
+    let lock = prepare_lock_directory(path.as_ref())?;
+
+    DirLock::new_with_timeout(lock, Duration::ZERO)
 }
 
 /// Lock the provided file with exclusive access and write the current process ID
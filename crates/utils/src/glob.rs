@@ -1,4 +1,5 @@
 use std::fmt::Debug;
+use std::io::Read;
 use std::sync::{LazyLock, RwLock};
 use std::{
     ffi::OsStr,
@@ -7,6 +8,7 @@ use std::{
 use tracing::instrument;
 use wax::{Any, LinkBehavior, Pattern};
 
+pub use crate::glob_cache::*;
 pub use crate::glob_error::GlobError;
 pub use wax::{self, Glob};
 
@@ -119,8 +121,52 @@ impl<'glob> GlobSet<'glob> {
 
         self.is_match(path)
     }
+
+    /// Return a list of booleans, in the same order as the provided paths, indicating
+    /// whether each path matches the glob patterns, while taking into account negated
+    /// patterns. This is equivalent to calling [`GlobSet::matches`] for each path,
+    /// but large batches are matched in parallel across multiple threads.
+    pub fn matches_many<P: AsRef<OsStr> + Sync>(&self, paths: &[P]) -> Vec<bool> {
+        if !self.enabled {
+            return vec![false; paths.len()];
+        }
+
+        let threads = std::thread::available_parallelism()
+            .map(|count| count.get())
+            .unwrap_or(1)
+            .min(paths.len().div_ceil(PARALLEL_MATCH_THRESHOLD));
+
+        // Spawning threads is only worth it for large batches
+        if threads <= 1 {
+            return paths.iter().map(|path| self.matches(path)).collect();
+        }
+
+        let chunk_size = paths.len().div_ceil(threads);
+
+        std::thread::scope(|scope| {
+            let handles = paths
+                .chunks(chunk_size)
+                .map(|chunk| {
+                    scope.spawn(move || {
+                        chunk
+                            .iter()
+                            .map(|path| self.matches(path))
+                            .collect::<Vec<_>>()
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            handles
+                .into_iter()
+                .flat_map(|handle| handle.join().expect("Glob matching thread panicked."))
+                .collect()
+        })
+    }
 }
 
+// Minimum number of paths per thread when matching in parallel.
+const PARALLEL_MATCH_THRESHOLD: usize = 5000;
+
 /// Parse and create a [`Glob`] instance from the borrowed string pattern.
 /// If parsing fails, a [`GlobError`] is returned.
 #[inline]
@@ -132,53 +178,105 @@ pub fn create_glob(pattern: &str) -> Result<Glob<'_>, GlobError> {
     })
 }
 
-/// Return true if the provided string looks like a glob pattern.
+/// Escape all glob meta-characters in the provided literal string (file name, etc)
+/// with a backslash, so that it can be safely embedded within a glob pattern.
+#[inline]
+pub fn escape(literal: &str) -> String {
+    wax::escape(literal).into_owned()
+}
+
+/// Return true if the provided string looks like a glob pattern. Escaped characters
+/// and unbalanced brackets/braces are not considered a glob.
 /// This is not exhaustive and may be inaccurate.
 #[inline]
 #[instrument]
 pub fn is_glob<T: AsRef<str> + Debug>(value: T) -> bool {
     let value = value.as_ref();
 
-    if value.contains("**") {
-        return true;
+    let mut chars = value.chars();
+    let mut open_bracket = false;
+    let mut open_brace = false;
+
+    while let Some(ch) = chars.next() {
+        match ch {
+            '\\' => {
+                // Skip the escaped character
+                chars.next();
+            }
+            '*' | '?' | '!' => {
+                return true;
+            }
+            '[' => {
+                open_bracket = true;
+            }
+            ']' if open_bracket => {
+                return true;
+            }
+            '{' => {
+                open_brace = true;
+            }
+            '}' if open_brace => {
+                return true;
+            }
+            _ => {}
+        };
     }
 
-    let single_values = vec!['*', '?', '!'];
-    let paired_values = vec![('{', '}'), ('[', ']')];
-    let mut bytes = value.bytes();
-    let mut is_escaped = |index: usize| {
-        if index == 0 {
-            return false;
-        }
+    false
+}
 
-        bytes.nth(index - 1).unwrap_or(b' ') == b'\\'
-    };
+/// Return true if the path matches the provided glob pattern. If the pattern is
+/// negated (starts with `!`), return true if the path does *not* match it.
+/// This is a convenience over [`GlobSet`] for one-off checks.
+#[inline]
+pub fn matches<P: AsRef<OsStr>>(path: P, pattern: &str) -> Result<bool, GlobError> {
+    matches_any(path, [pattern])
+}
 
-    for single in single_values {
-        if !value.contains(single) {
-            continue;
-        }
+/// Return true if the path matches any of the provided non-negated glob patterns,
+/// and none of the negated patterns (starts with `!`). If only negated patterns are
+/// provided, return true if the path does not match any of them.
+/// This is a convenience over [`GlobSet`] for one-off checks.
+pub fn matches_any<'glob, P, I, V>(path: P, patterns: I) -> Result<bool, GlobError>
+where
+    P: AsRef<OsStr>,
+    I: IntoIterator<Item = &'glob V> + Debug,
+    V: AsRef<str> + 'glob + ?Sized,
+{
+    let path = path.as_ref();
+    let (expressions, negations) = split_patterns(patterns);
+    let only_negations = expressions.is_empty() && !negations.is_empty();
+    let set = GlobSet::new_split(expressions, negations)?;
+
+    Ok(if only_negations {
+        !set.is_negated(path)
+    } else {
+        set.matches(path)
+    })
+}
 
-        if let Some(index) = value.find(single) {
-            if !is_escaped(index) {
-                return true;
-            }
-        }
-    }
+/// Return true if the path matches all of the provided non-negated glob patterns,
+/// and none of the negated patterns (starts with `!`).
+pub fn matches_all<'glob, P, I, V>(path: P, patterns: I) -> Result<bool, GlobError>
+where
+    P: AsRef<OsStr>,
+    I: IntoIterator<Item = &'glob V> + Debug,
+    V: AsRef<str> + 'glob + ?Sized,
+{
+    let path = path.as_ref();
+    let (expressions, negations) = split_patterns(patterns);
 
-    for (open, close) in paired_values {
-        if !value.contains(open) || !value.contains(close) {
-            continue;
-        }
+    if GlobSet::new_split(Vec::<&str>::new(), negations)?.is_negated(path) {
+        return Ok(false);
+    }
 
-        if let Some(index) = value.find(open) {
-            if !is_escaped(index) {
-                return true;
-            }
+    for expression in expressions {
+        if !create_glob(expression)?.is_match(path) {
+            return Ok(false);
         }
     }
 
-    false
+    Ok(true)
 }
 
 /// Normalize a glob-based file path to use forward slashes. If the path contains
@@ -232,6 +330,96 @@ where
     (expressions, negations)
 }
 
+/// Options to customize the walking behavior of [`walk_with_options`].
+#[derive(Clone, Debug, Default)]
+pub struct GlobWalkOptions {
+    /// Exclude files larger than the provided size (in bytes).
+    /// Directories are never excluded.
+    pub max_file_size: Option<u64>,
+
+    /// Walk each non-negated pattern concurrently across a pool of threads,
+    /// and merge the results. The order of results is preserved.
+    pub parallel: bool,
+
+    /// Return paths relative to the base directory, using forward slashes,
+    /// instead of absolute paths.
+    pub relative: bool,
+
+    /// Exclude files that are detected as binary, by checking the first
+    /// few KB of the file for null bytes. Directories are never excluded.
+    pub skip_binary: bool,
+}
+
+// Number of bytes to sample when detecting binary files
+const BINARY_SAMPLE_SIZE: u64 = 8192;
+
+fn is_binary_file(path: &Path) -> bool {
+    let Ok(file) = std::fs::File::open(path) else {
+        return false;
+    };
+
+    let mut buffer = Vec::with_capacity(BINARY_SAMPLE_SIZE as usize);
+
+    if file
+        .take(BINARY_SAMPLE_SIZE)
+        .read_to_end(&mut buffer)
+        .is_err()
+    {
+        return false;
+    }
+
+    buffer.contains(&0)
+}
+
+fn is_file_excluded(path: &Path, options: &GlobWalkOptions) -> bool {
+    if options.max_file_size.is_none() && !options.skip_binary {
+        return false;
+    }
+
+    let Ok(meta) = path.metadata() else {
+        return false;
+    };
+
+    if !meta.is_file() {
+        return false;
+    }
+
+    if options
+        .max_file_size
+        .is_some_and(|max_size| meta.len() > max_size)
+    {
+        return true;
+    }
+
+    options.skip_binary && is_binary_file(path)
+}
+
+fn walk_expression(
+    base_dir: &Path,
+    expression: &str,
+    negations: &[&str],
+) -> Result<Vec<PathBuf>, GlobError> {
+    let mut paths = vec![];
+
+    for entry in create_glob(expression)?
+        .walk_with_behavior(base_dir, LinkBehavior::ReadFile)
+        .not(negations.to_vec())
+        .unwrap()
+    {
+        match entry {
+            Ok(e) => {
+                paths.push(e.into_path());
+            }
+            Err(_) => {
+                // Will crash if the file doesn't exist
+                continue;
+            }
+        };
+    }
+
+    Ok(paths)
+}
+
 /// Walk the file system starting from the provided directory, and return all files and directories
 /// that match the provided glob patterns. Use [`walk_files`] if you only want to return files.
 #[inline]
@@ -242,30 +430,111 @@ where
     I: IntoIterator<Item = &'glob V> + Debug,
     V: AsRef<str> + 'glob + ?Sized,
 {
+    walk_with_options(base_dir, patterns, GlobWalkOptions::default())
+}
+
+/// Walk the file system starting from the provided directory, and return all files and directories
+/// that match the provided glob patterns, using the provided options to customize the walk.
+#[instrument]
+pub fn walk_with_options<'glob, P, I, V>(
+    base_dir: P,
+    patterns: I,
+    options: GlobWalkOptions,
+) -> Result<Vec<PathBuf>, GlobError>
+where
+    P: AsRef<Path> + Debug,
+    I: IntoIterator<Item = &'glob V> + Debug,
+    V: AsRef<str> + 'glob + ?Sized,
+{
+    let base_dir = base_dir.as_ref();
     let (expressions, mut negations) = split_patterns(patterns);
     negations.extend(GLOBAL_NEGATIONS.read().unwrap().iter());
 
     let mut paths = vec![];
 
-    for expression in expressions {
-        for entry in create_glob(expression)?
-            .walk_with_behavior(base_dir.as_ref(), LinkBehavior::ReadFile)
-            .not(negations.clone())
-            .unwrap()
-        {
-            match entry {
-                Ok(e) => {
-                    paths.push(e.into_path());
-                }
-                Err(_) => {
-                    // Will crash if the file doesn't exist
-                    continue;
-                }
-            };
+    if !options.parallel || expressions.len() <= 1 {
+        for expression in expressions {
+            paths.extend(walk_expression(base_dir, expression, &negations)?);
         }
+
+        return finalize_walked_paths(base_dir, paths, &options);
     }
 
-    Ok(paths)
+    let threads = std::thread::available_parallelism()
+        .map(|count| count.get())
+        .unwrap_or(1)
+        .min(expressions.len());
+    let chunk_size = expressions.len().div_ceil(threads);
+
+    let results = std::thread::scope(|scope| {
+        let handles = expressions
+            .chunks(chunk_size)
+            .map(|chunk| {
+                let negations = &negations;
+
+                scope.spawn(move || {
+                    let mut chunk_paths = vec![];
+
+                    for expression in chunk {
+                        chunk_paths.extend(walk_expression(base_dir, expression, negations)?);
+                    }
+
+                    Ok::<_, GlobError>(chunk_paths)
+                })
+            })
+            .collect::<Vec<_>>();
+
+        handles
+            .into_iter()
+            .map(|handle| handle.join().expect("Glob walking thread panicked."))
+            .collect::<Vec<_>>()
+    });
+
+    for result in results {
+        paths.extend(result?);
+    }
+
+    finalize_walked_paths(base_dir, paths, &options)
+}
+
+fn finalize_walked_paths(
+    base_dir: &Path,
+    mut paths: Vec<PathBuf>,
+    options: &GlobWalkOptions,
+) -> Result<Vec<PathBuf>, GlobError> {
+    paths.retain(|path| !is_file_excluded(path, options));
+
+    if !options.relative {
+        return Ok(paths);
+    }
+
+    paths
+        .into_iter()
+        .map(|path| match path.strip_prefix(base_dir) {
+            Ok(rel_path) => normalize(rel_path).map(PathBuf::from),
+            Err(_) => Ok(path),
+        })
+        .collect()
+}
+
+/// Walk the file system starting from the provided directory, and return all files and directories
+/// that match the provided glob patterns, as paths relative to the provided directory
+/// using forward slashes.
+#[inline]
+pub fn walk_relative<'glob, P, I, V>(base_dir: P, patterns: I) -> Result<Vec<PathBuf>, GlobError>
+where
+    P: AsRef<Path> + Debug,
+    I: IntoIterator<Item = &'glob V> + Debug,
+    V: AsRef<str> + 'glob + ?Sized,
+{
+    walk_with_options(
+        base_dir,
+        patterns,
+        GlobWalkOptions {
+            relative: true,
+            ..GlobWalkOptions::default()
+        },
+    )
 }
 
 /// Walk the file system starting from the provided directory, and return all files
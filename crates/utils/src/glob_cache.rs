@@ -0,0 +1,175 @@
+use crate::glob::{walk, GlobError};
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::Debug;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::RwLock;
+use std::time::SystemTime;
+use tracing::{instrument, trace};
+
+type GlobCacheKey = (PathBuf, Vec<String>);
+
+#[derive(Debug)]
+struct GlobCacheEntry {
+    mtimes: Vec<(PathBuf, Option<SystemTime>)>,
+    paths: Vec<PathBuf>,
+}
+
+impl GlobCacheEntry {
+    fn is_stale(&self) -> bool {
+        self.mtimes
+            .iter()
+            .any(|(dir, mtime)| mtime.is_none() || get_dir_mtime(dir) != *mtime)
+    }
+}
+
+/// Hit and miss counts for a [`GlobCache`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct GlobCacheStats {
+    pub hits: usize,
+    pub misses: usize,
+}
+
+/// A cache of glob walk results, keyed by the base directory and patterns.
+/// Subsequent walks with the same directory and patterns will return
+/// the cached results instead of walking the file system again.
+///
+/// The modified times of the base directory, and of every directory that contains
+/// (or is) a matched path, are stored alongside the results. If any of them have
+/// changed since the walk (a file was added or removed), the results will be
+/// transparently recomputed. Directories that had no matches are not tracked,
+/// so use [`GlobCache::invalidate`] when files are created within them.
+#[derive(Debug, Default)]
+pub struct GlobCache {
+    cache: RwLock<HashMap<GlobCacheKey, GlobCacheEntry>>,
+    hits: AtomicUsize,
+    misses: AtomicUsize,
+}
+
+impl GlobCache {
+    /// Create a new empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Walk the file system starting from the provided directory, and return all files
+    /// and directories that match the provided glob patterns. If the directory and
+    /// patterns have been walked before, the cached results will be returned.
+    #[instrument(skip(self))]
+    pub fn walk<'glob, P, I, V>(&self, base_dir: P, patterns: I) -> Result<Vec<PathBuf>, GlobError>
+    where
+        P: AsRef<Path> + Debug,
+        I: IntoIterator<Item = &'glob V> + Debug,
+        V: AsRef<str> + 'glob + ?Sized,
+    {
+        let key = (
+            base_dir.as_ref().to_path_buf(),
+            patterns
+                .into_iter()
+                .map(|pattern| pattern.as_ref().to_owned())
+                .collect::<Vec<_>>(),
+        );
+
+        if let Some(entry) = self.cache.read().unwrap().get(&key) {
+            if !entry.is_stale() {
+                self.hits.fetch_add(1, Ordering::Relaxed);
+
+                return Ok(entry.paths.clone());
+            }
+
+            trace!(dir = ?key.0, "Directory has been modified since last walk, invalidating");
+        }
+
+        self.misses.fetch_add(1, Ordering::Relaxed);
+
+        let paths = walk(&key.0, &key.1)?;
+        let mtimes = get_dir_mtimes(&key.0, &paths);
+
+        self.cache.write().unwrap().insert(
+            key,
+            GlobCacheEntry {
+                mtimes,
+                paths: paths.clone(),
+            },
+        );
+
+        Ok(paths)
+    }
+
+    /// Walk the file system starting from the provided directory, and return all files
+    /// that match the provided glob patterns. Results are cached with [`GlobCache::walk`].
+    pub fn walk_files<'glob, P, I, V>(
+        &self,
+        base_dir: P,
+        patterns: I,
+    ) -> Result<Vec<PathBuf>, GlobError>
+    where
+        P: AsRef<Path> + Debug,
+        I: IntoIterator<Item = &'glob V> + Debug,
+        V: AsRef<str> + 'glob + ?Sized,
+    {
+        let paths = self.walk(base_dir, patterns)?;
+
+        Ok(paths
+            .into_iter()
+            .filter(|p| p.is_file())
+            .collect::<Vec<_>>())
+    }
+
+    /// Invalidate all cached results for walks that started within the provided directory,
+    /// or any of its parent directories, forcing the next walk to re-scan the file system.
+    pub fn invalidate<P: AsRef<Path>>(&self, dir: P) {
+        let dir = dir.as_ref();
+
+        trace!(dir = ?dir, "Invalidating glob cache");
+
+        self.cache
+            .write()
+            .unwrap()
+            .retain(|(base_dir, _), _| !dir.starts_with(base_dir) && !base_dir.starts_with(dir));
+    }
+
+    /// Remove all cached results and reset stats.
+    pub fn clear(&self) {
+        self.cache.write().unwrap().clear();
+        self.hits.store(0, Ordering::Relaxed);
+        self.misses.store(0, Ordering::Relaxed);
+    }
+
+    /// Return hit and miss counts for the cache.
+    pub fn stats(&self) -> GlobCacheStats {
+        GlobCacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+        }
+    }
+}
+
+fn get_dir_mtime(dir: &Path) -> Option<SystemTime> {
+    dir.metadata().and_then(|meta| meta.modified()).ok()
+}
+
+// Gather the base directory, and all directories between it and each matched path.
+fn get_dir_mtimes(base_dir: &Path, paths: &[PathBuf]) -> Vec<(PathBuf, Option<SystemTime>)> {
+    let mut dirs = BTreeSet::from([base_dir.to_path_buf()]);
+
+    for path in paths {
+        if path.is_dir() {
+            dirs.insert(path.to_path_buf());
+        }
+
+        for dir in path.ancestors().skip(1) {
+            if dir == base_dir || !dir.starts_with(base_dir) || !dirs.insert(dir.to_path_buf()) {
+                break;
+            }
+        }
+    }
+
+    dirs.into_iter()
+        .map(|dir| {
+            let mtime = get_dir_mtime(&dir);
+
+            (dir, mtime)
+        })
+        .collect()
+}
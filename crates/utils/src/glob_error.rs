@@ -1,3 +1,4 @@
+use crate::fs::FsError;
 use starbase_styles::{Style, Stylize};
 use std::path::PathBuf;
 use thiserror::Error;
@@ -6,6 +7,9 @@ use wax::BuildError;
 #[cfg(not(feature = "miette"))]
 #[derive(Error, Debug)]
 pub enum GlobError {
+    #[error(transparent)]
+    Fs(#[from] Box<FsError>),
+
     #[error("Failed to create glob from pattern {}.\n{error}", .glob.style(Style::File))]
     Create {
         glob: String,
@@ -20,6 +24,10 @@ pub enum GlobError {
 #[cfg(feature = "miette")]
 #[derive(Error, Debug, miette::Diagnostic)]
 pub enum GlobError {
+    #[diagnostic(transparent)]
+    #[error(transparent)]
+    Fs(#[from] Box<FsError>),
+
     #[diagnostic(code(glob::create))]
     #[error("Failed to create glob from pattern {}.", .glob.style(Style::File))]
     Create {
@@ -32,3 +40,9 @@ pub enum GlobError {
     #[error("Failed to normalize glob path {}.", .path.style(Style::Path))]
     InvalidPath { path: PathBuf },
 }
+
+impl From<FsError> for GlobError {
+    fn from(e: FsError) -> GlobError {
+        GlobError::Fs(Box::new(e))
+    }
+}
@@ -1,4 +1,4 @@
-use crate::fs;
+use crate::{env, fs};
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 use std::fmt::Debug;
@@ -58,9 +58,7 @@ where
         error: Box::new(error),
     })?;
 
-    serde_json::from_str(&contents).map_err(|error| JsonError::Parse {
-        error: Box::new(error),
-    })
+    serde_json::from_str(&contents).map_err(|error| JsonError::new_parse(&contents, error))
 }
 
 /// Format and serialize the provided value into a string.
@@ -126,10 +124,117 @@ where
 
     trace!(file = ?path, "Reading JSON file");
 
-    serde_json::from_str(&contents).map_err(|error| JsonError::ReadFile {
-        path: path.to_path_buf(),
-        error: Box::new(error),
-    })
+    serde_json::from_str(&contents)
+        .map_err(|error| JsonError::new_read_file(path, Some(&contents), error))
+}
+
+struct ArrayEachVisitor<'a, T, F, E> {
+    callback: &'a mut F,
+    callback_error: &'a mut Option<E>,
+    marker: std::marker::PhantomData<T>,
+}
+
+impl<'de, T, F, E> serde::de::Visitor<'de> for ArrayEachVisitor<'_, T, F, E>
+where
+    T: DeserializeOwned,
+    F: FnMut(T) -> Result<(), E>,
+{
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a JSON array")
+    }
+
+    fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+        use serde::de::Error;
+
+        while let Some(item) = seq.next_element::<T>()? {
+            if let Err(error) = (self.callback)(item) {
+                *self.callback_error = Some(error);
+
+                return Err(A::Error::custom("aborted by callback"));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Read a file at the provided path that contains a top-level JSON array, and
+/// deserialize and pass each element to the provided callback, one at a time.
+/// This allows large arrays to be processed without loading the entire file into
+/// memory. If the callback returns an error, reading will stop and the error will
+/// be returned. Comments and trailing commas are not supported.
+#[instrument(name = "read_json_array_each", skip(callback))]
+pub fn read_array_each<P, T, F, E>(path: P, mut callback: F) -> Result<(), E>
+where
+    P: AsRef<Path> + Debug,
+    T: DeserializeOwned,
+    F: FnMut(T) -> Result<(), E>,
+    E: From<JsonError>,
+{
+    use serde::Deserializer as _;
+
+    let path = path.as_ref();
+    let file = fs::open_file(path).map_err(JsonError::from)?;
+    let mut deserializer = serde_json::Deserializer::from_reader(std::io::BufReader::new(file));
+    let mut callback_error = None;
+
+    trace!(file = ?path, "Streaming JSON array file");
+
+    let result = deserializer
+        .deserialize_seq(ArrayEachVisitor {
+            callback: &mut callback,
+            callback_error: &mut callback_error,
+            marker: std::marker::PhantomData,
+        })
+        .and_then(|_| deserializer.end());
+
+    if let Some(error) = callback_error {
+        return Err(error);
+    }
+
+    result.map_err(|error| JsonError::new_read_file(path, None, error).into())
+}
+
+impl env::StringVisitor for JsonValue {
+    fn visit_strings(&mut self, op: &mut dyn FnMut(&mut String)) {
+        match self {
+            JsonValue::String(inner) => op(inner),
+            JsonValue::Array(inner) => {
+                for item in inner {
+                    item.visit_strings(op);
+                }
+            }
+            JsonValue::Object(inner) => {
+                for item in inner.values_mut() {
+                    item.visit_strings(op);
+                }
+            }
+            _ => {}
+        };
+    }
+}
+
+/// Read a file at the provided path and deserialize into the required type,
+/// while substituting `${VAR}` references in string values using the provided
+/// lookup function. Pass [`env::lookup_var`] to use the current process environment.
+/// Only string values are interpolated, not keys. The path must already exist.
+#[instrument(name = "read_json_interpolated", skip(lookup))]
+pub fn read_file_interpolated<P, D, F>(path: P, lookup: F) -> Result<D, JsonError>
+where
+    P: AsRef<Path> + Debug,
+    D: DeserializeOwned,
+    F: Fn(&str) -> Option<String>,
+{
+    let path = path.as_ref();
+    let mut value: JsonValue = read_file(path)?;
+
+    trace!(file = ?path, "Interpolating JSON file");
+
+    env::interpolate_strings(&mut value, lookup);
+
+    serde_json::from_value(value).map_err(|error| JsonError::new_read_file(path, None, error))
 }
 
 /// Write a file and serialize the provided data to the provided path. If the parent directory
@@ -187,7 +292,7 @@ where
     let editor_config = fs::get_editor_config_props(path)?;
 
     let mut data = format_with_identation(&json, &editor_config.indent)?;
-    editor_config.apply_eof(&mut data);
+    editor_config.apply_serialized_eof(&mut data);
 
     fs::write_file(path, data)?;
 
@@ -1,8 +1,11 @@
 use crate::fs::FsError;
 use starbase_styles::{Style, Stylize};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use thiserror::Error;
 
+#[cfg(feature = "miette")]
+use miette::{NamedSource, SourceSpan};
+
 #[cfg(not(feature = "miette"))]
 #[derive(Error, Debug)]
 pub enum JsonError {
@@ -83,6 +86,10 @@ pub enum JsonError {
     Parse {
         #[source]
         error: Box<serde_json::Error>,
+        #[source_code]
+        src: Option<NamedSource<String>>,
+        #[label("Failed here")]
+        span: Option<SourceSpan>,
     },
 
     #[diagnostic(code(json::parse_file))]
@@ -91,6 +98,10 @@ pub enum JsonError {
         path: PathBuf,
         #[source]
         error: Box<serde_json::Error>,
+        #[source_code]
+        src: Option<NamedSource<String>>,
+        #[label("Failed here")]
+        span: Option<SourceSpan>,
     },
 
     #[diagnostic(code(json::format_file))]
@@ -102,6 +113,68 @@ pub enum JsonError {
     },
 }
 
+impl JsonError {
+    /// Return the 1-based line and column at which parsing failed, if known.
+    pub fn location(&self) -> Option<(usize, usize)> {
+        match self {
+            Self::Parse { error, .. } | Self::ReadFile { error, .. } if error.line() > 0 => {
+                Some((error.line(), error.column()))
+            }
+            _ => None,
+        }
+    }
+
+    pub(crate) fn new_parse(contents: &str, error: serde_json::Error) -> Self {
+        #[cfg(not(feature = "miette"))]
+        let _ = contents;
+
+        Self::Parse {
+            #[cfg(feature = "miette")]
+            src: Some(NamedSource::new("input.json", contents.to_owned())),
+            #[cfg(feature = "miette")]
+            span: get_error_offset(contents, &error).map(SourceSpan::from),
+            error: Box::new(error),
+        }
+    }
+
+    pub(crate) fn new_read_file(
+        path: &Path,
+        contents: Option<&str>,
+        error: serde_json::Error,
+    ) -> Self {
+        #[cfg(not(feature = "miette"))]
+        let _ = contents;
+
+        Self::ReadFile {
+            #[cfg(feature = "miette")]
+            src: contents
+                .map(|contents| NamedSource::new(path.to_string_lossy(), contents.to_owned())),
+            #[cfg(feature = "miette")]
+            span: contents
+                .and_then(|contents| get_error_offset(contents, &error))
+                .map(SourceSpan::from),
+            path: path.to_path_buf(),
+            error: Box::new(error),
+        }
+    }
+}
+
+// Convert the 1-based line and column of the error into a byte offset.
+#[cfg(feature = "miette")]
+fn get_error_offset(contents: &str, error: &serde_json::Error) -> Option<usize> {
+    if error.line() == 0 {
+        return None;
+    }
+
+    let line_start = contents
+        .split_inclusive('\n')
+        .take(error.line() - 1)
+        .map(|line| line.len())
+        .sum::<usize>();
+
+    Some((line_start + error.column().saturating_sub(1)).min(contents.len()))
+}
+
 impl From<FsError> for JsonError {
     fn from(e: FsError) -> JsonError {
         JsonError::Fs(Box::new(e))
@@ -1,3 +1,6 @@
+/// Utilities for cancelling long-running operations.
+pub mod cancel;
+
 /// Utilities for reading and writing environment variables.
 pub mod env;
 
@@ -11,6 +14,8 @@ mod fs_lock; // Exported from fs
 /// Utilities for globbing the file system.
 pub mod glob;
 #[cfg(feature = "glob")]
+mod glob_cache; // Exported from glob
+#[cfg(feature = "glob")]
 mod glob_error;
 
 #[cfg(feature = "json")]
@@ -25,6 +30,12 @@ pub mod net;
 #[cfg(feature = "net")]
 mod net_error;
 
+/// Utilities for working with file system paths.
+pub mod path;
+
+/// Utilities for redacting secrets before they're logged.
+pub mod redact;
+
 #[cfg(feature = "toml")]
 /// Utilities for parsing and formatting TOML, backed by `toml`.
 pub mod toml;
@@ -1,13 +1,21 @@
+use crate::cancel::CancellationToken;
 use crate::fs::{self, FsError};
+use crate::redact::redact_url;
 use async_trait::async_trait;
+use futures::stream::{self, StreamExt};
+use reqwest::header::{self, HeaderMap, HeaderValue};
 use reqwest::{Client, Response};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
 use std::cmp;
 use std::fmt::Debug;
+use std::future::Future;
 use std::io::Write;
 use std::net::{Shutdown, SocketAddr, TcpStream, ToSocketAddrs};
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tracing::{instrument, trace};
 use url::Url;
 
@@ -16,20 +24,74 @@ pub use crate::net_error::NetError;
 #[async_trait]
 pub trait Downloader: Send {
     async fn download(&self, url: Url) -> Result<Response, NetError>;
+
+    /// Download the provided URL while sending additional request headers,
+    /// for example, conditional headers like `If-None-Match`.
+    ///
+    /// The default implementation *drops* the headers and calls
+    /// [`Downloader::download`], so conditional requests will always
+    /// re-download. Override this method to forward the headers.
+    // Written without `async` so that the default doesn't require `Self: Sync`.
+    fn download_with_headers<'life0, 'async_trait>(
+        &'life0 self,
+        url: Url,
+        _headers: HeaderMap,
+    ) -> Pin<Box<dyn Future<Output = Result<Response, NetError>> + Send + 'async_trait>>
+    where
+        'life0: 'async_trait,
+        Self: 'async_trait,
+    {
+        self.download(url)
+    }
 }
 
 pub type BoxedDownloader = Box<dyn Downloader>;
 
+/// The default downloader, backed by a `reqwest` [`Client`]. By default,
+/// the `HTTP_PROXY`, `HTTPS_PROXY`, and `NO_PROXY` environment variables
+/// are used to route requests through a proxy.
 #[derive(Default)]
 pub struct DefaultDownloader {
     client: reqwest::Client,
 }
 
+impl DefaultDownloader {
+    /// Create a new downloader that routes all requests through the provided
+    /// proxy URL. Hosts listed in the `NO_PROXY` environment variable will
+    /// bypass the proxy.
+    pub fn with_proxy(proxy_url: &str) -> Result<Self, NetError> {
+        let handle_error = |error: reqwest::Error| NetError::ProxyFailed {
+            url: proxy_url.to_owned(),
+            error: Box::new(error),
+        };
+
+        let proxy = reqwest::Proxy::all(proxy_url)
+            .map_err(handle_error)?
+            .no_proxy(reqwest::NoProxy::from_env());
+
+        let client = Client::builder()
+            .proxy(proxy)
+            .build()
+            .map_err(handle_error)?;
+
+        Ok(Self { client })
+    }
+}
+
 #[async_trait]
 impl Downloader for DefaultDownloader {
     async fn download(&self, url: Url) -> Result<Response, NetError> {
+        self.download_with_headers(url, HeaderMap::new()).await
+    }
+
+    async fn download_with_headers(
+        &self,
+        url: Url,
+        headers: HeaderMap,
+    ) -> Result<Response, NetError> {
         self.client
             .get(url.clone())
+            .headers(headers)
             .send()
             .await
             .map_err(|error| NetError::Http {
@@ -43,48 +105,98 @@ pub type OnChunkFn = Box<dyn Fn(u64, u64) + Send>;
 
 #[derive(Default)]
 pub struct DownloadOptions {
+    /// Token to cancel the download with. When cancelled, the partially
+    /// written file is removed and [`NetError::Cancelled`] is returned.
+    pub cancel_token: Option<CancellationToken>,
     pub downloader: Option<BoxedDownloader>,
+    /// Maximum download throughput in bytes per second. When set, writing
+    /// chunks is paused as needed to stay at or below the target rate.
+    pub max_bytes_per_sec: Option<u64>,
     pub on_chunk: Option<OnChunkFn>,
+    /// Explicit proxy URL to route requests through. Only applies
+    /// when a custom downloader has not been provided.
+    pub proxy: Option<String>,
 }
 
 /// Download a file from the provided source URL, to the destination file path,
 /// using custom options.
-#[instrument(name = "download_from_url", skip(options))]
+#[instrument(
+    name = "download_from_url",
+    skip(source_url, options),
+    fields(source_url = %redact_url(&source_url))
+)]
 pub async fn download_from_url_with_options<S: AsRef<str> + Debug, D: AsRef<Path> + Debug>(
     source_url: S,
     dest_file: D,
     options: DownloadOptions,
 ) -> Result<(), NetError> {
-    let source_url = source_url.as_ref();
-    let dest_file = dest_file.as_ref();
-    let downloader = options
-        .downloader
-        .unwrap_or_else(|| Box::new(DefaultDownloader::default()));
+    let downloader = resolve_downloader(options.downloader, options.proxy.as_deref())?;
+
+    download_with_downloader(
+        downloader.as_ref(),
+        source_url.as_ref(),
+        dest_file.as_ref(),
+        options.on_chunk.as_deref(),
+        options.max_bytes_per_sec,
+        options.cancel_token.as_ref(),
+    )
+    .await
+}
 
-    let handle_fs_error = |error: std::io::Error| FsError::Write {
-        path: dest_file.to_path_buf(),
-        error: Box::new(error),
-    };
-    let handle_net_error = |error: reqwest::Error| NetError::Http {
-        error: Box::new(error),
+// Boxes the default downloader into the trait object type of the options.
+trait FromDefaultDownloader {
+    fn from_default(downloader: DefaultDownloader) -> Box<Self>;
+}
+
+impl FromDefaultDownloader for dyn Downloader {
+    fn from_default(downloader: DefaultDownloader) -> Box<Self> {
+        Box::new(downloader)
+    }
+}
+
+impl FromDefaultDownloader for dyn Downloader + Sync {
+    fn from_default(downloader: DefaultDownloader) -> Box<Self> {
+        Box::new(downloader)
+    }
+}
+
+// Use the provided downloader, otherwise create a default one that
+// routes requests through the proxy, if provided.
+fn resolve_downloader<D: FromDefaultDownloader + ?Sized>(
+    downloader: Option<Box<D>>,
+    proxy: Option<&str>,
+) -> Result<Box<D>, NetError> {
+    if let Some(downloader) = downloader {
+        return Ok(downloader);
+    }
+
+    Ok(D::from_default(match proxy {
+        Some(proxy) => DefaultDownloader::with_proxy(proxy)?,
+        None => DefaultDownloader::default(),
+    }))
+}
+
+fn parse_url(source_url: &str) -> Result<Url, NetError> {
+    Url::parse(source_url).map_err(|error| NetError::UrlParseFailed {
         url: source_url.to_owned(),
-    };
+        error: Box::new(error),
+    })
+}
 
-    trace!(
-        source_url,
-        dest_file = ?dest_file,
-        "Downloading file from remote URL to local file",
-    );
+fn check_cancelled(
+    source_url: &str,
+    cancel_token: Option<&CancellationToken>,
+) -> Result<(), NetError> {
+    if cancel_token.is_some_and(|token| token.is_cancelled()) {
+        return Err(NetError::Cancelled {
+            url: source_url.to_owned(),
+        });
+    }
 
-    // Fetch the file from the HTTP source
-    let mut response = downloader
-        .download(
-            Url::parse(source_url).map_err(|error| NetError::UrlParseFailed {
-                url: source_url.to_owned(),
-                error: Box::new(error),
-            })?,
-        )
-        .await?;
+    Ok(())
+}
+
+fn check_response_status(source_url: &str, response: &Response) -> Result<(), NetError> {
     let status = response.status();
 
     if status.as_u16() == 404 {
@@ -100,23 +212,95 @@ pub async fn download_from_url_with_options<S: AsRef<str> + Debug, D: AsRef<Path
         });
     }
 
+    Ok(())
+}
+
+async fn download_with_downloader(
+    downloader: &dyn Downloader,
+    source_url: &str,
+    dest_file: &Path,
+    on_chunk: Option<&(dyn Fn(u64, u64) + Send)>,
+    max_bytes_per_sec: Option<u64>,
+    cancel_token: Option<&CancellationToken>,
+) -> Result<(), NetError> {
+    trace!(
+        source_url = %redact_url(source_url),
+        dest_file = ?dest_file,
+        "Downloading file from remote URL to local file",
+    );
+
+    // Avoid sending the request at all if already cancelled
+    check_cancelled(source_url, cancel_token)?;
+
+    // Fetch the file from the HTTP source
+    let response = downloader.download(parse_url(source_url)?).await?;
+
+    check_response_status(source_url, &response)?;
+
+    write_response_to_file(
+        response,
+        source_url,
+        dest_file,
+        on_chunk,
+        max_bytes_per_sec,
+        cancel_token,
+    )
+    .await
+}
+
+async fn write_response_to_file(
+    mut response: Response,
+    source_url: &str,
+    dest_file: &Path,
+    on_chunk: Option<&(dyn Fn(u64, u64) + Send)>,
+    max_bytes_per_sec: Option<u64>,
+    cancel_token: Option<&CancellationToken>,
+) -> Result<(), NetError> {
+    let handle_fs_error = |error: std::io::Error| FsError::Write {
+        path: dest_file.to_path_buf(),
+        error: Box::new(error),
+    };
+    let handle_net_error = |error: reqwest::Error| NetError::Http {
+        error: Box::new(error),
+        url: source_url.to_owned(),
+    };
+
     // Wrap in a closure so that we can capture the error and cleanup
     let do_write = || async {
         let mut file = fs::create_file(dest_file)?;
 
         // Write the bytes in chunks
-        if let Some(on_chunk) = options.on_chunk {
+        if on_chunk.is_some() || max_bytes_per_sec.is_some() || cancel_token.is_some() {
             let total_size = response.content_length().unwrap_or(0);
-            let mut current_size: u64 = 0;
+            let mut written_size: u64 = 0;
+            let started = Instant::now();
 
-            on_chunk(0, total_size);
+            if let Some(on_chunk) = on_chunk {
+                on_chunk(0, total_size);
+            }
 
             while let Some(chunk) = response.chunk().await.map_err(handle_net_error)? {
+                check_cancelled(source_url, cancel_token)?;
+
                 file.write_all(&chunk).map_err(handle_fs_error)?;
 
-                current_size = cmp::min(current_size + (chunk.len() as u64), total_size);
+                written_size += chunk.len() as u64;
+
+                if let Some(on_chunk) = on_chunk {
+                    on_chunk(cmp::min(written_size, total_size), total_size);
+                }
+
+                // Sleep until the elapsed time matches the target rate
+                if let Some(rate) = max_bytes_per_sec.filter(|rate| *rate > 0) {
+                    let expected = Duration::from_secs_f64(written_size as f64 / rate as f64);
+                    let elapsed = started.elapsed();
+
+                    if expected > elapsed {
+                        check_cancelled(source_url, cancel_token)?;
 
-                on_chunk(current_size, total_size);
+                        tokio::time::sleep(expected - elapsed).await;
+                    }
+                }
             }
         }
         // Write all bytes at once
@@ -153,12 +337,219 @@ pub async fn download_from_url_with_client<S: AsRef<str> + Debug, D: AsRef<Path>
             downloader: Some(Box::new(DefaultDownloader {
                 client: client.to_owned(),
             })),
-            on_chunk: None,
+            ..DownloadOptions::default()
         },
     )
     .await
 }
 
+/// HTTP caching metadata of a previously downloaded file, used
+/// to make conditional requests with [`download_from_url_if_modified`].
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct DownloadMetadata {
+    /// Value of the `ETag` response header.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub etag: Option<String>,
+    /// Value of the `Last-Modified` response header.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_modified: Option<String>,
+}
+
+impl DownloadMetadata {
+    /// Return the path of the metadata file that is stored alongside the
+    /// provided downloaded file, for example, `file.txt.metadata.json`.
+    pub fn path_for(dest_file: &Path) -> PathBuf {
+        let mut name = dest_file.file_name().unwrap_or_default().to_os_string();
+        name.push(".metadata.json");
+
+        dest_file.with_file_name(name)
+    }
+
+    /// Load the metadata stored alongside the provided downloaded file, if it exists.
+    pub fn load(dest_file: &Path) -> Option<Self> {
+        let contents = fs::read_file(Self::path_for(dest_file)).ok()?;
+
+        serde_json::from_str(&contents).ok()
+    }
+
+    fn from_response(response: &Response) -> Self {
+        let get_header = |name| {
+            response
+                .headers()
+                .get(name)
+                .and_then(|value: &HeaderValue| value.to_str().ok())
+                .map(|value| value.to_owned())
+        };
+
+        Self {
+            etag: get_header(header::ETAG),
+            last_modified: get_header(header::LAST_MODIFIED),
+        }
+    }
+
+    fn to_headers(&self) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+
+        if let Some(value) = self
+            .etag
+            .as_ref()
+            .and_then(|etag| HeaderValue::from_str(etag).ok())
+        {
+            headers.insert(header::IF_NONE_MATCH, value);
+        }
+
+        if let Some(value) = self
+            .last_modified
+            .as_ref()
+            .and_then(|last_modified| HeaderValue::from_str(last_modified).ok())
+        {
+            headers.insert(header::IF_MODIFIED_SINCE, value);
+        }
+
+        headers
+    }
+}
+
+/// Status of a conditional download.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Downloaded {
+    /// The remote file was downloaded and written to the destination.
+    Modified,
+    /// The remote file has not changed (`304 Not Modified`),
+    /// and the existing destination file was kept.
+    NotModified,
+}
+
+/// Download a file from the provided source URL, to the destination file path,
+/// but only if it has been modified since it was last downloaded. This sends
+/// `If-None-Match` and `If-Modified-Since` headers based on the [`DownloadMetadata`]
+/// stored alongside the destination file, and keeps the existing file when
+/// the server responds with `304 Not Modified`.
+#[instrument(
+    name = "download_from_url_if_modified",
+    skip(source_url, options),
+    fields(source_url = %redact_url(&source_url))
+)]
+pub async fn download_from_url_if_modified<S: AsRef<str> + Debug, D: AsRef<Path> + Debug>(
+    source_url: S,
+    dest_file: D,
+    options: DownloadOptions,
+) -> Result<Downloaded, NetError> {
+    let source_url = source_url.as_ref();
+    let dest_file = dest_file.as_ref();
+    let downloader = resolve_downloader(options.downloader, options.proxy.as_deref())?;
+
+    // Only send conditional headers if we have a file to keep
+    let headers = if dest_file.exists() {
+        DownloadMetadata::load(dest_file)
+            .map(|metadata| metadata.to_headers())
+            .unwrap_or_default()
+    } else {
+        HeaderMap::new()
+    };
+
+    trace!(
+        source_url = %redact_url(source_url),
+        dest_file = ?dest_file,
+        conditional = !headers.is_empty(),
+        "Downloading file from remote URL to local file if modified",
+    );
+
+    check_cancelled(source_url, options.cancel_token.as_ref())?;
+
+    let response = downloader
+        .download_with_headers(parse_url(source_url)?, headers)
+        .await?;
+
+    if response.status() == reqwest::StatusCode::NOT_MODIFIED && dest_file.exists() {
+        trace!(dest_file = ?dest_file, "Remote file not modified, keeping existing file");
+
+        return Ok(Downloaded::NotModified);
+    }
+
+    check_response_status(source_url, &response)?;
+
+    let metadata = DownloadMetadata::from_response(&response);
+
+    write_response_to_file(
+        response,
+        source_url,
+        dest_file,
+        options.on_chunk.as_deref(),
+        options.max_bytes_per_sec,
+        options.cancel_token.as_ref(),
+    )
+    .await?;
+
+    let metadata_file = DownloadMetadata::path_for(dest_file);
+
+    if metadata == DownloadMetadata::default() {
+        fs::remove_file(metadata_file)?;
+    } else {
+        fs::write_file(
+            metadata_file,
+            serde_json::to_string(&metadata).unwrap_or_default(),
+        )?;
+    }
+
+    Ok(Downloaded::Modified)
+}
+
+pub type OnItemChunkFn = Box<dyn Fn(usize, u64, u64) + Send + Sync>;
+
+#[derive(Default)]
+pub struct DownloadManyOptions {
+    pub downloader: Option<Box<dyn Downloader + Sync>>,
+    /// Called for each chunk written, with the index of the item
+    /// being downloaded, the current size, and the total size.
+    pub on_chunk: Option<OnItemChunkFn>,
+    pub proxy: Option<String>,
+}
+
+/// Download many files from the provided source URLs, to their destination file
+/// paths, with up to `concurrency` downloads running at once. A single downloader
+/// is shared across all downloads, and a result is returned for each item, in
+/// the same order as the provided items.
+#[instrument(skip(items, options), fields(count = items.len()))]
+pub async fn download_many(
+    items: Vec<(Url, PathBuf)>,
+    concurrency: usize,
+    options: DownloadManyOptions,
+) -> Result<Vec<Result<(), NetError>>, NetError> {
+    let downloader = resolve_downloader(options.downloader, options.proxy.as_deref())?;
+    let downloader = downloader.as_ref();
+    let on_chunk = options.on_chunk.as_ref();
+
+    trace!(
+        count = items.len(),
+        concurrency,
+        "Downloading many files from remote URLs"
+    );
+
+    let results = stream::iter(items.into_iter().enumerate())
+        .map(|(index, (source_url, dest_file))| async move {
+            let on_item_chunk =
+                on_chunk.map(|on_chunk| move |current, total| on_chunk(index, current, total));
+
+            download_with_downloader(
+                downloader,
+                source_url.as_str(),
+                &dest_file,
+                on_item_chunk
+                    .as_ref()
+                    .map(|on_item_chunk| on_item_chunk as &(dyn Fn(u64, u64) + Send)),
+                None,
+                None,
+            )
+            .await
+        })
+        .buffered(concurrency.max(1))
+        .collect()
+        .await;
+
+    Ok(results)
+}
+
 /// Download a file from the provided source URL, to the destination file path.
 pub async fn download_from_url<S: AsRef<str> + Debug, D: AsRef<Path> + Debug>(
     source_url: S,
@@ -167,6 +558,114 @@ pub async fn download_from_url<S: AsRef<str> + Debug, D: AsRef<Path> + Debug>(
     download_from_url_with_options(source_url, dest_file, DownloadOptions::default()).await
 }
 
+/// The default maximum size of a response body (10 MiB) for [`fetch_text`] and [`fetch_json`].
+pub const DEFAULT_FETCH_MAX_SIZE: u64 = 10 * 1024 * 1024;
+
+pub struct FetchOptions {
+    pub downloader: Option<BoxedDownloader>,
+    /// Maximum size of the response body in bytes. Responses larger
+    /// than this will error instead of being buffered into memory.
+    pub max_size: u64,
+    /// Explicit proxy URL to route requests through. Only applies
+    /// when a custom downloader has not been provided.
+    pub proxy: Option<String>,
+}
+
+impl Default for FetchOptions {
+    fn default() -> Self {
+        Self {
+            downloader: None,
+            max_size: DEFAULT_FETCH_MAX_SIZE,
+            proxy: None,
+        }
+    }
+}
+
+/// Fetch the body of the provided URL as a string, using custom options.
+/// If the body is not valid UTF-8, an error is returned.
+#[instrument(
+    name = "fetch_text",
+    skip(source_url, options),
+    fields(source_url = %redact_url(&source_url))
+)]
+pub async fn fetch_text_with_options<S: AsRef<str> + Debug>(
+    source_url: S,
+    options: FetchOptions,
+) -> Result<String, NetError> {
+    let source_url = source_url.as_ref();
+    let downloader = resolve_downloader(options.downloader, options.proxy.as_deref())?;
+
+    trace!(
+        source_url = %redact_url(source_url),
+        "Fetching contents from remote URL",
+    );
+
+    let mut response = downloader.download(parse_url(source_url)?).await?;
+
+    check_response_status(source_url, &response)?;
+
+    let too_large = || NetError::ResponseTooLarge {
+        url: source_url.to_owned(),
+        max_size: options.max_size,
+    };
+
+    // Fail early if the server tells us the size upfront
+    if response
+        .content_length()
+        .is_some_and(|size| size > options.max_size)
+    {
+        return Err(too_large());
+    }
+
+    // Otherwise verify the size as the chunks are received
+    let mut body = vec![];
+
+    while let Some(chunk) = response.chunk().await.map_err(|error| NetError::Http {
+        error: Box::new(error),
+        url: source_url.to_owned(),
+    })? {
+        if (body.len() + chunk.len()) as u64 > options.max_size {
+            return Err(too_large());
+        }
+
+        body.extend_from_slice(&chunk);
+    }
+
+    String::from_utf8(body).map_err(|error| NetError::InvalidUtf8 {
+        url: source_url.to_owned(),
+        error: Box::new(error),
+    })
+}
+
+/// Fetch the body of the provided URL as a string. The body is limited
+/// to [`DEFAULT_FETCH_MAX_SIZE`] bytes.
+pub async fn fetch_text<S: AsRef<str> + Debug>(source_url: S) -> Result<String, NetError> {
+    fetch_text_with_options(source_url, FetchOptions::default()).await
+}
+
+/// Fetch the body of the provided URL and deserialize it from JSON
+/// into the required type, using custom options.
+pub async fn fetch_json_with_options<S: AsRef<str> + Debug, D: DeserializeOwned>(
+    source_url: S,
+    options: FetchOptions,
+) -> Result<D, NetError> {
+    let source_url = source_url.as_ref();
+    let body = fetch_text_with_options(source_url, options).await?;
+
+    serde_json::from_str(&body).map_err(|error| NetError::InvalidJson {
+        url: source_url.to_owned(),
+        error: Box::new(error),
+    })
+}
+
+/// Fetch the body of the provided URL and deserialize it from JSON
+/// into the required type. The body is limited to [`DEFAULT_FETCH_MAX_SIZE`] bytes.
+pub async fn fetch_json<S: AsRef<str> + Debug, D: DeserializeOwned>(
+    source_url: S,
+) -> Result<D, NetError> {
+    fetch_json_with_options(source_url, FetchOptions::default()).await
+}
+
 mod offline {
     use super::*;
 
@@ -8,6 +8,9 @@ pub enum NetError {
     #[error(transparent)]
     Fs(#[from] Box<FsError>),
 
+    #[error("Download from {} was cancelled.", .url.style(Style::Url))]
+    Cancelled { url: String },
+
     #[error("Failed to make HTTP request for {}.\n{error}", .url.style(Style::Url))]
     Http {
         url: String,
@@ -27,6 +30,33 @@ pub enum NetError {
     #[error("Unable to download file, the URL {} does not exist.", .url.style(Style::Url))]
     UrlNotFound { url: String },
 
+    #[error("Failed to parse JSON response from {}.\n{error}", .url.style(Style::Url))]
+    InvalidJson {
+        url: String,
+        #[source]
+        error: Box<serde_json::Error>,
+    },
+
+    #[error("Response from {} is not valid UTF-8.\n{error}", .url.style(Style::Url))]
+    InvalidUtf8 {
+        url: String,
+        #[source]
+        error: Box<std::string::FromUtf8Error>,
+    },
+
+    #[error(
+        "Response from {} exceeded the maximum size of {max_size} bytes.",
+        .url.style(Style::Url),
+    )]
+    ResponseTooLarge { url: String, max_size: u64 },
+
+    #[error("Failed to configure HTTP proxy {}.\n{error}", .url.style(Style::Url))]
+    ProxyFailed {
+        url: String,
+        #[source]
+        error: Box<reqwest::Error>,
+    },
+
     #[error("Failed to parse URL {}.\n{error}", .url.style(Style::Url))]
     UrlParseFailed {
         url: String,
@@ -42,6 +72,10 @@ pub enum NetError {
     #[error(transparent)]
     Fs(#[from] Box<FsError>),
 
+    #[diagnostic(code(net::cancelled))]
+    #[error("Download from {} was cancelled.", .url.style(Style::Url))]
+    Cancelled { url: String },
+
     #[diagnostic(code(net::http))]
     #[error("Failed to make HTTP request for {}.", .url.style(Style::Url))]
     Http {
@@ -65,6 +99,37 @@ pub enum NetError {
     #[error("Unable to download file, the URL {} does not exist.", .url.style(Style::Url))]
     UrlNotFound { url: String },
 
+    #[diagnostic(code(net::invalid_json))]
+    #[error("Failed to parse JSON response from {}.", .url.style(Style::Url))]
+    InvalidJson {
+        url: String,
+        #[source]
+        error: Box<serde_json::Error>,
+    },
+
+    #[diagnostic(code(net::invalid_utf8))]
+    #[error("Response from {} is not valid UTF-8.", .url.style(Style::Url))]
+    InvalidUtf8 {
+        url: String,
+        #[source]
+        error: Box<std::string::FromUtf8Error>,
+    },
+
+    #[diagnostic(code(net::response_too_large))]
+    #[error(
+        "Response from {} exceeded the maximum size of {max_size} bytes.",
+        .url.style(Style::Url),
+    )]
+    ResponseTooLarge { url: String, max_size: u64 },
+
+    #[diagnostic(code(net::invalid_proxy))]
+    #[error("Failed to configure HTTP proxy {}.", .url.style(Style::Url))]
+    ProxyFailed {
+        url: String,
+        #[source]
+        error: Box<reqwest::Error>,
+    },
+
     #[diagnostic(code(net::invalid_url))]
     #[error("Failed to parse URL {}.", .url.style(Style::Url))]
     UrlParseFailed {
@@ -74,6 +139,38 @@ pub enum NetError {
     },
 }
 
+impl NetError {
+    /// Return true if the error is likely temporary, and the request is worth
+    /// retrying. This includes connection failures, timeouts, server errors (5xx),
+    /// and rate limiting (429), but not missing resources (404) or invalid URLs.
+    pub fn is_transient(&self) -> bool {
+        match self {
+            Self::Http { error, .. } => {
+                error.is_connect()
+                    || error.is_timeout()
+                    || error.status().is_some_and(is_transient_status)
+            }
+            Self::DownloadFailed { status, .. } => status
+                .split_whitespace()
+                .next()
+                .and_then(|code| code.parse::<u16>().ok())
+                .and_then(|code| reqwest::StatusCode::from_u16(code).ok())
+                .is_some_and(is_transient_status),
+            Self::Cancelled { .. } | Self::Fs(_) | Self::HttpUnknown { .. } => false,
+            Self::UrlNotFound { .. } => false,
+            Self::InvalidJson { .. } | Self::InvalidUtf8 { .. } => false,
+            Self::ResponseTooLarge { .. } => false,
+            Self::ProxyFailed { .. } | Self::UrlParseFailed { .. } => false,
+        }
+    }
+}
+
+fn is_transient_status(status: reqwest::StatusCode) -> bool {
+    status.is_server_error()
+        || status == reqwest::StatusCode::TOO_MANY_REQUESTS
+        || status == reqwest::StatusCode::REQUEST_TIMEOUT
+}
+
 impl From<FsError> for NetError {
     fn from(e: FsError) -> NetError {
         NetError::Fs(Box::new(e))
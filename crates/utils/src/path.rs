@@ -0,0 +1,199 @@
+use std::path::{Component, Path, PathBuf};
+
+/// Lexically normalize the provided path, by removing `.` components and resolving
+/// `..` components against their parent, without touching the file system.
+/// Leading `..` components of a relative path are preserved, while `..`
+/// components that would escape the root of an absolute path are dropped.
+pub fn normalize<T: AsRef<Path>>(path: T) -> PathBuf {
+    let mut components: Vec<Component> = vec![];
+
+    for component in path.as_ref().components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => match components.last() {
+                Some(Component::Normal(_)) => {
+                    components.pop();
+                }
+                Some(Component::RootDir | Component::Prefix(_)) => {}
+                _ => {
+                    components.push(component);
+                }
+            },
+            _ => {
+                components.push(component);
+            }
+        };
+    }
+
+    components.into_iter().collect()
+}
+
+#[cfg(not(windows))]
+fn is_same_component(a: &Component, b: &Component) -> bool {
+    a == b
+}
+
+// Windows paths are case-insensitive, and the same drive may be
+// referenced with either a normal (`C:`) or verbatim (`\\?\C:`) prefix.
+#[cfg(windows)]
+fn is_same_component(a: &Component, b: &Component) -> bool {
+    use std::path::Prefix;
+
+    fn drive(component: &Component) -> Option<u8> {
+        match component {
+            Component::Prefix(prefix) => match prefix.kind() {
+                Prefix::Disk(letter) | Prefix::VerbatimDisk(letter) => {
+                    Some(letter.to_ascii_uppercase())
+                }
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    match (drive(a), drive(b)) {
+        (Some(a), Some(b)) => a == b,
+        _ => a
+            .as_os_str()
+            .to_string_lossy()
+            .eq_ignore_ascii_case(&b.as_os_str().to_string_lossy()),
+    }
+}
+
+/// Return true if the candidate path is located within the root path (or is the root
+/// itself). Both paths are lexically [normalized](normalize) without touching the
+/// file system, so `..` escapes are correctly rejected. A relative candidate is
+/// resolved against the root. On Windows, drive letters and components are
+/// compared case-insensitively. An empty root or candidate is never within.
+pub fn is_within<R: AsRef<Path>, C: AsRef<Path>>(root: R, candidate: C) -> bool {
+    let root = root.as_ref();
+    let candidate = candidate.as_ref();
+
+    if root.as_os_str().is_empty() || candidate.as_os_str().is_empty() {
+        return false;
+    }
+
+    let root = normalize(root);
+    let candidate = if candidate.has_root() {
+        normalize(candidate)
+    } else {
+        normalize(root.join(candidate))
+    };
+
+    // A root of `.` normalizes to nothing, so it can't contain absolute paths
+    if root.as_os_str().is_empty() && candidate.has_root() {
+        return false;
+    }
+
+    // A path that escapes upwards can't be reasoned about lexically
+    if root
+        .components()
+        .chain(candidate.components())
+        .any(|component| component == Component::ParentDir)
+    {
+        return false;
+    }
+
+    let mut candidate_components = candidate.components();
+
+    for root_component in root.components() {
+        match candidate_components.next() {
+            Some(component) if is_same_component(&root_component, &component) => {}
+            _ => return false,
+        };
+    }
+
+    true
+}
+
+/// Return the file name of an executable for the current platform, by appending
+/// an `.exe` extension on Windows (when not already present). On other platforms,
+/// the name is returned as-is.
+pub fn exe_name<T: AsRef<str>>(base: T) -> String {
+    let base = base.as_ref();
+
+    if cfg!(windows) && !base.to_lowercase().ends_with(".exe") {
+        format!("{base}.exe")
+    } else {
+        base.to_owned()
+    }
+}
+
+/// Return a list of file extensions that are considered executable on the current
+/// platform. On Windows, this is derived from the `PATHEXT` environment variable,
+/// falling back to `.com`, `.exe`, `.bat`, and `.cmd`. On other platforms, this is empty.
+pub fn exe_extensions() -> Vec<String> {
+    if !cfg!(windows) {
+        return vec![];
+    }
+
+    match std::env::var("PATHEXT") {
+        Ok(value) if !value.is_empty() => value
+            .split(';')
+            .filter(|ext| !ext.is_empty())
+            .map(|ext| ext.to_lowercase())
+            .collect(),
+        _ => vec![".com".into(), ".exe".into(), ".bat".into(), ".cmd".into()],
+    }
+}
+
+#[cfg(unix)]
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+
+    path.metadata()
+        .is_ok_and(|meta| meta.is_file() && meta.permissions().mode() & 0o111 != 0)
+}
+
+#[cfg(not(unix))]
+fn is_executable(path: &Path) -> bool {
+    path.is_file()
+}
+
+/// Search the provided directories, in order, for an executable with the provided
+/// name, and return the first match. On Windows, each [executable extension](exe_extensions)
+/// is appended to the name while searching (unless it already has one), while on other
+/// platforms, the file must have an executable permission bit.
+pub fn find_executable<N, I, P>(name: N, dirs: I) -> Option<PathBuf>
+where
+    N: AsRef<str>,
+    I: IntoIterator<Item = P>,
+    P: AsRef<Path>,
+{
+    let name = name.as_ref();
+    let extensions = exe_extensions();
+    let has_extension = extensions
+        .iter()
+        .any(|ext| name.to_lowercase().ends_with(ext));
+
+    for dir in dirs {
+        let dir = dir.as_ref();
+
+        if extensions.is_empty() || has_extension {
+            let path = dir.join(name);
+
+            if is_executable(&path) {
+                return Some(path);
+            }
+
+            continue;
+        }
+
+        for ext in &extensions {
+            let path = dir.join(format!("{name}{ext}"));
+
+            if is_executable(&path) {
+                return Some(path);
+            }
+        }
+    }
+
+    None
+}
+
+/// Search the directories in the `PATH` environment variable for an executable
+/// with the provided name, and return the first match. See [`find_executable`].
+#[inline]
+pub fn find_executable_on_path<N: AsRef<str>>(name: N) -> Option<PathBuf> {
+    find_executable(name, crate::env::paths())
+}
@@ -0,0 +1,91 @@
+/// Placeholder that replaces redacted values.
+pub const REDACTED: &str = "REDACTED";
+
+/// Query parameter names (compared case-insensitively) whose values
+/// are considered secret and will be redacted by [`redact_url`].
+pub const SECRET_QUERY_PARAMS: &[&str] = &[
+    "access_token",
+    "api_key",
+    "apikey",
+    "auth",
+    "authorization",
+    "client_secret",
+    "code",
+    "key",
+    "password",
+    "refresh_token",
+    "secret",
+    "sig",
+    "signature",
+    "token",
+    "x-amz-credential",
+    "x-amz-security-token",
+    "x-amz-signature",
+];
+
+fn is_secret_param(name: &str) -> bool {
+    SECRET_QUERY_PARAMS
+        .iter()
+        .any(|param| param.eq_ignore_ascii_case(name))
+}
+
+fn redact_query(query: &str) -> String {
+    query
+        .split('&')
+        .map(|pair| match pair.split_once('=') {
+            Some((name, value)) if !value.is_empty() && is_secret_param(name) => {
+                format!("{name}={REDACTED}")
+            }
+            _ => pair.to_owned(),
+        })
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+/// Redact credentials from the provided URL so that it's safe to log.
+/// This strips the userinfo (`user:password@`) from the authority, and
+/// replaces the values of [known secret](SECRET_QUERY_PARAMS) query
+/// parameters. The URL is processed lexically, so invalid URLs are
+/// still redacted on a best-effort basis.
+pub fn redact_url<T: AsRef<str>>(url: T) -> String {
+    let url = url.as_ref();
+
+    // Split off the fragment first, as it may contain `?` or `@`
+    let (rest, fragment) = match url.split_once('#') {
+        Some((rest, fragment)) => (rest, Some(fragment)),
+        None => (url, None),
+    };
+
+    let (rest, query) = match rest.split_once('?') {
+        Some((rest, query)) => (rest, Some(query)),
+        None => (rest, None),
+    };
+
+    let mut result = match rest.split_once("://") {
+        Some((scheme, remainder)) => {
+            let (authority, path) = match remainder.find('/') {
+                Some(index) => remainder.split_at(index),
+                None => (remainder, ""),
+            };
+            let host = match authority.rsplit_once('@') {
+                Some((_, host)) => host,
+                None => authority,
+            };
+
+            format!("{scheme}://{host}{path}")
+        }
+        None => rest.to_owned(),
+    };
+
+    if let Some(query) = query {
+        result.push('?');
+        result.push_str(&redact_query(query));
+    }
+
+    if let Some(fragment) = fragment {
+        result.push('#');
+        result.push_str(fragment);
+    }
+
+    result
+}
@@ -1,8 +1,9 @@
-use crate::fs;
+use crate::{env, fs};
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 use std::fmt::Debug;
 use std::path::Path;
+use toml_edit::{Array, DocumentMut, Item, Table, Value};
 use tracing::{instrument, trace};
 
 pub use crate::toml_error::TomlError;
@@ -19,9 +20,9 @@ where
 {
     trace!("Parsing TOML");
 
-    toml::from_str(data.as_ref()).map_err(|error| TomlError::Parse {
-        error: Box::new(error),
-    })
+    let data = data.as_ref();
+
+    toml::from_str(data).map_err(|error| TomlError::new_parse(data, error))
 }
 
 /// Format and serialize the provided value into a string.
@@ -44,6 +45,156 @@ where
     }
 }
 
+/// Options to customize the formatting of [`format_with_options`]
+/// and [`write_file_with_options`].
+#[derive(Clone, Debug, Default)]
+pub struct TomlFormatOptions {
+    /// Format arrays of tables inline (`key = [{ ... }]`), instead of
+    /// as `[[key]]` blocks. Arrays within inline tables are always inline.
+    pub inline_array_tables: bool,
+
+    /// Format nested tables inline (`key = { ... }`), instead of as `[key]` blocks.
+    pub inline_tables: bool,
+
+    /// Format using the pretty serializer.
+    pub pretty: bool,
+}
+
+fn apply_table_styles(table: &mut Table, options: &TomlFormatOptions) {
+    for (mut key, item) in table.iter_mut() {
+        let value = std::mem::take(item);
+
+        *item = match value {
+            Item::Table(inner) if options.inline_tables => {
+                key.leaf_decor_mut().clear();
+                Item::Value(inner.into_inline_table().into())
+            }
+            Item::ArrayOfTables(inner) if options.inline_array_tables => {
+                key.leaf_decor_mut().clear();
+                Item::Value(inner.into_array().into())
+            }
+            Item::Value(inner) if inner.is_inline_table() && !options.inline_tables => {
+                Item::Value(inner)
+                    .into_table()
+                    .map(Item::Table)
+                    .unwrap_or_else(|v| v)
+            }
+            Item::Value(inner)
+                if !options.inline_array_tables
+                    && inner.as_array().is_some_and(|arr| {
+                        !arr.is_empty() && arr.iter().all(|v| v.is_inline_table())
+                    }) =>
+            {
+                Item::Value(inner)
+                    .into_array_of_tables()
+                    .map(Item::ArrayOfTables)
+                    .unwrap_or_else(|v| v)
+            }
+            other => other,
+        };
+
+        match item {
+            Item::Table(inner) => {
+                apply_table_styles(inner, options);
+            }
+            Item::ArrayOfTables(inner) => {
+                for inner_table in inner.iter_mut() {
+                    apply_table_styles(inner_table, options);
+                }
+            }
+            _ => {}
+        };
+    }
+}
+
+/// Format and serialize the provided value into a string, while customizing
+/// the style of nested tables and arrays of tables.
+#[instrument(name = "format_toml_with_options", skip(data))]
+pub fn format_with_options<D>(data: &D, options: TomlFormatOptions) -> Result<String, TomlError>
+where
+    D: ?Sized + Serialize,
+{
+    let output = format(data, options.pretty)?;
+
+    // Output was generated by the serializer, so should always be valid
+    let Ok(mut document) = output.parse::<DocumentMut>() else {
+        return Ok(output);
+    };
+
+    apply_table_styles(document.as_table_mut(), &options);
+
+    Ok(document.to_string())
+}
+
+fn apply_array_indentation(array: &mut Array, indent: &str, depth: usize) {
+    // Only multi-line arrays (as rendered by the pretty serializer) are re-indented
+    let multiline = array.iter().any(|value| {
+        value
+            .decor()
+            .prefix()
+            .and_then(|prefix| prefix.as_str())
+            .is_some_and(|prefix| prefix.contains('\n'))
+    });
+
+    for value in array.iter_mut() {
+        if multiline {
+            value
+                .decor_mut()
+                .set_prefix(format!("\n{}", indent.repeat(depth)));
+            value.decor_mut().set_suffix("");
+        }
+
+        if let Value::Array(inner) = value {
+            apply_array_indentation(inner, indent, if multiline { depth + 1 } else { depth });
+        }
+    }
+
+    if multiline {
+        array.set_trailing(format!("\n{}", indent.repeat(depth - 1)));
+        array.set_trailing_comma(true);
+    }
+}
+
+fn apply_table_indentation(table: &mut Table, indent: &str) {
+    for (_, item) in table.iter_mut() {
+        match item {
+            Item::Value(Value::Array(inner)) => {
+                apply_array_indentation(inner, indent, 1);
+            }
+            Item::Table(inner) => {
+                apply_table_indentation(inner, indent);
+            }
+            Item::ArrayOfTables(inner) => {
+                for inner_table in inner.iter_mut() {
+                    apply_table_indentation(inner_table, indent);
+                }
+            }
+            _ => {}
+        };
+    }
+}
+
+/// Format and serialize the provided value into a pretty string,
+/// while using the provided indentation for multi-line arrays.
+#[instrument(name = "format_toml_with_identation", skip(data))]
+pub fn format_with_identation<D>(data: &D, indent: &str) -> Result<String, TomlError>
+where
+    D: ?Sized + Serialize,
+{
+    trace!(indent, "Formatting TOML with preserved indentation");
+
+    let output = format(data, true)?;
+
+    // Output was generated by the serializer, so should always be valid
+    let Ok(mut document) = output.parse::<DocumentMut>() else {
+        return Ok(output);
+    };
+
+    apply_table_indentation(document.as_table_mut(), indent);
+
+    Ok(document.to_string())
+}
+
 /// Read a file at the provided path and deserialize into the required type.
 /// The path must already exist.
 #[inline]
@@ -58,10 +209,50 @@ where
 
     trace!(file = ?path, "Reading TOML file");
 
-    toml::from_str(&contents).map_err(|error| TomlError::ReadFile {
-        path: path.to_path_buf(),
-        error: Box::new(error),
-    })
+    toml::from_str(&contents)
+        .map_err(|error| TomlError::new_read_file(path, Some(&contents), error))
+}
+
+impl env::StringVisitor for TomlValue {
+    fn visit_strings(&mut self, op: &mut dyn FnMut(&mut String)) {
+        match self {
+            TomlValue::String(inner) => op(inner),
+            TomlValue::Array(inner) => {
+                for item in inner {
+                    item.visit_strings(op);
+                }
+            }
+            TomlValue::Table(inner) => {
+                for (_, item) in inner.iter_mut() {
+                    item.visit_strings(op);
+                }
+            }
+            _ => {}
+        };
+    }
+}
+
+/// Read a file at the provided path and deserialize into the required type,
+/// while substituting `${VAR}` references in string values using the provided
+/// lookup function. Pass [`env::lookup_var`] to use the current process environment.
+/// Only string values are interpolated, not keys. The path must already exist.
+#[instrument(name = "read_toml_interpolated", skip(lookup))]
+pub fn read_file_interpolated<P, D, F>(path: P, lookup: F) -> Result<D, TomlError>
+where
+    P: AsRef<Path> + Debug,
+    D: DeserializeOwned,
+    F: Fn(&str) -> Option<String>,
+{
+    let path = path.as_ref();
+    let mut value: TomlValue = read_file(path)?;
+
+    trace!(file = ?path, "Interpolating TOML file");
+
+    env::interpolate_strings(&mut value, lookup);
+
+    value
+        .try_into()
+        .map_err(|error| TomlError::new_read_file(path, None, error))
 }
 
 /// Write a file and serialize the provided data to the provided path. If the parent directory
@@ -93,3 +284,56 @@ where
 
     Ok(())
 }
+
+/// Write a file and serialize the provided data to the provided path, while customizing
+/// the style of nested tables and arrays of tables. If the parent directory
+/// does not exist, it will be created.
+#[inline]
+#[instrument(name = "write_toml_with_options", skip(toml))]
+pub fn write_file_with_options<P, D>(
+    path: P,
+    toml: &D,
+    options: TomlFormatOptions,
+) -> Result<(), TomlError>
+where
+    P: AsRef<Path> + Debug,
+    D: ?Sized + Serialize,
+{
+    let path = path.as_ref();
+
+    trace!(file = ?path, "Writing TOML file");
+
+    fs::write_file(path, format_with_options(toml, options)?)?;
+
+    Ok(())
+}
+
+/// Write a file and serialize the provided data to the provided path, while taking the
+/// closest `.editorconfig` into account. If the parent directory does not exist,
+/// it will be created.
+///
+/// This function is used for consumer facing files, like configs.
+#[cfg(feature = "editor-config")]
+#[inline]
+#[instrument(name = "write_toml_with_config", skip(toml))]
+pub fn write_file_with_config<P, D>(path: P, toml: &D, pretty: bool) -> Result<(), TomlError>
+where
+    P: AsRef<Path> + Debug,
+    D: ?Sized + Serialize,
+{
+    if !pretty {
+        return write_file(path, &toml, false);
+    }
+
+    trace!(file = ?path, "Writing TOML file with .editorconfig");
+
+    let path = path.as_ref();
+    let editor_config = fs::get_editor_config_props(path)?;
+
+    let mut data = format_with_identation(toml, &editor_config.indent)?;
+    editor_config.apply_serialized_eof(&mut data);
+
+    fs::write_file(path, data)?;
+
+    Ok(())
+}
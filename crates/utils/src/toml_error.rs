@@ -1,8 +1,11 @@
 use crate::fs::FsError;
 use starbase_styles::{Style, Stylize};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use thiserror::Error;
 
+#[cfg(feature = "miette")]
+use miette::{NamedSource, SourceSpan};
+
 #[cfg(not(feature = "miette"))]
 #[derive(Error, Debug)]
 pub enum TomlError {
@@ -19,6 +22,7 @@ pub enum TomlError {
     Parse {
         #[source]
         error: Box<toml::de::Error>,
+        location: Option<(usize, usize)>,
     },
 
     #[error("Failed to parse TOML file {}.\n{error}", .path.style(Style::Path))]
@@ -26,6 +30,7 @@ pub enum TomlError {
         path: PathBuf,
         #[source]
         error: Box<toml::de::Error>,
+        location: Option<(usize, usize)>,
     },
 
     #[error("Failed to format TOML for file {}.\n{error}", .path.style(Style::Path))]
@@ -55,6 +60,12 @@ pub enum TomlError {
     Parse {
         #[source]
         error: Box<toml::de::Error>,
+        location: Option<(usize, usize)>,
+
+        #[source_code]
+        src: Option<NamedSource<String>>,
+        #[label("Failed here")]
+        span: Option<SourceSpan>,
     },
 
     #[diagnostic(code(toml::parse_file))]
@@ -63,6 +74,12 @@ pub enum TomlError {
         path: PathBuf,
         #[source]
         error: Box<toml::de::Error>,
+        location: Option<(usize, usize)>,
+
+        #[source_code]
+        src: Option<NamedSource<String>>,
+        #[label("Failed here")]
+        span: Option<SourceSpan>,
     },
 
     #[diagnostic(code(toml::format_file))]
@@ -74,6 +91,56 @@ pub enum TomlError {
     },
 }
 
+impl TomlError {
+    /// Return the 1-based line and column at which parsing failed, if known.
+    pub fn location(&self) -> Option<(usize, usize)> {
+        match self {
+            Self::Parse { location, .. } | Self::ReadFile { location, .. } => *location,
+            _ => None,
+        }
+    }
+
+    pub(crate) fn new_parse(contents: &str, error: toml::de::Error) -> Self {
+        Self::Parse {
+            location: get_error_location(contents, &error),
+            #[cfg(feature = "miette")]
+            src: Some(NamedSource::new("input.toml", contents.to_owned())),
+            #[cfg(feature = "miette")]
+            span: error.span().map(SourceSpan::from),
+            error: Box::new(error),
+        }
+    }
+
+    pub(crate) fn new_read_file(
+        path: &Path,
+        contents: Option<&str>,
+        error: toml::de::Error,
+    ) -> Self {
+        Self::ReadFile {
+            location: contents.and_then(|contents| get_error_location(contents, &error)),
+            #[cfg(feature = "miette")]
+            src: contents
+                .map(|contents| NamedSource::new(path.to_string_lossy(), contents.to_owned())),
+            #[cfg(feature = "miette")]
+            span: contents.and(error.span()).map(SourceSpan::from),
+            path: path.to_path_buf(),
+            error: Box::new(error),
+        }
+    }
+}
+
+// TOML errors only provide a byte span, so convert it into a 1-based line and column
+fn get_error_location(data: &str, error: &toml::de::Error) -> Option<(usize, usize)> {
+    let offset = error.span()?.start.min(data.len());
+    let before = data.get(..offset)?;
+    let line_start = before.rfind('\n').map(|index| index + 1).unwrap_or(0);
+
+    Some((
+        before.matches('\n').count() + 1,
+        before[line_start..].chars().count() + 1,
+    ))
+}
+
 impl From<FsError> for TomlError {
     fn from(e: FsError) -> TomlError {
         TomlError::Fs(Box::new(e))
@@ -1,7 +1,7 @@
-use crate::fs;
+use crate::{env, fs};
 use regex::Regex;
 use serde::de::DeserializeOwned;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
 use std::path::Path;
 use std::sync::LazyLock;
@@ -47,9 +47,9 @@ where
 {
     trace!("Parsing YAML");
 
-    serde_yml::from_str(data.as_ref()).map_err(|error| YamlError::Parse {
-        error: Box::new(error),
-    })
+    let data = data.as_ref();
+
+    serde_yml::from_str(data).map_err(|error| YamlError::new_parse(data, error))
 }
 
 /// Format and serialize the provided value into a string.
@@ -121,10 +121,82 @@ where
 
     trace!(file = ?path, "Reading YAML file");
 
-    serde_yml::from_str(&contents).map_err(|error| YamlError::ReadFile {
-        path: path.to_path_buf(),
-        error: Box::new(error),
-    })
+    serde_yml::from_str(&contents)
+        .map_err(|error| YamlError::new_read_file(path, Some(&contents), error))
+}
+
+/// Read a file at the provided path that may contain multiple documents
+/// (separated by `---`), and deserialize each document into the required type.
+/// Documents are returned in order, and empty documents are skipped.
+/// The path must already exist.
+#[instrument(name = "read_yaml_multi")]
+pub fn read_file_multi<P, D>(path: P) -> Result<Vec<D>, YamlError>
+where
+    P: AsRef<Path> + Debug,
+    D: DeserializeOwned,
+{
+    let path = path.as_ref();
+    let contents = fs::read_file(path)?;
+
+    trace!(file = ?path, "Reading multi-document YAML file");
+
+    let map_error = |error| YamlError::new_read_file(path, Some(&contents), error);
+    let mut documents = vec![];
+
+    for document in serde_yml::Deserializer::from_str(&contents) {
+        let value = YamlValue::deserialize(document).map_err(map_error)?;
+
+        if value.is_null() {
+            continue;
+        }
+
+        documents.push(serde_yml::from_value(value).map_err(map_error)?);
+    }
+
+    Ok(documents)
+}
+
+impl env::StringVisitor for YamlValue {
+    fn visit_strings(&mut self, op: &mut dyn FnMut(&mut String)) {
+        match self {
+            YamlValue::String(inner) => op(inner),
+            YamlValue::Sequence(inner) => {
+                for item in inner {
+                    item.visit_strings(op);
+                }
+            }
+            YamlValue::Mapping(inner) => {
+                for item in inner.values_mut() {
+                    item.visit_strings(op);
+                }
+            }
+            YamlValue::Tagged(inner) => {
+                inner.value.visit_strings(op);
+            }
+            _ => {}
+        };
+    }
+}
+
+/// Read a file at the provided path and deserialize into the required type,
+/// while substituting `${VAR}` references in string values using the provided
+/// lookup function. Pass [`env::lookup_var`] to use the current process environment.
+/// Only string values are interpolated, not keys. The path must already exist.
+#[instrument(name = "read_yaml_interpolated", skip(lookup))]
+pub fn read_file_interpolated<P, D, F>(path: P, lookup: F) -> Result<D, YamlError>
+where
+    P: AsRef<Path> + Debug,
+    D: DeserializeOwned,
+    F: Fn(&str) -> Option<String>,
+{
+    let path = path.as_ref();
+    let mut value: YamlValue = read_file(path)?;
+
+    trace!(file = ?path, "Interpolating YAML file");
+
+    env::interpolate_strings(&mut value, lookup);
+
+    serde_yml::from_value(value).map_err(|error| YamlError::new_read_file(path, None, error))
 }
 
 /// Write a file and serialize the provided data to the provided path. If the parent directory
@@ -171,7 +243,7 @@ where
     let editor_config = fs::get_editor_config_props(path)?;
 
     let mut data = format_with_identation(yaml, &editor_config.indent)?;
-    editor_config.apply_eof(&mut data);
+    editor_config.apply_serialized_eof(&mut data);
 
     fs::write_file(path, data)?;
 
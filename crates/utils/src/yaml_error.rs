@@ -1,8 +1,11 @@
 use crate::fs::FsError;
 use starbase_styles::{Style, Stylize};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use thiserror::Error;
 
+#[cfg(feature = "miette")]
+use miette::{NamedSource, SourceSpan};
+
 #[cfg(not(feature = "miette"))]
 #[derive(Error, Debug)]
 pub enum YamlError {
@@ -55,6 +58,11 @@ pub enum YamlError {
     Parse {
         #[source]
         error: Box<serde_yml::Error>,
+
+        #[source_code]
+        src: Option<NamedSource<String>>,
+        #[label("Failed here")]
+        span: Option<SourceSpan>,
     },
 
     #[diagnostic(code(yaml::parse_file))]
@@ -63,6 +71,11 @@ pub enum YamlError {
         path: PathBuf,
         #[source]
         error: Box<serde_yml::Error>,
+
+        #[source_code]
+        src: Option<NamedSource<String>>,
+        #[label("Failed here")]
+        span: Option<SourceSpan>,
     },
 
     #[diagnostic(code(yaml::format_file))]
@@ -74,6 +87,54 @@ pub enum YamlError {
     },
 }
 
+impl YamlError {
+    /// Return the 1-based line and column at which parsing failed, if known.
+    pub fn location(&self) -> Option<(usize, usize)> {
+        match self {
+            Self::Parse { error, .. } | Self::ReadFile { error, .. } => error
+                .location()
+                .map(|location| (location.line(), location.column())),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn new_parse(contents: &str, error: serde_yml::Error) -> Self {
+        #[cfg(not(feature = "miette"))]
+        let _ = contents;
+
+        Self::Parse {
+            #[cfg(feature = "miette")]
+            src: Some(NamedSource::new("input.yaml", contents.to_owned())),
+            #[cfg(feature = "miette")]
+            span: error
+                .location()
+                .map(|location| SourceSpan::from(location.index())),
+            error: Box::new(error),
+        }
+    }
+
+    pub(crate) fn new_read_file(
+        path: &Path,
+        contents: Option<&str>,
+        error: serde_yml::Error,
+    ) -> Self {
+        #[cfg(not(feature = "miette"))]
+        let _ = contents;
+
+        Self::ReadFile {
+            #[cfg(feature = "miette")]
+            src: contents
+                .map(|contents| NamedSource::new(path.to_string_lossy(), contents.to_owned())),
+            #[cfg(feature = "miette")]
+            span: contents
+                .and(error.location())
+                .map(|location| SourceSpan::from(location.index())),
+            path: path.to_path_buf(),
+            error: Box::new(error),
+        }
+    }
+}
+
 impl From<FsError> for YamlError {
     fn from(e: FsError) -> YamlError {
         YamlError::Fs(Box::new(e))
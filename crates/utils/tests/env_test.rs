@@ -0,0 +1,39 @@
+use starbase_utils::env;
+
+fn lookup(name: &str) -> Option<String> {
+    match name {
+        "HOME" => Some("/home/user".into()),
+        "EMPTY" => Some("".into()),
+        _ => None,
+    }
+}
+
+mod interpolate {
+    use super::*;
+
+    #[test]
+    fn substitutes_present_vars() {
+        assert_eq!(
+            env::interpolate("${HOME}/.cache", lookup),
+            "/home/user/.cache"
+        );
+        assert_eq!(
+            env::interpolate("${HOME}:${HOME}", lookup),
+            "/home/user:/home/user"
+        );
+        assert_eq!(env::interpolate("a${EMPTY}b", lookup), "ab");
+    }
+
+    #[test]
+    fn keeps_missing_vars() {
+        assert_eq!(env::interpolate("${MISSING}/bin", lookup), "${MISSING}/bin");
+        assert_eq!(env::interpolate("${}", lookup), "${}");
+    }
+
+    #[test]
+    fn ignores_non_references() {
+        assert_eq!(env::interpolate("$HOME", lookup), "$HOME");
+        assert_eq!(env::interpolate("${HOME", lookup), "${HOME");
+        assert_eq!(env::interpolate("plain", lookup), "plain");
+    }
+}
@@ -72,4 +72,202 @@ mod fs_lock {
             assert!(elapsed >= Duration::from_millis(2500));
         }
     }
+
+    mod lock_directory_with_timeout {
+        use super::*;
+
+        #[test]
+        fn acquires_when_unlocked() {
+            let sandbox = create_empty_sandbox();
+            let dir = sandbox.path().join("dir");
+
+            let lock = fs::lock_directory_with_timeout(&dir, Duration::from_millis(100));
+
+            assert!(lock.is_ok());
+            assert!(dir.join(".lock").exists());
+        }
+
+        #[test]
+        fn times_out_when_locked() {
+            let sandbox = create_empty_sandbox();
+            let dir = sandbox.path().join("dir");
+            let _lock = fs::lock_directory(&dir).unwrap();
+            let start = Instant::now();
+
+            let result = fs::lock_directory_with_timeout(&dir, Duration::from_millis(100));
+
+            assert!(matches!(result, Err(fs::FsError::LockTimeout { .. })));
+            assert!(start.elapsed() >= Duration::from_millis(100));
+            assert!(start.elapsed() < Duration::from_secs(1));
+        }
+
+        #[test]
+        fn acquires_once_released() {
+            let sandbox = create_empty_sandbox();
+            let dir = sandbox.path().join("dir");
+            let lock = fs::lock_directory(&dir).unwrap();
+
+            let handle = thread::spawn(move || {
+                thread::sleep(Duration::from_millis(100));
+                drop(lock);
+            });
+
+            let result = fs::lock_directory_with_timeout(&dir, Duration::from_secs(5));
+
+            handle.join().unwrap();
+
+            assert!(result.is_ok());
+        }
+    }
+
+    mod try_lock_directory {
+        use super::*;
+
+        #[test]
+        fn returns_lock_when_unlocked() {
+            let sandbox = create_empty_sandbox();
+            let dir = sandbox.path().join("dir");
+
+            assert!(fs::try_lock_directory(&dir).unwrap().is_some());
+        }
+
+        #[test]
+        fn returns_none_when_locked() {
+            let sandbox = create_empty_sandbox();
+            let dir = sandbox.path().join("dir");
+            let _lock = fs::lock_directory(&dir).unwrap();
+
+            assert!(fs::try_lock_directory(&dir).unwrap().is_none());
+        }
+    }
+
+    mod stale_locks {
+        use super::*;
+
+        // Larger than the max PID on all supported platforms
+        const DEAD_PID: &str = "99999999";
+
+        #[test]
+        fn reads_pid_from_lock() {
+            let sandbox = create_empty_sandbox();
+            let dir = sandbox.path().join("dir");
+            let _lock = fs::lock_directory(&dir).unwrap();
+
+            assert_eq!(
+                fs::read_lock_pid(dir.join(".lock")),
+                Some(std::process::id())
+            );
+        }
+
+        #[test]
+        fn current_process_is_alive() {
+            assert!(fs::is_process_alive(std::process::id()));
+            assert!(!fs::is_process_alive(DEAD_PID.parse().unwrap()));
+        }
+
+        #[test]
+        fn not_stale_when_owner_alive() {
+            let sandbox = create_empty_sandbox();
+            let dir = sandbox.path().join("dir");
+            let _lock = fs::lock_directory(&dir).unwrap();
+
+            assert!(!fs::is_dir_lock_stale(&dir));
+            assert!(!fs::reclaim_dir_lock(&dir, false).unwrap());
+            assert!(dir.join(".lock").exists());
+        }
+
+        #[test]
+        fn reclaims_when_owner_dead() {
+            let sandbox = create_empty_sandbox();
+            sandbox.create_file("dir/.lock", DEAD_PID);
+
+            let dir = sandbox.path().join("dir");
+
+            assert!(fs::is_dir_locked(&dir));
+            assert!(fs::is_dir_lock_stale(&dir));
+            assert!(fs::reclaim_dir_lock(&dir, false).unwrap());
+            assert!(!fs::is_dir_locked(&dir));
+
+            // And can be locked again with the new PID
+            let _lock = fs::lock_directory(&dir).unwrap();
+
+            assert_eq!(
+                fs::read_lock_pid(dir.join(".lock")),
+                Some(std::process::id())
+            );
+        }
+
+        #[test]
+        fn reclaims_when_forced() {
+            let sandbox = create_empty_sandbox();
+            sandbox.create_file("dir/.lock", std::process::id().to_string());
+
+            let dir = sandbox.path().join("dir");
+
+            assert!(!fs::reclaim_dir_lock(&dir, false).unwrap());
+            assert!(fs::reclaim_dir_lock(&dir, true).unwrap());
+            assert!(!fs::is_dir_locked(&dir));
+        }
+    }
+
+    mod lock_directory_shared {
+        use super::*;
+
+        #[test]
+        fn shared_locks_coexist() {
+            let sandbox = create_empty_sandbox();
+            let dir = sandbox.path().join("dir");
+
+            let one = fs::lock_directory_shared(&dir).unwrap();
+            let two = fs::lock_directory_shared(&dir).unwrap();
+
+            assert!(one.is_shared());
+            assert!(two.is_shared());
+            assert!(fs::is_dir_locked(&dir));
+        }
+
+        #[test]
+        fn blocks_exclusive_until_all_released() {
+            let sandbox = create_empty_sandbox();
+            let dir = sandbox.path().join("dir");
+
+            let one = fs::lock_directory_shared(&dir).unwrap();
+            let two = fs::lock_directory_shared(&dir).unwrap();
+
+            assert!(matches!(
+                fs::lock_directory_with_timeout(&dir, Duration::from_millis(50)),
+                Err(fs::FsError::LockTimeout { .. })
+            ));
+
+            drop(one);
+
+            // Still held by the other shared lock
+            assert!(dir.join(".lock").exists());
+            assert!(fs::try_lock_directory(&dir).unwrap().is_none());
+
+            drop(two);
+
+            assert!(!dir.join(".lock").exists());
+            assert!(fs::try_lock_directory(&dir).unwrap().is_some());
+        }
+
+        #[test]
+        fn waits_for_exclusive() {
+            let sandbox = create_empty_sandbox();
+            let dir = sandbox.path().join("dir");
+            let lock = fs::lock_directory(&dir).unwrap();
+            let start = Instant::now();
+
+            let handle = thread::spawn(move || {
+                thread::sleep(Duration::from_millis(100));
+                drop(lock);
+            });
+
+            let _shared = fs::lock_directory_shared(&dir).unwrap();
+
+            handle.join().unwrap();
+
+            assert!(start.elapsed() >= Duration::from_millis(100));
+        }
+    }
 }
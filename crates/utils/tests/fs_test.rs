@@ -6,6 +6,249 @@ use starbase_utils::fs;
 mod fs_base {
     use super::*;
 
+    mod sync_dirs {
+        use super::*;
+        use starbase_utils::fs::{SyncDirsOptions, SyncDirsResult};
+        use std::path::PathBuf;
+
+        fn create_dirs() -> starbase_sandbox::Sandbox {
+            let sandbox = create_empty_sandbox();
+            sandbox.create_file("src/same.txt", "same");
+            sandbox.create_file("src/changed.txt", "new");
+            sandbox.create_file("src/nested/added.txt", "added");
+            sandbox.create_file("dest/same.txt", "same");
+            sandbox.create_file("dest/changed.txt", "old");
+            sandbox.create_file("dest/removed.txt", "removed");
+            sandbox
+        }
+
+        #[test]
+        fn syncs_changes() {
+            let sandbox = create_dirs();
+            let src = sandbox.path().join("src");
+            let dest = sandbox.path().join("dest");
+
+            let result = fs::sync_dirs(&src, &dest, SyncDirsOptions::default()).unwrap();
+
+            assert_eq!(
+                result,
+                SyncDirsResult {
+                    added: vec![PathBuf::from("nested/added.txt")],
+                    changed: vec![PathBuf::from("changed.txt")],
+                    removed: vec![PathBuf::from("removed.txt")],
+                    unchanged: 1,
+                }
+            );
+            assert_eq!(fs::read_file(dest.join("changed.txt")).unwrap(), "new");
+            assert_eq!(
+                fs::read_file(dest.join("nested/added.txt")).unwrap(),
+                "added"
+            );
+            assert!(!dest.join("removed.txt").exists());
+
+            // Nothing to do the 2nd time
+            let result = fs::sync_dirs(&src, &dest, SyncDirsOptions::default()).unwrap();
+
+            assert_eq!(
+                result,
+                SyncDirsResult {
+                    unchanged: 3,
+                    ..SyncDirsResult::default()
+                }
+            );
+        }
+
+        #[test]
+        fn detects_same_size_changes() {
+            let sandbox = create_empty_sandbox();
+            sandbox.create_file("src/file.txt", "abc");
+            sandbox.create_file("dest/file.txt", "abd");
+
+            let result = fs::sync_dirs(
+                sandbox.path().join("src"),
+                sandbox.path().join("dest"),
+                SyncDirsOptions::default(),
+            )
+            .unwrap();
+
+            assert_eq!(result.changed, vec![PathBuf::from("file.txt")]);
+        }
+
+        #[test]
+        fn can_keep_stale_files() {
+            let sandbox = create_dirs();
+            let dest = sandbox.path().join("dest");
+
+            let result = fs::sync_dirs(
+                sandbox.path().join("src"),
+                &dest,
+                SyncDirsOptions {
+                    keep_stale: true,
+                    ..SyncDirsOptions::default()
+                },
+            )
+            .unwrap();
+
+            assert!(result.removed.is_empty());
+            assert!(dest.join("removed.txt").exists());
+        }
+
+        #[test]
+        fn dry_run_doesnt_modify() {
+            let sandbox = create_dirs();
+            let dest = sandbox.path().join("dest");
+
+            let result = fs::sync_dirs(
+                sandbox.path().join("src"),
+                &dest,
+                SyncDirsOptions {
+                    dry_run: true,
+                    ..SyncDirsOptions::default()
+                },
+            )
+            .unwrap();
+
+            assert_eq!(result.added.len(), 1);
+            assert_eq!(result.changed.len(), 1);
+            assert_eq!(result.removed.len(), 1);
+            assert_eq!(fs::read_file(dest.join("changed.txt")).unwrap(), "old");
+            assert!(!dest.join("nested/added.txt").exists());
+            assert!(dest.join("removed.txt").exists());
+        }
+    }
+
+    mod write_file_if_changed {
+        use super::*;
+        use std::time::{Duration, SystemTime};
+
+        #[test]
+        fn writes_when_missing() {
+            let sandbox = create_empty_sandbox();
+            let path = sandbox.path().join("nested/file.txt");
+
+            assert!(fs::write_file_if_changed(&path, "content").unwrap());
+            assert_eq!(fs::read_file(&path).unwrap(), "content");
+        }
+
+        #[test]
+        fn writes_when_changed() {
+            let sandbox = create_empty_sandbox();
+            sandbox.create_file("file.txt", "old");
+            let path = sandbox.path().join("file.txt");
+
+            assert!(fs::write_file_if_changed(&path, "new").unwrap());
+            assert_eq!(fs::read_file(&path).unwrap(), "new");
+        }
+
+        #[test]
+        fn skips_when_unchanged() {
+            let sandbox = create_empty_sandbox();
+            sandbox.create_file("file.txt", "content");
+            let path = sandbox.path().join("file.txt");
+
+            // Move the mtime into the past, so we can detect a write
+            let past = SystemTime::now() - Duration::from_secs(60);
+            std::fs::File::options()
+                .write(true)
+                .open(&path)
+                .unwrap()
+                .set_modified(past)
+                .unwrap();
+
+            assert!(!fs::write_file_if_changed(&path, "content").unwrap());
+            assert_eq!(fs::metadata(&path).unwrap().modified().unwrap(), past);
+        }
+    }
+
+    mod write_file_with_config {
+        use super::*;
+
+        #[test]
+        fn appends_final_newline() {
+            let sandbox = create_empty_sandbox();
+            sandbox.create_file(".editorconfig", "[*]\ninsert_final_newline = true");
+            let path = sandbox.path().join("file.txt");
+
+            fs::write_file_with_config(&path, "content").unwrap();
+
+            assert_eq!(fs::read_file(&path).unwrap(), "content\n");
+        }
+
+        #[test]
+        fn preserves_trailing_newlines_when_disabled() {
+            let sandbox = create_empty_sandbox();
+            sandbox.create_file(".editorconfig", "[*]\ninsert_final_newline = false");
+            let path = sandbox.path().join("file.txt");
+
+            fs::write_file_with_config(&path, "content\n\n").unwrap();
+
+            assert_eq!(fs::read_file(&path).unwrap(), "content\n\n");
+        }
+    }
+
+    mod normalize_line_endings {
+        use super::*;
+        use starbase_utils::fs::LineEnding;
+
+        #[test]
+        fn converts_to_lf() {
+            assert_eq!(
+                fs::normalize_line_endings("a\r\nb\nc\rd\r\n", LineEnding::Lf),
+                "a\nb\nc\nd\n"
+            );
+        }
+
+        #[test]
+        fn converts_to_crlf() {
+            assert_eq!(
+                fs::normalize_line_endings("a\r\nb\nc\rd\r\n", LineEnding::Crlf),
+                "a\r\nb\r\nc\r\nd\r\n"
+            );
+        }
+
+        #[test]
+        fn handles_consecutive_endings() {
+            assert_eq!(
+                fs::normalize_line_endings("\r\r\n\n\r", LineEnding::Lf),
+                "\n\n\n\n"
+            );
+            assert_eq!(fs::normalize_line_endings("", LineEnding::Crlf), "");
+            assert_eq!(fs::normalize_line_endings("abc", LineEnding::Crlf), "abc");
+        }
+
+        #[test]
+        fn writes_normalized_file() {
+            let sandbox = create_empty_sandbox();
+            let path = sandbox.path().join("file.txt");
+
+            fs::write_file_normalized(&path, "a\nb\r\nc\r", LineEnding::Crlf).unwrap();
+
+            assert_eq!(fs::read_file(&path).unwrap(), "a\r\nb\r\nc\r\n");
+        }
+    }
+
+    mod hash_file {
+        use super::*;
+
+        #[test]
+        fn hashes_contents() {
+            let sandbox = create_empty_sandbox();
+            sandbox.create_file("file.txt", "hello");
+
+            assert_eq!(
+                fs::hash_file(sandbox.path().join("file.txt")).unwrap(),
+                "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824"
+            );
+        }
+
+        #[test]
+        fn errors_if_missing() {
+            let sandbox = create_empty_sandbox();
+
+            assert!(fs::hash_file(sandbox.path().join("missing.txt")).is_err());
+        }
+    }
+
     mod remove_file {
         use super::*;
 
@@ -159,5 +402,460 @@ mod fs_base {
                 "\t\t"
             );
         }
+
+        #[test]
+        fn info_for_spaces() {
+            let sandbox = create_sandbox("indent");
+            let info = fs::detect_indentation_info(
+                fs::read_file(sandbox.path().join("spaces-4.js")).unwrap(),
+            );
+
+            assert_eq!(
+                info,
+                fs::Indentation {
+                    kind: fs::IndentationKind::Spaces,
+                    width: 4
+                }
+            );
+            assert_eq!(info.to_string(), "4 spaces");
+        }
+
+        #[test]
+        fn info_for_tabs() {
+            let sandbox = create_sandbox("indent");
+            let info =
+                fs::detect_indentation_info(fs::read_file(sandbox.path().join("tabs.js")).unwrap());
+
+            assert_eq!(
+                info,
+                fs::Indentation {
+                    kind: fs::IndentationKind::Tabs,
+                    width: 1
+                }
+            );
+            assert_eq!(info.to_string(), "1 tab");
+        }
+
+        #[test]
+        fn info_skips_odd_spaces() {
+            let info = fs::detect_indentation_info("{\n   // odd\n     // odd\n    a: 1,\n}");
+
+            assert_eq!(info.kind, fs::IndentationKind::Spaces);
+            assert_eq!(info.width, 4);
+        }
+
+        #[test]
+        fn info_prefers_most_common_style() {
+            let info = fs::detect_indentation_info("a\n\tb\n\tc\n  d\n\te");
+
+            assert_eq!(info.kind, fs::IndentationKind::Tabs);
+            assert_eq!(info.as_string(), "\t");
+
+            let info = fs::detect_indentation_info("a\n  b\n    c\n\td\n  e");
+
+            assert_eq!(info.kind, fs::IndentationKind::Spaces);
+            assert_eq!(info.as_string(), "  ");
+        }
+
+        #[test]
+        fn info_defaults_to_2_spaces() {
+            let info = fs::detect_indentation_info("a\nb\n   c");
+
+            assert_eq!(info.to_string(), "2 spaces");
+        }
+    }
+
+    mod copy_dir_all_with_cancel {
+        use super::*;
+        use starbase_utils::cancel::CancellationToken;
+        use starbase_utils::fs::FsError;
+
+        fn create_files() -> starbase_sandbox::Sandbox {
+            let sandbox = create_empty_sandbox();
+            sandbox.create_file("src/a.txt", "a");
+            sandbox.create_file("src/nested/b.txt", "b");
+            sandbox.create_file("src/nested/deep/c.txt", "c");
+            sandbox
+        }
+
+        #[test]
+        fn copies_when_not_cancelled() {
+            let sandbox = create_files();
+            let src = sandbox.path().join("src");
+            let dest = sandbox.path().join("dest");
+
+            fs::copy_dir_all_with_cancel(&src, &src, &dest, &CancellationToken::new()).unwrap();
+
+            assert!(dest.join("a.txt").exists());
+            assert!(dest.join("nested/b.txt").exists());
+            assert!(dest.join("nested/deep/c.txt").exists());
+        }
+
+        #[test]
+        fn removes_copied_files_when_cancelled() {
+            let sandbox = create_files();
+            let src = sandbox.path().join("src");
+            let dest = sandbox.path().join("dest");
+            let token = CancellationToken::new();
+
+            token.cancel();
+
+            let error = fs::copy_dir_all_with_cancel(&src, &src, &dest, &token).unwrap_err();
+
+            assert!(matches!(error, FsError::Cancelled { .. }));
+            assert!(!dest.exists());
+        }
+
+        #[test]
+        fn removes_copied_files_when_cancelled_mid_copy() {
+            let sandbox = create_empty_sandbox();
+
+            for i in 0..2000 {
+                sandbox.create_file(format!("src/{}/{i}.txt", i % 10), "content");
+            }
+
+            let src = sandbox.path().join("src");
+            let dest = sandbox.path().join("dest");
+            let token = CancellationToken::new();
+            let cancel_token = token.clone();
+            let watch_dest = dest.clone();
+
+            // Cancel from another thread once some files have been copied
+            let handle = std::thread::spawn(move || {
+                while !std::fs::read_dir(&watch_dest).is_ok_and(|entries| entries.count() > 1) {
+                    std::thread::yield_now();
+                }
+
+                cancel_token.cancel();
+            });
+
+            let error = fs::copy_dir_all_with_cancel(&src, &src, &dest, &token).unwrap_err();
+
+            handle.join().unwrap();
+
+            assert!(matches!(error, FsError::Cancelled { .. }));
+            assert!(!dest.exists());
+        }
+
+        #[test]
+        fn keeps_existing_files_when_cancelled() {
+            let sandbox = create_files();
+            sandbox.create_file("dest/existing.txt", "existing");
+
+            let src = sandbox.path().join("src");
+            let dest = sandbox.path().join("dest");
+            let token = CancellationToken::new();
+
+            token.cancel();
+
+            assert!(fs::copy_dir_all_with_cancel(&src, &src, &dest, &token).is_err());
+            assert!(dest.join("existing.txt").exists());
+            assert!(!dest.join("a.txt").exists());
+        }
+    }
+
+    mod create_dir_all {
+        use super::*;
+        use std::sync::{Arc, Barrier};
+        use std::thread;
+
+        #[test]
+        fn creates_nested_dirs() {
+            let sandbox = create_empty_sandbox();
+            let dir = sandbox.path().join("a/b/c");
+
+            fs::create_dir_all(&dir).unwrap();
+
+            assert!(dir.is_dir());
+        }
+
+        #[test]
+        fn noop_when_exists() {
+            let sandbox = create_empty_sandbox();
+            sandbox.create_file("dir/file.txt", "");
+
+            fs::create_dir_all(sandbox.path().join("dir")).unwrap();
+
+            assert!(sandbox.path().join("dir/file.txt").exists());
+        }
+
+        #[test]
+        fn tolerates_concurrent_creation() {
+            let sandbox = create_empty_sandbox();
+            let dir = sandbox.path().join("a/b/c/d/e");
+            let barrier = Arc::new(Barrier::new(8));
+
+            let handles = (0..8)
+                .map(|_| {
+                    let dir = dir.clone();
+                    let barrier = Arc::clone(&barrier);
+
+                    thread::spawn(move || {
+                        barrier.wait();
+                        fs::create_dir_all(&dir)
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            for handle in handles {
+                handle.join().unwrap().unwrap();
+            }
+
+            assert!(dir.is_dir());
+        }
+
+        #[test]
+        fn errors_when_file_exists() {
+            let sandbox = create_empty_sandbox();
+            sandbox.create_file("file", "");
+
+            assert!(fs::create_dir_all(sandbox.path().join("file/nested")).is_err());
+        }
+    }
+
+    mod is_dir_empty {
+        use super::*;
+        use starbase_utils::fs::FsError;
+
+        #[test]
+        fn returns_true_when_empty() {
+            let sandbox = create_empty_sandbox();
+            let dir = sandbox.path().join("dir");
+
+            fs::create_dir_all(&dir).unwrap();
+
+            assert!(fs::is_dir_empty(&dir).unwrap());
+        }
+
+        #[test]
+        fn returns_false_when_has_files() {
+            let sandbox = create_empty_sandbox();
+            sandbox.create_file("dir/file.txt", "");
+
+            assert!(!fs::is_dir_empty(sandbox.path().join("dir")).unwrap());
+        }
+
+        #[test]
+        fn returns_false_when_has_dirs() {
+            let sandbox = create_empty_sandbox();
+
+            fs::create_dir_all(sandbox.path().join("dir/nested")).unwrap();
+
+            assert!(!fs::is_dir_empty(sandbox.path().join("dir")).unwrap());
+        }
+
+        #[test]
+        fn errors_when_file() {
+            let sandbox = create_empty_sandbox();
+            sandbox.create_file("file.txt", "");
+
+            assert!(matches!(
+                fs::is_dir_empty(sandbox.path().join("file.txt")),
+                Err(FsError::RequireDir { .. })
+            ));
+        }
+
+        #[test]
+        fn errors_when_missing() {
+            let sandbox = create_empty_sandbox();
+
+            assert!(fs::is_dir_empty(sandbox.path().join("missing")).is_err());
+        }
+    }
+
+    #[cfg(feature = "trash")]
+    mod trash {
+        use super::*;
+
+        #[test]
+        #[ignore = "writes to the system trash"]
+        fn moves_file_out_of_location() {
+            let sandbox = create_empty_sandbox();
+            sandbox.create_file("file.txt", "content");
+
+            let path = sandbox.path().join("file.txt");
+
+            fs::trash(&path).unwrap();
+
+            assert!(!path.exists());
+        }
+
+        #[test]
+        #[ignore = "writes to the system trash"]
+        fn moves_dir_out_of_location() {
+            let sandbox = create_empty_sandbox();
+            sandbox.create_file("dir/file.txt", "content");
+
+            let path = sandbox.path().join("dir");
+
+            fs::trash(&path).unwrap();
+
+            assert!(!path.exists());
+        }
+
+        #[test]
+        fn noop_when_missing() {
+            let sandbox = create_empty_sandbox();
+
+            fs::trash(sandbox.path().join("missing.txt")).unwrap();
+        }
+    }
+
+    mod read_dir_sorted {
+        use super::*;
+
+        fn create_files() -> starbase_sandbox::Sandbox {
+            let sandbox = create_empty_sandbox();
+            sandbox.create_file("c.txt", "");
+            sandbox.create_file("a.txt", "");
+            sandbox.create_file("b/file.txt", "");
+            sandbox.create_file("d/file.txt", "");
+            sandbox
+        }
+
+        fn names(entries: Vec<std::fs::DirEntry>) -> Vec<String> {
+            entries
+                .into_iter()
+                .map(|entry| entry.file_name().to_string_lossy().to_string())
+                .collect()
+        }
+
+        #[test]
+        fn sorts_alphabetically() {
+            let sandbox = create_files();
+
+            assert_eq!(
+                names(fs::read_dir_sorted(sandbox.path(), false).unwrap()),
+                vec!["a.txt", "b", "c.txt", "d"]
+            );
+        }
+
+        #[test]
+        fn sorts_dirs_first() {
+            let sandbox = create_files();
+
+            assert_eq!(
+                names(fs::read_dir_sorted(sandbox.path(), true).unwrap()),
+                vec!["b", "d", "a.txt", "c.txt"]
+            );
+        }
+
+        #[test]
+        fn filters_with_predicate() {
+            let sandbox = create_files();
+            let mut entries = names(
+                fs::read_dir_filtered(sandbox.path(), |entry| {
+                    entry.file_type().is_ok_and(|file_type| file_type.is_file())
+                })
+                .unwrap(),
+            );
+
+            entries.sort();
+
+            assert_eq!(entries, vec!["a.txt", "c.txt"]);
+        }
+
+        #[test]
+        fn returns_empty_when_missing() {
+            let sandbox = create_empty_sandbox();
+
+            assert!(fs::read_dir_sorted(sandbox.path().join("missing"), true)
+                .unwrap()
+                .is_empty());
+        }
+    }
+
+    mod hash_dir {
+        use super::*;
+        use starbase_utils::fs::HashDirOptions;
+
+        fn create_files() -> starbase_sandbox::Sandbox {
+            let sandbox = create_empty_sandbox();
+            sandbox.create_file("a.txt", "a");
+            sandbox.create_file("nested/b.txt", "b");
+            sandbox.create_file("nested/c.md", "c");
+            sandbox
+        }
+
+        #[test]
+        fn is_stable_across_runs() {
+            let sandbox = create_files();
+            let hash = fs::hash_dir(sandbox.path(), HashDirOptions::default()).unwrap();
+
+            assert_eq!(
+                hash,
+                fs::hash_dir(sandbox.path(), HashDirOptions::default()).unwrap()
+            );
+            assert_eq!(
+                hash,
+                fs::hash_dir(create_files().path(), HashDirOptions::default()).unwrap()
+            );
+        }
+
+        #[test]
+        fn changes_when_a_file_changes() {
+            let sandbox = create_files();
+            let hash = fs::hash_dir(sandbox.path(), HashDirOptions::default()).unwrap();
+
+            sandbox.create_file("nested/b.txt", "changed");
+
+            assert_ne!(
+                hash,
+                fs::hash_dir(sandbox.path(), HashDirOptions::default()).unwrap()
+            );
+        }
+
+        #[test]
+        fn changes_when_a_file_moves() {
+            let sandbox = create_files();
+            let hash = fs::hash_dir(sandbox.path(), HashDirOptions::default()).unwrap();
+
+            std::fs::rename(sandbox.path().join("a.txt"), sandbox.path().join("z.txt")).unwrap();
+
+            assert_ne!(
+                hash,
+                fs::hash_dir(sandbox.path(), HashDirOptions::default()).unwrap()
+            );
+        }
+
+        #[test]
+        fn respects_patterns_and_global_negations() {
+            let sandbox = create_files();
+            let options = HashDirOptions {
+                patterns: vec!["**/*.txt".into()],
+                ..Default::default()
+            };
+            let hash = fs::hash_dir(sandbox.path(), options.clone()).unwrap();
+
+            sandbox.create_file("nested/c.md", "changed");
+            sandbox.create_file("node_modules/dep/index.txt", "ignored");
+
+            assert_eq!(hash, fs::hash_dir(sandbox.path(), options).unwrap());
+        }
+
+        #[cfg(unix)]
+        #[test]
+        fn can_include_permissions() {
+            use std::os::unix::fs::PermissionsExt;
+
+            let sandbox = create_files();
+            let options = HashDirOptions {
+                include_permissions: true,
+                ..Default::default()
+            };
+            let hash = fs::hash_dir(sandbox.path(), options.clone()).unwrap();
+
+            std::fs::set_permissions(
+                sandbox.path().join("a.txt"),
+                std::fs::Permissions::from_mode(0o755),
+            )
+            .unwrap();
+
+            assert_ne!(hash, fs::hash_dir(sandbox.path(), options).unwrap());
+            assert_eq!(
+                fs::hash_dir(sandbox.path(), HashDirOptions::default()).unwrap(),
+                fs::hash_dir(create_files().path(), HashDirOptions::default()).unwrap()
+            );
+        }
     }
 }
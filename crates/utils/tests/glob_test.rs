@@ -101,6 +101,108 @@ mod is_glob {
         assert!(!is_glob("\\*.rs"));
         assert!(!is_glob("file\\?.js"));
         assert!(!is_glob("folder-\\[id\\]"));
+        assert!(!is_glob("folder-\\{id\\}"));
+    }
+
+    #[test]
+    fn returns_true_when_partially_escaped() {
+        assert!(is_glob("\\[id\\]/*.rs"));
+        assert!(is_glob("file\\?.{js,ts}"));
+        assert!(is_glob("!dir/file.ts"));
+    }
+
+    #[test]
+    fn returns_false_when_unbalanced() {
+        assert!(!is_glob("file[.ts"));
+        assert!(!is_glob("file].ts"));
+        assert!(!is_glob("file]id[.ts"));
+        assert!(!is_glob("file{.ts"));
+        assert!(!is_glob("file}id{.ts"));
+    }
+
+    #[test]
+    fn returns_true_for_unescaped_bang() {
+        assert!(is_glob("!dir/file.ts"));
+        assert!(is_glob("file!.ts"));
+        assert!(!is_glob("file\\!.ts"));
+    }
+}
+
+mod escape {
+    use super::*;
+
+    #[test]
+    fn escapes_meta_chars() {
+        assert_eq!(escape("file.ts"), "file.ts");
+        assert_eq!(escape("[id].ts"), "\\[id\\].ts");
+        assert_eq!(escape("{a,b}*?.ts"), "\\{a\\,b\\}\\*\\?.ts");
+        assert_eq!(escape("(group)"), "\\(group\\)");
+    }
+
+    #[test]
+    fn escaped_literals_are_not_globs() {
+        assert!(!is_glob(escape("folder-[id]/file*.ts")));
+    }
+
+    #[test]
+    fn escaped_literals_match_themselves() {
+        let pattern = format!("{}/*.ts", escape("[id]"));
+        let glob = create_glob(&pattern).unwrap();
+
+        assert!(wax::Pattern::is_match(&glob, "[id]/file.ts"));
+        assert!(!wax::Pattern::is_match(&glob, "i/file.ts"));
+    }
+}
+
+mod matches {
+    use super::*;
+
+    #[test]
+    fn single_pattern() {
+        assert!(matches("files/a.ts", "files/*.ts").unwrap());
+        assert!(!matches("files/a.js", "files/*.ts").unwrap());
+        assert!(!matches("node_modules/a.ts", "**/*.ts").unwrap());
+    }
+
+    #[test]
+    fn single_negated_pattern() {
+        assert!(matches("files/a.js", "!files/*.ts").unwrap());
+        assert!(!matches("files/a.ts", "!files/*.ts").unwrap());
+    }
+
+    #[test]
+    fn errors_for_invalid_pattern() {
+        assert!(matches("file", "{a").is_err());
+    }
+
+    #[test]
+    fn any_of_multiple_patterns() {
+        let patterns = ["*.ts", "*.js", "!*.test.*"];
+
+        assert!(matches_any("a.ts", patterns).unwrap());
+        assert!(matches_any("a.js", patterns).unwrap());
+        assert!(!matches_any("a.md", patterns).unwrap());
+        assert!(!matches_any("a.test.ts", patterns).unwrap());
+        assert!(!matches_any("a.ts", Vec::<&str>::new()).unwrap());
+    }
+
+    #[test]
+    fn any_of_only_negations() {
+        let patterns = ["!*.md", "!*.txt"];
+
+        assert!(matches_any("a.ts", patterns).unwrap());
+        assert!(!matches_any("a.md", patterns).unwrap());
+        assert!(!matches_any("a.txt", patterns).unwrap());
+    }
+
+    #[test]
+    fn all_of_multiple_patterns() {
+        let patterns = ["src/**/*", "**/*.ts", "!**/*.test.ts"];
+
+        assert!(matches_all("src/a.ts", patterns).unwrap());
+        assert!(!matches_all("src/a.js", patterns).unwrap());
+        assert!(!matches_all("lib/a.ts", patterns).unwrap());
+        assert!(!matches_all("src/a.test.ts", patterns).unwrap());
     }
 }
 
@@ -118,3 +220,352 @@ mod split_patterns {
         );
     }
 }
+
+mod glob_cache {
+    use super::*;
+    use starbase_sandbox::create_empty_sandbox;
+
+    #[test]
+    fn returns_cached_results() {
+        let sandbox = create_empty_sandbox();
+        sandbox.create_file("a.txt", "");
+
+        let cache = GlobCache::new();
+
+        assert_eq!(
+            cache.walk_files(sandbox.path(), ["*.txt"]).unwrap(),
+            vec![sandbox.path().join("a.txt")]
+        );
+
+        sandbox.create_file("a.txt", "changed");
+
+        assert_eq!(
+            cache.walk_files(sandbox.path(), ["*.txt"]).unwrap(),
+            vec![sandbox.path().join("a.txt")]
+        );
+        assert_eq!(cache.stats(), GlobCacheStats { hits: 1, misses: 1 });
+    }
+
+    #[test]
+    fn caches_per_pattern() {
+        let sandbox = create_empty_sandbox();
+        sandbox.create_file("a.txt", "");
+        sandbox.create_file("b.md", "");
+
+        let cache = GlobCache::new();
+        cache.walk(sandbox.path(), ["*.txt"]).unwrap();
+        cache.walk(sandbox.path(), ["*.md"]).unwrap();
+
+        assert_eq!(cache.stats(), GlobCacheStats { hits: 0, misses: 2 });
+    }
+
+    #[test]
+    fn recomputes_after_invalidate() {
+        let sandbox = create_empty_sandbox();
+        sandbox.create_file("a.txt", "");
+
+        let cache = GlobCache::new();
+        cache.walk_files(sandbox.path(), ["*.txt"]).unwrap();
+
+        cache.invalidate(sandbox.path());
+        sandbox.create_file("b.txt", "");
+
+        let mut files = cache.walk_files(sandbox.path(), ["*.txt"]).unwrap();
+        files.sort();
+
+        assert_eq!(
+            files,
+            vec![sandbox.path().join("a.txt"), sandbox.path().join("b.txt")]
+        );
+        assert_eq!(cache.stats(), GlobCacheStats { hits: 0, misses: 2 });
+    }
+
+    #[test]
+    fn invalidates_walks_from_parent_dirs() {
+        let sandbox = create_empty_sandbox();
+        sandbox.create_file("nested/a.txt", "");
+
+        let cache = GlobCache::new();
+        cache.walk_files(sandbox.path(), ["**/*.txt"]).unwrap();
+
+        sandbox.create_file("nested/b.txt", "");
+        cache.invalidate(sandbox.path().join("nested"));
+
+        assert_eq!(
+            cache
+                .walk_files(sandbox.path(), ["**/*.txt"])
+                .unwrap()
+                .len(),
+            2
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn recomputes_when_dir_mtime_changes() {
+        let sandbox = create_empty_sandbox();
+        sandbox.create_file("a.txt", "");
+
+        let cache = GlobCache::new();
+        cache.walk_files(sandbox.path(), ["*.txt"]).unwrap();
+
+        sandbox.create_file("b.txt", "");
+
+        // Touch the directory in case the file system has a coarse mtime resolution
+        std::fs::File::open(sandbox.path())
+            .unwrap()
+            .set_modified(std::time::SystemTime::now() + std::time::Duration::from_secs(5))
+            .unwrap();
+
+        let mut files = cache.walk_files(sandbox.path(), ["*.txt"]).unwrap();
+        files.sort();
+
+        assert_eq!(
+            files,
+            vec![sandbox.path().join("a.txt"), sandbox.path().join("b.txt")]
+        );
+        assert_eq!(cache.stats(), GlobCacheStats { hits: 0, misses: 2 });
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn recomputes_when_nested_dir_mtime_changes() {
+        let sandbox = create_empty_sandbox();
+        sandbox.create_file("nested/a.txt", "");
+
+        let cache = GlobCache::new();
+        cache.walk_files(sandbox.path(), ["**/*.txt"]).unwrap();
+
+        sandbox.create_file("nested/b.txt", "");
+
+        // Touch the directory in case the file system has a coarse mtime resolution
+        std::fs::File::open(sandbox.path().join("nested"))
+            .unwrap()
+            .set_modified(std::time::SystemTime::now() + std::time::Duration::from_secs(5))
+            .unwrap();
+
+        let mut files = cache.walk_files(sandbox.path(), ["**/*.txt"]).unwrap();
+        files.sort();
+
+        assert_eq!(
+            files,
+            vec![
+                sandbox.path().join("nested/a.txt"),
+                sandbox.path().join("nested/b.txt")
+            ]
+        );
+        assert_eq!(cache.stats(), GlobCacheStats { hits: 0, misses: 2 });
+    }
+
+    #[test]
+    fn clear_resets_everything() {
+        let sandbox = create_empty_sandbox();
+        sandbox.create_file("a.txt", "");
+
+        let cache = GlobCache::new();
+        cache.walk(sandbox.path(), ["*.txt"]).unwrap();
+        cache.walk(sandbox.path(), ["*.txt"]).unwrap();
+        cache.clear();
+
+        assert_eq!(cache.stats(), GlobCacheStats::default());
+
+        cache.walk(sandbox.path(), ["*.txt"]).unwrap();
+
+        assert_eq!(cache.stats(), GlobCacheStats { hits: 0, misses: 1 });
+    }
+}
+
+mod walk {
+    use super::*;
+    use starbase_sandbox::create_empty_sandbox;
+    use std::path::{Path, PathBuf};
+
+    #[test]
+    fn parallel_matches_sequential() {
+        let sandbox = create_empty_sandbox();
+
+        for dir in ["a", "b", "c", "d", "e"] {
+            for file in ["one.txt", "two.md", "nested/three.txt", "nested/four.json"] {
+                sandbox.create_file(format!("{dir}/{file}"), "");
+            }
+        }
+
+        let patterns = [
+            "a/**/*.txt",
+            "b/**/*",
+            "c/*.md",
+            "d/nested/*",
+            "e/**/*.json",
+            "!b/nested/**",
+        ];
+
+        let mut sequential = walk(sandbox.path(), patterns).unwrap();
+        let mut parallel = walk_with_options(
+            sandbox.path(),
+            patterns,
+            GlobWalkOptions {
+                parallel: true,
+                ..GlobWalkOptions::default()
+            },
+        )
+        .unwrap();
+
+        assert!(!sequential.is_empty());
+
+        sequential.sort();
+        parallel.sort();
+
+        assert_eq!(sequential, parallel);
+    }
+
+    #[test]
+    fn returns_relative_paths() {
+        let sandbox = create_empty_sandbox();
+        sandbox.create_file("one.txt", "");
+        sandbox.create_file("nested/two.txt", "");
+        sandbox.create_file("nested/deep/three.txt", "");
+
+        let mut paths = walk_relative(sandbox.path(), ["**/*.txt"]).unwrap();
+        paths.sort();
+
+        assert_eq!(
+            paths
+                .iter()
+                .map(|path| path.to_str().unwrap())
+                .collect::<Vec<_>>(),
+            vec!["nested/deep/three.txt", "nested/two.txt", "one.txt"]
+        );
+
+        let mut parallel_paths = walk_with_options(
+            sandbox.path(),
+            ["*.txt", "nested/**/*.txt"],
+            GlobWalkOptions {
+                parallel: true,
+                relative: true,
+                ..GlobWalkOptions::default()
+            },
+        )
+        .unwrap();
+        parallel_paths.sort();
+
+        assert_eq!(paths, parallel_paths);
+    }
+
+    fn walk_sorted(root: &Path, options: GlobWalkOptions) -> Vec<PathBuf> {
+        let mut paths = walk_with_options(
+            root,
+            ["*", "*/*"],
+            GlobWalkOptions {
+                relative: true,
+                ..options
+            },
+        )
+        .unwrap();
+        paths.sort();
+        paths
+    }
+
+    #[test]
+    fn excludes_files_over_max_size() {
+        let sandbox = create_empty_sandbox();
+        sandbox.create_file("small.txt", "small");
+        sandbox.create_file("large.txt", "l".repeat(1024));
+        sandbox.create_file("dir/exact.txt", "e".repeat(100));
+
+        assert_eq!(
+            walk_sorted(
+                sandbox.path(),
+                GlobWalkOptions {
+                    max_file_size: Some(100),
+                    ..GlobWalkOptions::default()
+                }
+            ),
+            vec![
+                PathBuf::from("dir"),
+                PathBuf::from("dir/exact.txt"),
+                PathBuf::from("small.txt")
+            ]
+        );
+    }
+
+    #[test]
+    fn excludes_binary_files() {
+        let sandbox = create_empty_sandbox();
+        sandbox.create_file("text.txt", "plain text");
+        sandbox.create_file("binary.bin", "bin\0ary");
+
+        assert_eq!(
+            walk_sorted(
+                sandbox.path(),
+                GlobWalkOptions {
+                    skip_binary: true,
+                    ..GlobWalkOptions::default()
+                }
+            ),
+            vec![PathBuf::from("text.txt")]
+        );
+    }
+
+    #[test]
+    fn includes_binary_and_large_files_by_default() {
+        let sandbox = create_empty_sandbox();
+        sandbox.create_file("large.txt", "l".repeat(1024));
+        sandbox.create_file("binary.bin", "bin\0ary");
+
+        assert_eq!(
+            walk_sorted(sandbox.path(), GlobWalkOptions::default()),
+            vec![PathBuf::from("binary.bin"), PathBuf::from("large.txt")]
+        );
+    }
+}
+
+mod globset_matches_many {
+    use super::*;
+
+    fn create_paths(count: usize) -> Vec<String> {
+        (0..count)
+            .map(|index| match index % 4 {
+                0 => format!("pkg-{index}/src/index.ts"),
+                1 => format!("pkg-{index}/src/index.test.ts"),
+                2 => format!("pkg-{index}/node_modules/dep/index.js"),
+                _ => format!("pkg-{index}/README.md"),
+            })
+            .collect()
+    }
+
+    fn assert_same_as_scalar(set: &GlobSet, paths: &[String]) {
+        assert_eq!(
+            set.matches_many(paths),
+            paths
+                .iter()
+                .map(|path| set.matches(path))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn matches_small_batches() {
+        let set = GlobSet::new(["**/*.{ts,js}", "!**/*.test.ts"]).unwrap();
+
+        assert_same_as_scalar(&set, &create_paths(10));
+        assert_eq!(
+            set.matches_many(&create_paths(4)),
+            vec![true, false, false, false]
+        );
+    }
+
+    #[test]
+    fn matches_large_batches_in_order() {
+        let set = GlobSet::new(["**/*.{ts,js}", "!**/*.test.ts"]).unwrap();
+
+        assert_same_as_scalar(&set, &create_paths(50_000));
+    }
+
+    #[test]
+    fn handles_empty() {
+        let set = GlobSet::new(["**/*"]).unwrap();
+        let empty: Vec<String> = vec![];
+
+        assert!(set.matches_many(&empty).is_empty());
+    }
+}
@@ -177,3 +177,167 @@ mod editor_config {
         assert!(!fs::read_file(&path).unwrap().ends_with('\n'));
     }
 }
+
+mod read_file_interpolated {
+    use super::*;
+    use starbase_sandbox::create_empty_sandbox;
+    use starbase_utils::json::JsonValue;
+
+    fn lookup(name: &str) -> Option<String> {
+        (name == "HOME").then(|| "/home/user".to_owned())
+    }
+
+    #[test]
+    fn substitutes_nested_string_values() {
+        let sandbox = create_empty_sandbox();
+        sandbox.create_file(
+            "config.json",
+            r#"{ "${HOME}": 1, "cache": "${HOME}/.cache", "nested": { "list": ["${HOME}", "${MISSING}", 2] } }"#,
+        );
+
+        let value: JsonValue =
+            json::read_file_interpolated(sandbox.path().join("config.json"), lookup).unwrap();
+
+        assert_eq!(
+            value,
+            object!({
+                "${HOME}": 1,
+                "cache": "/home/user/.cache",
+                "nested": { "list": ["/home/user", "${MISSING}", 2] }
+            })
+        );
+    }
+}
+
+mod read_array_each {
+    use super::*;
+    use serde::Deserialize;
+    use starbase_sandbox::create_empty_sandbox;
+    use starbase_utils::json::JsonError;
+
+    #[derive(Deserialize)]
+    struct Item {
+        id: usize,
+    }
+
+    #[derive(Debug)]
+    enum CallbackError {
+        Json,
+        Stop,
+    }
+
+    impl From<JsonError> for CallbackError {
+        fn from(_: JsonError) -> Self {
+            Self::Json
+        }
+    }
+
+    #[test]
+    fn visits_each_element() {
+        let sandbox = create_empty_sandbox();
+        let items = (0..10_000)
+            .map(|id| object!({ "id": id, "name": format!("item-{id}") }))
+            .collect::<Vec<_>>();
+
+        json::write_file(sandbox.path().join("data.json"), &items, true).unwrap();
+
+        let mut count = 0;
+        let mut sum = 0;
+
+        json::read_array_each(sandbox.path().join("data.json"), |item: Item| {
+            count += 1;
+            sum += item.id;
+            Ok::<_, JsonError>(())
+        })
+        .unwrap();
+
+        assert_eq!(count, 10_000);
+        assert_eq!(sum, (0..10_000).sum::<usize>());
+    }
+
+    #[test]
+    fn surfaces_parse_errors_mid_stream() {
+        let sandbox = create_empty_sandbox();
+        sandbox.create_file(
+            "data.json",
+            r#"[{ "id": 1 }, { "id": 2 }, { "id": "x" }, { "id": 4 }]"#,
+        );
+
+        let mut ids = vec![];
+        let result = json::read_array_each(sandbox.path().join("data.json"), |item: Item| {
+            ids.push(item.id);
+            Ok::<_, JsonError>(())
+        });
+
+        assert!(matches!(result, Err(JsonError::ReadFile { .. })));
+        assert_eq!(ids, vec![1, 2]);
+    }
+
+    #[test]
+    fn surfaces_callback_errors() {
+        let sandbox = create_empty_sandbox();
+        sandbox.create_file("data.json", r#"[{ "id": 1 }, { "id": 2 }, { "id": 3 }]"#);
+
+        let mut ids = vec![];
+        let result = json::read_array_each(sandbox.path().join("data.json"), |item: Item| {
+            if item.id == 2 {
+                return Err(CallbackError::Stop);
+            }
+
+            ids.push(item.id);
+            Ok(())
+        });
+
+        assert!(matches!(result, Err(CallbackError::Stop)));
+        assert_eq!(ids, vec![1]);
+    }
+
+    #[test]
+    fn errors_for_non_array() {
+        let sandbox = create_empty_sandbox();
+        sandbox.create_file("data.json", r#"{ "id": 1 }"#);
+
+        let result = json::read_array_each(sandbox.path().join("data.json"), |_: Item| {
+            Ok::<_, JsonError>(())
+        });
+
+        assert!(result.is_err());
+    }
+}
+
+mod parse_errors {
+    use super::*;
+    use starbase_sandbox::create_empty_sandbox;
+
+    #[test]
+    fn reports_location_for_string() {
+        let error =
+            json::parse::<_, json::JsonValue>("{\n  \"a\": 1,\n  \"b\": nope\n}").unwrap_err();
+
+        assert_eq!(error.location(), Some((3, 9)));
+    }
+
+    #[test]
+    fn reports_location_for_file() {
+        let sandbox = create_empty_sandbox();
+        sandbox.create_file("file.json", "{\n  \"a\": 1,\n  \"b\": nope\n}");
+
+        let error =
+            json::read_file::<_, json::JsonValue>(sandbox.path().join("file.json")).unwrap_err();
+
+        assert_eq!(error.location(), Some((3, 9)));
+        assert!(error.to_string().contains("file.json"));
+    }
+
+    #[test]
+    fn labels_source_span() {
+        use miette::Diagnostic;
+
+        let error =
+            json::parse::<_, json::JsonValue>("{\n  \"a\": 1,\n  \"b\": nope\n}").unwrap_err();
+        let label = error.labels().unwrap().next().unwrap();
+
+        assert!(error.source_code().is_some());
+        assert_eq!(label.offset(), 20);
+    }
+}
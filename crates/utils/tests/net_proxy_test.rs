@@ -0,0 +1,117 @@
+use starbase_sandbox::create_empty_sandbox;
+use starbase_utils::net::{self, DownloadOptions};
+use std::env;
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::thread;
+
+// Start a local HTTP server that responds to every request with the provided body,
+// and return its base URL. Used as both the proxy and the target server.
+fn start_server(body: &'static str) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+
+    thread::spawn(move || {
+        for mut stream in listener.incoming().flatten() {
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+            // Drain the request line and headers
+            loop {
+                let mut line = String::new();
+
+                if reader.read_line(&mut line).unwrap() == 0 || line == "\r\n" {
+                    break;
+                }
+            }
+
+            let _ = write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            );
+        }
+    });
+
+    format!("http://{address}")
+}
+
+fn reset_env() {
+    for name in [
+        "HTTP_PROXY",
+        "http_proxy",
+        "HTTPS_PROXY",
+        "https_proxy",
+        "ALL_PROXY",
+        "all_proxy",
+        "NO_PROXY",
+        "no_proxy",
+    ] {
+        env::remove_var(name);
+    }
+}
+
+async fn download(url: &str, options: DownloadOptions) -> String {
+    let sandbox = create_empty_sandbox();
+    let file = sandbox.path().join("file");
+
+    net::download_from_url_with_options(url, &file, options)
+        .await
+        .unwrap();
+
+    std::fs::read_to_string(file).unwrap()
+}
+
+// Environment variables are process-wide, so all scenarios are run
+// sequentially within a single test.
+#[tokio::test]
+async fn configures_proxy() {
+    let proxy_url = start_server("proxied");
+    let target_url = format!("{}/file.txt", start_server("direct"));
+
+    // From env vars
+    reset_env();
+    env::set_var("HTTP_PROXY", &proxy_url);
+
+    assert_eq!(
+        download(&target_url, DownloadOptions::default()).await,
+        "proxied"
+    );
+
+    env::set_var("NO_PROXY", "127.0.0.1");
+
+    assert_eq!(
+        download(&target_url, DownloadOptions::default()).await,
+        "direct"
+    );
+
+    // From options
+    reset_env();
+
+    assert_eq!(
+        download(
+            &target_url,
+            DownloadOptions {
+                proxy: Some(proxy_url.clone()),
+                ..DownloadOptions::default()
+            }
+        )
+        .await,
+        "proxied"
+    );
+
+    env::set_var("NO_PROXY", "127.0.0.1");
+
+    assert_eq!(
+        download(
+            &target_url,
+            DownloadOptions {
+                proxy: Some(proxy_url.clone()),
+                ..DownloadOptions::default()
+            }
+        )
+        .await,
+        "direct"
+    );
+
+    reset_env();
+}
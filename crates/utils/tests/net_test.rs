@@ -1,5 +1,56 @@
 use starbase_sandbox::create_empty_sandbox;
-use starbase_utils::net;
+use starbase_utils::cancel::CancellationToken;
+use starbase_utils::net::{self, DownloadManyOptions, DownloadOptions, NetError};
+use std::collections::BTreeSet;
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+use url::Url;
+
+// Start a local HTTP server that responds to `/<status>/<body>` requests
+// with the provided status code and body, and return its base URL.
+fn start_server() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+
+    thread::spawn(move || {
+        for stream in listener.incoming() {
+            let Ok(mut stream) = stream else {
+                continue;
+            };
+
+            thread::spawn(move || {
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+
+                // Drain the headers
+                loop {
+                    let mut line = String::new();
+
+                    if reader.read_line(&mut line).unwrap() == 0 || line == "\r\n" {
+                        break;
+                    }
+                }
+
+                let path = request_line.split_whitespace().nth(1).unwrap_or("/");
+                let mut parts = path.trim_start_matches('/').splitn(2, '/');
+                let status = parts.next().unwrap_or("200");
+                let body = parts.next().unwrap_or("");
+
+                let _ = write!(
+                    stream,
+                    "HTTP/1.1 {status} Status\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+            });
+        }
+    });
+
+    format!("http://{address}")
+}
 
 mod download {
     use super::*;
@@ -47,4 +98,495 @@ mod download {
         assert!(dest_file.exists());
         assert_ne!(dest_file.metadata().unwrap().len(), 0);
     }
+
+    #[tokio::test]
+    async fn throttles_to_max_bytes_per_sec() {
+        let sandbox = create_empty_sandbox();
+        let dest_file = sandbox.path().join("file.txt");
+        let body = "a".repeat(2000);
+        let started = Instant::now();
+
+        net::download_from_url_with_options(
+            format!("{}/200/{body}", start_server()),
+            &dest_file,
+            DownloadOptions {
+                max_bytes_per_sec: Some(4000),
+                ..DownloadOptions::default()
+            },
+        )
+        .await
+        .unwrap();
+
+        // 2000 bytes at 4000 bytes/sec should take at least 500ms,
+        // with some tolerance for timer precision
+        assert!(started.elapsed() >= Duration::from_millis(450));
+        assert_eq!(std::fs::read_to_string(&dest_file).unwrap(), body);
+    }
+
+    #[tokio::test]
+    async fn removes_partial_file_when_cancelled() {
+        let sandbox = create_empty_sandbox();
+        let dest_file = sandbox.path().join("file.txt");
+        let token = CancellationToken::new();
+        let cancel_token = token.clone();
+
+        let error = net::download_from_url_with_options(
+            format!("{}/200/content", start_server()),
+            &dest_file,
+            DownloadOptions {
+                cancel_token: Some(token),
+                // Cancel once the download has started
+                on_chunk: Some(Box::new(move |_, _| cancel_token.cancel())),
+                ..DownloadOptions::default()
+            },
+        )
+        .await
+        .unwrap_err();
+
+        assert!(matches!(error, NetError::Cancelled { .. }));
+        assert!(!dest_file.exists());
+    }
+
+    #[tokio::test]
+    async fn skips_request_when_already_cancelled() {
+        let sandbox = create_empty_sandbox();
+        let dest_file = sandbox.path().join("file.txt");
+        let token = CancellationToken::new();
+        token.cancel();
+
+        // Nothing listens on this port, so sending the request would fail
+        let error = net::download_from_url_with_options(
+            "http://127.0.0.1:1/200/content",
+            &dest_file,
+            DownloadOptions {
+                cancel_token: Some(token),
+                ..DownloadOptions::default()
+            },
+        )
+        .await
+        .unwrap_err();
+
+        assert!(matches!(error, NetError::Cancelled { .. }));
+        assert!(!dest_file.exists());
+    }
+}
+
+mod is_transient {
+    use super::*;
+
+    async fn download_error(url: String) -> NetError {
+        let sandbox = create_empty_sandbox();
+
+        net::download_from_url(url, sandbox.path().join("file"))
+            .await
+            .unwrap_err()
+    }
+
+    #[tokio::test]
+    async fn connection_errors() {
+        // Bind and drop to get an unused port
+        let port = TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+
+        assert!(download_error(format!("http://127.0.0.1:{port}"))
+            .await
+            .is_transient());
+    }
+
+    #[tokio::test]
+    async fn server_errors() {
+        let url = start_server();
+
+        assert!(download_error(format!("{url}/500")).await.is_transient());
+        assert!(download_error(format!("{url}/503")).await.is_transient());
+        assert!(download_error(format!("{url}/429")).await.is_transient());
+    }
+
+    #[tokio::test]
+    async fn client_errors() {
+        let url = start_server();
+
+        assert!(!download_error(format!("{url}/404")).await.is_transient());
+        assert!(!download_error(format!("{url}/403")).await.is_transient());
+        assert!(!download_error(format!("{url}/400")).await.is_transient());
+    }
+
+    #[test]
+    fn other_variants() {
+        assert!(!NetError::Cancelled { url: "".into() }.is_transient());
+        assert!(!NetError::UrlNotFound { url: "".into() }.is_transient());
+        assert!(!NetError::HttpUnknown {
+            url: "".into(),
+            error: "".into()
+        }
+        .is_transient());
+        assert!(!NetError::UrlParseFailed {
+            url: "".into(),
+            error: Box::new(url::ParseError::EmptyHost)
+        }
+        .is_transient());
+        assert!(NetError::DownloadFailed {
+            url: "".into(),
+            status: "502 Bad Gateway".into()
+        }
+        .is_transient());
+    }
+}
+
+mod download_many {
+    use super::*;
+
+    #[derive(Clone, Default)]
+    struct CapturedWriter(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for CapturedWriter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn doesnt_trace_item_urls() {
+        let sandbox = create_empty_sandbox();
+        let url = start_server();
+        let items = vec![(
+            Url::parse(&format!("{url}/200/file?token=hunter2"))
+                .map(|mut url| {
+                    url.set_username("user").unwrap();
+                    url.set_password(Some("secret")).unwrap();
+                    url
+                })
+                .unwrap(),
+            sandbox.path().join("file.txt"),
+        )];
+
+        let writer = CapturedWriter::default();
+        let captured = writer.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .with_ansi(false)
+            .with_max_level(tracing::Level::TRACE)
+            .with_writer(move || writer.clone())
+            .finish();
+        let _guard = tracing::subscriber::set_default(subscriber);
+
+        net::download_many(items, 1, DownloadManyOptions::default())
+            .await
+            .unwrap();
+
+        let output = String::from_utf8(captured.0.lock().unwrap().clone()).unwrap();
+
+        assert!(output.contains("download_many{concurrency=1 count=1}"));
+        assert!(!output.contains("hunter2"));
+        assert!(!output.contains("secret"));
+    }
+
+    #[tokio::test]
+    async fn downloads_all_items() {
+        let sandbox = create_empty_sandbox();
+        let url = start_server();
+        let items = (0..5)
+            .map(|i| {
+                (
+                    Url::parse(&format!("{url}/200/file{i}")).unwrap(),
+                    sandbox.path().join(format!("file{i}.txt")),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let results = net::download_many(items, 2, DownloadManyOptions::default())
+            .await
+            .unwrap();
+
+        assert_eq!(results.len(), 5);
+        assert!(results.iter().all(|result| result.is_ok()));
+
+        for i in 0..5 {
+            assert_eq!(
+                std::fs::read_to_string(sandbox.path().join(format!("file{i}.txt"))).unwrap(),
+                format!("file{i}")
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn returns_result_per_item() {
+        let sandbox = create_empty_sandbox();
+        let url = start_server();
+        let items = vec![
+            (
+                Url::parse(&format!("{url}/200/a")).unwrap(),
+                sandbox.path().join("a.txt"),
+            ),
+            (
+                Url::parse(&format!("{url}/404")).unwrap(),
+                sandbox.path().join("b.txt"),
+            ),
+            (
+                Url::parse(&format!("{url}/200/c")).unwrap(),
+                sandbox.path().join("c.txt"),
+            ),
+        ];
+
+        let results = net::download_many(items, 3, DownloadManyOptions::default())
+            .await
+            .unwrap();
+
+        assert!(results[0].is_ok());
+        assert!(matches!(results[1], Err(NetError::UrlNotFound { .. })));
+        assert!(results[2].is_ok());
+        assert!(!sandbox.path().join("b.txt").exists());
+    }
+
+    #[tokio::test]
+    async fn identifies_items_in_progress() {
+        let sandbox = create_empty_sandbox();
+        let url = start_server();
+        let items = (0..3)
+            .map(|i| {
+                (
+                    Url::parse(&format!("{url}/200/file{i}")).unwrap(),
+                    sandbox.path().join(format!("file{i}.txt")),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let seen = Arc::new(Mutex::new(BTreeSet::new()));
+        let seen_clone = Arc::clone(&seen);
+
+        net::download_many(
+            items,
+            2,
+            DownloadManyOptions {
+                on_chunk: Some(Box::new(move |index, current, total| {
+                    if current == total && total > 0 {
+                        seen_clone.lock().unwrap().insert(index);
+                    }
+                })),
+                ..DownloadManyOptions::default()
+            },
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(*seen.lock().unwrap(), BTreeSet::from([0, 1, 2]));
+    }
+}
+
+mod fetch {
+    use super::*;
+    use net::FetchOptions;
+
+    #[tokio::test]
+    async fn returns_text() {
+        let url = start_server();
+
+        assert_eq!(
+            net::fetch_text(format!("{url}/200/hello")).await.unwrap(),
+            "hello"
+        );
+    }
+
+    #[tokio::test]
+    async fn returns_json() {
+        let url = start_server();
+
+        assert_eq!(
+            net::fetch_json::<_, Vec<u32>>(format!("{url}/200/[1,2,3]"))
+                .await
+                .unwrap(),
+            vec![1, 2, 3]
+        );
+    }
+
+    #[tokio::test]
+    async fn errors_invalid_json() {
+        let url = start_server();
+
+        assert!(matches!(
+            net::fetch_json::<_, Vec<u32>>(format!("{url}/200/nope"))
+                .await
+                .unwrap_err(),
+            NetError::InvalidJson { .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn errors_non_success_status() {
+        let url = start_server();
+
+        assert!(matches!(
+            net::fetch_text(format!("{url}/500/oops"))
+                .await
+                .unwrap_err(),
+            NetError::DownloadFailed { .. }
+        ));
+        assert!(matches!(
+            net::fetch_text(format!("{url}/404")).await.unwrap_err(),
+            NetError::UrlNotFound { .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn errors_invalid_utf8() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+
+        thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut line = String::new();
+
+            while reader.read_line(&mut line).unwrap() > 0 && line != "\r\n" {
+                line.clear();
+            }
+
+            stream
+                .write_all(
+                    b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\nConnection: close\r\n\r\n\xff\xfe",
+                )
+                .unwrap();
+        });
+
+        assert!(matches!(
+            net::fetch_text(format!("http://{address}/"))
+                .await
+                .unwrap_err(),
+            NetError::InvalidUtf8 { .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn errors_when_too_large() {
+        let url = start_server();
+
+        assert!(matches!(
+            net::fetch_text_with_options(
+                format!("{url}/200/abcdef"),
+                FetchOptions {
+                    max_size: 3,
+                    ..FetchOptions::default()
+                }
+            )
+            .await
+            .unwrap_err(),
+            NetError::ResponseTooLarge { max_size: 3, .. }
+        ));
+    }
+}
+
+mod download_if_modified {
+    use super::*;
+    use net::{DownloadMetadata, Downloaded};
+
+    // Start a local HTTP server that always responds with an `ETag`, and with
+    // a `304` when the request's `If-None-Match` header matches it.
+    fn start_etag_server(requests: Arc<Mutex<Vec<Option<String>>>>) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(mut stream) = stream else {
+                    continue;
+                };
+
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut if_none_match = None;
+
+                loop {
+                    let mut line = String::new();
+
+                    if reader.read_line(&mut line).unwrap() == 0 || line == "\r\n" {
+                        break;
+                    }
+
+                    if let Some((name, value)) = line.split_once(':') {
+                        if name.eq_ignore_ascii_case("if-none-match") {
+                            if_none_match = Some(value.trim().to_owned());
+                        }
+                    }
+                }
+
+                let response = if if_none_match.as_deref() == Some("\"v1\"") {
+                    "HTTP/1.1 304 Not Modified\r\nETag: \"v1\"\r\nConnection: close\r\n\r\n"
+                        .to_owned()
+                } else {
+                    "HTTP/1.1 200 OK\r\nETag: \"v1\"\r\nContent-Length: 7\r\nConnection: close\r\n\r\ncontent"
+                        .to_owned()
+                };
+
+                requests.lock().unwrap().push(if_none_match);
+
+                let _ = stream.write_all(response.as_bytes());
+            }
+        });
+
+        format!("http://{address}")
+    }
+
+    #[tokio::test]
+    async fn downloads_then_keeps_unmodified_file() {
+        let sandbox = create_empty_sandbox();
+        let requests = Arc::new(Mutex::new(vec![]));
+        let url = start_etag_server(Arc::clone(&requests));
+        let dest_file = sandbox.path().join("file.txt");
+
+        let status =
+            net::download_from_url_if_modified(&url, &dest_file, DownloadOptions::default())
+                .await
+                .unwrap();
+
+        assert_eq!(status, Downloaded::Modified);
+        assert_eq!(std::fs::read_to_string(&dest_file).unwrap(), "content");
+        assert_eq!(
+            DownloadMetadata::load(&dest_file).unwrap().etag.unwrap(),
+            "\"v1\""
+        );
+
+        // Change the local file to verify it's kept as-is
+        std::fs::write(&dest_file, "local").unwrap();
+
+        let status =
+            net::download_from_url_if_modified(&url, &dest_file, DownloadOptions::default())
+                .await
+                .unwrap();
+
+        assert_eq!(status, Downloaded::NotModified);
+        assert_eq!(std::fs::read_to_string(&dest_file).unwrap(), "local");
+        assert_eq!(
+            *requests.lock().unwrap(),
+            vec![None, Some("\"v1\"".to_owned())]
+        );
+    }
+
+    #[tokio::test]
+    async fn redownloads_when_file_missing() {
+        let sandbox = create_empty_sandbox();
+        let requests = Arc::new(Mutex::new(vec![]));
+        let url = start_etag_server(Arc::clone(&requests));
+        let dest_file = sandbox.path().join("file.txt");
+
+        net::download_from_url_if_modified(&url, &dest_file, DownloadOptions::default())
+            .await
+            .unwrap();
+
+        std::fs::remove_file(&dest_file).unwrap();
+
+        let status =
+            net::download_from_url_if_modified(&url, &dest_file, DownloadOptions::default())
+                .await
+                .unwrap();
+
+        assert_eq!(status, Downloaded::Modified);
+        assert!(dest_file.exists());
+        assert_eq!(*requests.lock().unwrap(), vec![None, None]);
+    }
 }
@@ -0,0 +1,197 @@
+use starbase_utils::path;
+use std::path::PathBuf;
+
+mod normalize {
+    use super::*;
+
+    #[test]
+    fn removes_current_dir() {
+        assert_eq!(path::normalize("./a/./b/."), PathBuf::from("a/b"));
+    }
+
+    #[test]
+    fn resolves_parent_dir() {
+        assert_eq!(path::normalize("a/b/../c"), PathBuf::from("a/c"));
+        assert_eq!(path::normalize("a/../../b"), PathBuf::from("../b"));
+    }
+
+    #[cfg(not(windows))]
+    #[test]
+    fn doesnt_escape_absolute_root() {
+        assert_eq!(path::normalize("/a/../../b"), PathBuf::from("/b"));
+    }
+}
+
+mod is_within {
+    use super::*;
+
+    #[cfg(not(windows))]
+    #[test]
+    fn in_bounds() {
+        assert!(path::is_within("/root", "/root"));
+        assert!(path::is_within("/root", "/root/a/b"));
+        assert!(path::is_within("/root/", "/root/a/../b"));
+        assert!(path::is_within("/root", "a/./b"));
+        assert!(path::is_within("rel", "rel/a"));
+    }
+
+    #[cfg(not(windows))]
+    #[test]
+    fn escaping() {
+        assert!(!path::is_within("/root", "/root/../other"));
+        assert!(!path::is_within("/root", "/rootless/a"));
+        assert!(!path::is_within("/root", "/other"));
+        assert!(!path::is_within("/root", "../root-sibling"));
+        assert!(!path::is_within("/root", "a/../../b"));
+        assert!(!path::is_within("rel", "../other/a"));
+    }
+
+    #[test]
+    fn empty_paths() {
+        assert!(!path::is_within("", "x"));
+        assert!(!path::is_within("", "../x"));
+        assert!(!path::is_within("/root", ""));
+        assert!(!path::is_within("", ""));
+    }
+
+    #[test]
+    fn current_dir_root() {
+        assert!(path::is_within(".", "x"));
+        assert!(path::is_within(".", "./x/y"));
+        assert!(!path::is_within(".", "../x"));
+        assert!(!path::is_within("./", "x/../../y"));
+        assert!(!path::is_within(".", "/x"));
+    }
+
+    #[cfg(not(windows))]
+    #[test]
+    fn is_case_sensitive() {
+        assert!(!path::is_within("/root", "/ROOT/a"));
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn in_bounds() {
+        assert!(path::is_within("C:\\root", "C:\\root\\a"));
+        assert!(path::is_within("C:\\root", "c:\\ROOT\\a"));
+        assert!(path::is_within("C:\\root", "\\\\?\\C:\\root\\a"));
+        assert!(path::is_within("C:\\root", "a\\b"));
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn escaping() {
+        assert!(!path::is_within("C:\\root", "C:\\root\\..\\other"));
+        assert!(!path::is_within("C:\\root", "a\\..\\..\\b"));
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn cross_drive() {
+        assert!(!path::is_within("C:\\root", "D:\\root\\a"));
+        assert!(!path::is_within("C:\\root", "D:root\\a"));
+    }
+}
+
+mod exe_name {
+    use super::*;
+
+    #[cfg(not(windows))]
+    #[test]
+    fn returns_as_is() {
+        assert_eq!(path::exe_name("node"), "node");
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn appends_exe_suffix() {
+        assert_eq!(path::exe_name("node"), "node.exe");
+        assert_eq!(path::exe_name("node.exe"), "node.exe");
+        assert_eq!(path::exe_name("NODE.EXE"), "NODE.EXE");
+    }
+}
+
+mod find_executable {
+    use super::*;
+    use starbase_sandbox::create_empty_sandbox;
+
+    #[cfg(unix)]
+    fn create_exe(sandbox: &starbase_sandbox::Sandbox, name: &str) {
+        use std::os::unix::fs::PermissionsExt;
+
+        sandbox.create_file(name, "");
+
+        std::fs::set_permissions(
+            sandbox.path().join(name),
+            std::fs::Permissions::from_mode(0o755),
+        )
+        .unwrap();
+    }
+
+    #[cfg(windows)]
+    fn create_exe(sandbox: &starbase_sandbox::Sandbox, name: &str) {
+        sandbox.create_file(format!("{name}.exe"), "");
+    }
+
+    #[test]
+    fn returns_none_when_not_found() {
+        let sandbox = create_empty_sandbox();
+
+        assert_eq!(path::find_executable("tool", [sandbox.path()]), None);
+    }
+
+    #[test]
+    fn returns_first_match_in_order() {
+        let sandbox = create_empty_sandbox();
+        create_exe(&sandbox, "a/tool");
+        create_exe(&sandbox, "b/tool");
+
+        let found = path::find_executable(
+            "tool",
+            [
+                sandbox.path().join("c"),
+                sandbox.path().join("b"),
+                sandbox.path().join("a"),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(found, sandbox.path().join("b").join(path::exe_name("tool")));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn skips_non_executable_files() {
+        let sandbox = create_empty_sandbox();
+        sandbox.create_file("a/tool", "");
+        create_exe(&sandbox, "b/tool");
+
+        let found =
+            path::find_executable("tool", [sandbox.path().join("a"), sandbox.path().join("b")]);
+
+        assert_eq!(found, Some(sandbox.path().join("b/tool")));
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn matches_other_extensions() {
+        let sandbox = create_empty_sandbox();
+        sandbox.create_file("tool.cmd", "");
+
+        assert_eq!(
+            path::find_executable("tool", [sandbox.path()]),
+            Some(sandbox.path().join("tool.cmd"))
+        );
+        assert_eq!(
+            path::find_executable("tool.cmd", [sandbox.path()]),
+            Some(sandbox.path().join("tool.cmd"))
+        );
+    }
+
+    #[test]
+    fn searches_path_env_var() {
+        let found = path::find_executable_on_path(if cfg!(windows) { "cmd" } else { "sh" });
+
+        assert!(found.is_some());
+    }
+}
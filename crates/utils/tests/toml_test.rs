@@ -0,0 +1,277 @@
+use serde::Serialize;
+use starbase_utils::toml::{self, TomlFormatOptions};
+
+#[derive(Serialize)]
+struct Meta {
+    enabled: bool,
+}
+
+#[derive(Serialize)]
+struct Plugin {
+    name: String,
+    meta: Meta,
+}
+
+#[derive(Serialize)]
+struct Config {
+    title: String,
+    meta: Meta,
+    plugins: Vec<Plugin>,
+}
+
+fn create_config() -> Config {
+    Config {
+        title: "Example".into(),
+        meta: Meta { enabled: true },
+        plugins: vec![
+            Plugin {
+                name: "a".into(),
+                meta: Meta { enabled: true },
+            },
+            Plugin {
+                name: "b".into(),
+                meta: Meta { enabled: false },
+            },
+        ],
+    }
+}
+
+mod format_with_options {
+    use super::*;
+
+    #[test]
+    fn formats_array_tables_as_blocks() {
+        assert_eq!(
+            toml::format_with_options(&create_config(), TomlFormatOptions::default()).unwrap(),
+            r#"title = "Example"
+
+[meta]
+enabled = true
+
+[[plugins]]
+name = "a"
+
+[plugins.meta]
+enabled = true
+
+[[plugins]]
+name = "b"
+
+[plugins.meta]
+enabled = false
+"#
+        );
+    }
+
+    #[test]
+    fn formats_nested_tables_inline() {
+        assert_eq!(
+            toml::format_with_options(
+                &create_config(),
+                TomlFormatOptions {
+                    inline_tables: true,
+                    ..TomlFormatOptions::default()
+                }
+            )
+            .unwrap(),
+            r#"title = "Example"
+meta = { enabled = true }
+
+[[plugins]]
+name = "a"
+meta = { enabled = true }
+
+[[plugins]]
+name = "b"
+meta = { enabled = false }
+"#
+        );
+    }
+
+    #[test]
+    fn formats_array_tables_inline() {
+        assert_eq!(
+            toml::format_with_options(
+                &create_config(),
+                TomlFormatOptions {
+                    inline_array_tables: true,
+                    inline_tables: true,
+                    ..TomlFormatOptions::default()
+                }
+            )
+            .unwrap(),
+            r#"title = "Example"
+meta = { enabled = true }
+plugins = [{ name = "a", meta = { enabled = true } }, { name = "b", meta = { enabled = false } }]
+"#
+        );
+    }
+
+    #[test]
+    fn converts_inline_array_tables_to_blocks() {
+        let value: toml::TomlValue =
+            toml::parse(r#"root = { deps = [{ name = "a" }, { name = "b" }] }"#).unwrap();
+
+        assert_eq!(
+            toml::format_with_options(&value, TomlFormatOptions::default()).unwrap(),
+            r#"[[root.deps]]
+name = "a"
+
+[[root.deps]]
+name = "b"
+"#
+        );
+    }
+}
+
+mod read_file_interpolated {
+    use super::*;
+    use serde::Deserialize;
+    use starbase_sandbox::create_empty_sandbox;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Tool {
+        paths: Vec<String>,
+        version: u32,
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct ToolConfig {
+        cache: String,
+        tool: Tool,
+    }
+
+    #[test]
+    fn substitutes_nested_string_values() {
+        let sandbox = create_empty_sandbox();
+        sandbox.create_file(
+            "config.toml",
+            "cache = \"${HOME}/.cache\"\n\n[tool]\npaths = [\"${HOME}/bin\", \"${MISSING}\"]\nversion = 1\n",
+        );
+
+        let config: ToolConfig =
+            toml::read_file_interpolated(sandbox.path().join("config.toml"), |name: &str| {
+                (name == "HOME").then(|| "/home/user".to_owned())
+            })
+            .unwrap();
+
+        assert_eq!(
+            config,
+            ToolConfig {
+                cache: "/home/user/.cache".into(),
+                tool: Tool {
+                    paths: vec!["/home/user/bin".into(), "${MISSING}".into()],
+                    version: 1,
+                }
+            }
+        );
+    }
+}
+
+mod editor_config {
+    use super::*;
+    use starbase_sandbox::create_empty_sandbox;
+    use starbase_utils::fs;
+
+    #[derive(Serialize)]
+    struct Project {
+        name: String,
+        tags: Vec<String>,
+        matrix: Vec<Vec<u32>>,
+    }
+
+    fn create_project() -> Project {
+        Project {
+            name: "example".into(),
+            tags: vec!["a".into(), "b".into()],
+            matrix: vec![vec![1, 2], vec![3, 4]],
+        }
+    }
+
+    fn write_with_config(config: &str) -> String {
+        let sandbox = create_empty_sandbox();
+        sandbox.create_file(".editorconfig", format!("root = true\n\n{config}"));
+
+        let path = sandbox.path().join("file.toml");
+
+        toml::write_file_with_config(&path, &create_project(), true).unwrap();
+
+        fs::read_file(&path).unwrap()
+    }
+
+    #[test]
+    fn uses_defaults_when_no_config() {
+        assert_eq!(
+            write_with_config(""),
+            "name = \"example\"\ntags = [\n  \"a\",\n  \"b\",\n]\nmatrix = [\n  [\n    1,\n    2,\n  ],\n  [\n    3,\n    4,\n  ],\n]\n"
+        );
+    }
+
+    #[test]
+    fn can_change_space_indent() {
+        assert_eq!(
+            write_with_config("[*.toml]\nindent_style = space\nindent_size = 2"),
+            "name = \"example\"\ntags = [\n  \"a\",\n  \"b\",\n]\nmatrix = [\n  [\n    1,\n    2,\n  ],\n  [\n    3,\n    4,\n  ],\n]\n"
+        );
+    }
+
+    #[test]
+    fn can_change_tab_indent() {
+        assert_eq!(
+            write_with_config("[*.toml]\nindent_style = tab"),
+            "name = \"example\"\ntags = [\n\t\"a\",\n\t\"b\",\n]\nmatrix = [\n\t[\n\t\t1,\n\t\t2,\n\t],\n\t[\n\t\t3,\n\t\t4,\n\t],\n]\n"
+        );
+    }
+
+    #[test]
+    fn can_disable_trailing_line() {
+        assert!(!write_with_config("[*.toml]\ninsert_final_newline = false").ends_with('\n'));
+    }
+
+    #[test]
+    fn writes_ugly() {
+        let sandbox = create_empty_sandbox();
+        sandbox.create_file(".editorconfig", "root = true\n\n[*]\nindent_style = tab");
+
+        let path = sandbox.path().join("file.toml");
+
+        toml::write_file_with_config(&path, &create_project(), false).unwrap();
+
+        assert!(!fs::read_file(&path).unwrap().contains('\t'));
+    }
+}
+
+mod parse_errors {
+    use super::*;
+    use starbase_sandbox::create_empty_sandbox;
+
+    #[test]
+    fn reports_location_for_string() {
+        let error = toml::parse::<_, toml::TomlValue>("a = 1\nb = \nc = 3").unwrap_err();
+
+        assert_eq!(error.location(), Some((2, 5)));
+    }
+
+    #[test]
+    fn reports_location_for_file() {
+        let sandbox = create_empty_sandbox();
+        sandbox.create_file("file.toml", "a = 1\nb = \nc = 3");
+
+        let error =
+            toml::read_file::<_, toml::TomlValue>(sandbox.path().join("file.toml")).unwrap_err();
+
+        assert_eq!(error.location(), Some((2, 5)));
+        assert!(error.to_string().contains("file.toml"));
+    }
+
+    #[test]
+    fn labels_source_span() {
+        use miette::Diagnostic;
+
+        let error = toml::parse::<_, toml::TomlValue>("a = 1\nb = \nc = 3").unwrap_err();
+        let label = error.labels().unwrap().next().unwrap();
+
+        assert!(error.source_code().is_some());
+        assert_eq!(label.offset(), 10);
+    }
+}
@@ -87,3 +87,119 @@ mod editor_config {
         assert!(!fs::read_file(&path).unwrap().ends_with('\n'));
     }
 }
+
+mod read_file_interpolated {
+    use super::*;
+    use starbase_sandbox::create_empty_sandbox;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn substitutes_nested_string_values() {
+        let sandbox = create_empty_sandbox();
+        sandbox.create_file(
+            "config.yaml",
+            "cache: ${HOME}/.cache\nlist:\n  - ${HOME}\n  - ${MISSING}\n",
+        );
+
+        let value: BTreeMap<String, Value> =
+            yaml::read_file_interpolated(sandbox.path().join("config.yaml"), |name: &str| {
+                (name == "HOME").then(|| "/home/user".to_owned())
+            })
+            .unwrap();
+
+        assert_eq!(value["cache"], "/home/user/.cache");
+        assert_eq!(value["list"][0], "/home/user");
+        assert_eq!(value["list"][1], "${MISSING}");
+    }
+}
+
+mod read_file_multi {
+    use super::*;
+    use starbase_sandbox::create_empty_sandbox;
+
+    #[derive(Debug, PartialEq, serde::Deserialize)]
+    struct Manifest {
+        kind: String,
+    }
+
+    #[test]
+    fn reads_single_document() {
+        let sandbox = create_empty_sandbox();
+        sandbox.create_file("file.yaml", "kind: Pod\n");
+
+        let docs: Vec<Manifest> = yaml::read_file_multi(sandbox.path().join("file.yaml")).unwrap();
+
+        assert_eq!(docs, vec![Manifest { kind: "Pod".into() }]);
+    }
+
+    #[test]
+    fn reads_multiple_documents_in_order() {
+        let sandbox = create_empty_sandbox();
+        sandbox.create_file(
+            "file.yaml",
+            "---\nkind: Pod\n---\nkind: Service\n---\nkind: Deployment\n",
+        );
+
+        let docs: Vec<Manifest> = yaml::read_file_multi(sandbox.path().join("file.yaml")).unwrap();
+
+        assert_eq!(
+            docs.into_iter().map(|doc| doc.kind).collect::<Vec<_>>(),
+            ["Pod", "Service", "Deployment"]
+        );
+    }
+
+    #[test]
+    fn skips_empty_trailing_document() {
+        let sandbox = create_empty_sandbox();
+        sandbox.create_file("file.yaml", "kind: Pod\n---\nkind: Service\n---\n");
+
+        let docs: Vec<Manifest> = yaml::read_file_multi(sandbox.path().join("file.yaml")).unwrap();
+
+        assert_eq!(docs.len(), 2);
+    }
+
+    #[test]
+    fn errors_for_invalid_document() {
+        let sandbox = create_empty_sandbox();
+        sandbox.create_file("file.yaml", "kind: Pod\n---\nname: unknown\n");
+
+        let error =
+            yaml::read_file_multi::<_, Manifest>(sandbox.path().join("file.yaml")).unwrap_err();
+
+        assert!(error.to_string().contains("file.yaml"));
+    }
+}
+
+mod parse_errors {
+    use super::*;
+    use starbase_sandbox::create_empty_sandbox;
+
+    #[test]
+    fn reports_location_for_string() {
+        let error = yaml::parse::<_, Value>("a: 1\nb: [1, 2\nc: 3").unwrap_err();
+
+        assert_eq!(error.location(), Some((3, 2)));
+    }
+
+    #[test]
+    fn reports_location_for_file() {
+        let sandbox = create_empty_sandbox();
+        sandbox.create_file("file.yaml", "a: 1\nb: [1, 2\nc: 3");
+
+        let error = yaml::read_file::<_, Value>(sandbox.path().join("file.yaml")).unwrap_err();
+
+        assert_eq!(error.location(), Some((3, 2)));
+        assert!(error.to_string().contains("file.yaml"));
+    }
+
+    #[test]
+    fn labels_source_span() {
+        use miette::Diagnostic;
+
+        let error = yaml::parse::<_, Value>("a: 1\nb: [1, 2\nc: 3").unwrap_err();
+        let label = error.labels().unwrap().next().unwrap();
+
+        assert!(error.source_code().is_some());
+        assert_eq!(label.offset(), 15);
+    }
+}
@@ -348,6 +348,20 @@ async fn render(session: TestSession, ui: String) {
                     Section(title: "Title", title_color: Color::Red) {
                         Text(content: "With colored header")
                     }
+                    Section(title: "Collapsed", collapsed: true) {
+                        Text(content: "Hidden 1")
+                        Text(content: "Hidden 2")
+                    }
+                    Section(title: "Collapsed with summary", collapsed: true, summary: "Expand with --verbose".to_owned()) {
+                        Text(content: "Hidden")
+                    }
+                    Section(title: "Indent 0") {
+                        Section(title: "Indent 1", indent: 1usize) {
+                            Section(title: "Indent 2", indent: 2usize) {
+                                Text(content: "Nested content")
+                            }
+                        }
+                    }
                 }
             })
             .unwrap();